@@ -0,0 +1,108 @@
+#ifndef AMOUNT_CONVERSION_H
+#define AMOUNT_CONVERSION_H
+
+/* Generated with cbindgen from src/ffi.rs, do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// Outcome of an FFI call.
+typedef enum AmountConversionStatus {
+  AMOUNT_CONVERSION_STATUS_OK = 0,
+  AMOUNT_CONVERSION_STATUS_NULL_POINTER = 1,
+  AMOUNT_CONVERSION_STATUS_INVALID_UTF8 = 2,
+  AMOUNT_CONVERSION_STATUS_UNKNOWN_CURRENCY = 3,
+  AMOUNT_CONVERSION_STATUS_OVERFLOW = 4,
+  AMOUNT_CONVERSION_STATUS_INVALID_DECIMAL_STRING = 5,
+  AMOUNT_CONVERSION_STATUS_TOO_MANY_DECIMALS = 6,
+  AMOUNT_CONVERSION_STATUS_CURRENCY_MISMATCH = 7,
+  AMOUNT_CONVERSION_STATUS_INVALID_EXCHANGE_RATE = 8,
+  AMOUNT_CONVERSION_STATUS_SUBUNIT_MISMATCH = 9,
+  AMOUNT_CONVERSION_STATUS_MINOR_UNITS_OUT_OF_RANGE = 10,
+  AMOUNT_CONVERSION_STATUS_INVALID_RATE = 11,
+  AMOUNT_CONVERSION_STATUS_NEGATIVE_ADJUSTMENT = 12,
+  AMOUNT_CONVERSION_STATUS_DIVISION_BY_ZERO = 13,
+  AMOUNT_CONVERSION_STATUS_INVALID_FACTOR = 14,
+  AMOUNT_CONVERSION_STATUS_INVALID_DENOMINATION = 15,
+  AMOUNT_CONVERSION_STATUS_NO_BREAKDOWN = 16,
+  AMOUNT_CONVERSION_STATUS_VALUE_DATE_MISMATCH = 17,
+  AMOUNT_CONVERSION_STATUS_REDENOMINATION_CYCLE = 18,
+  AMOUNT_CONVERSION_STATUS_EMPTY_SUM = 19,
+  AMOUNT_CONVERSION_STATUS_FRACTIONAL_AMOUNT_NOT_ALLOWED = 20,
+  AMOUNT_CONVERSION_STATUS_AMOUNT_OUT_OF_BOUNDS = 21,
+} AmountConversionStatus;
+
+// Opaque money handle holding an amount in the currency's lowest subunit.
+typedef struct AmountConversionMoney AmountConversionMoney;
+
+// Creates a money handle from an amount in subunits and an ISO 4217 code like `"USD"`.
+//
+// # Safety
+//
+// `currency` must be null or a valid NUL-terminated string, `out` must be null or writable.
+enum AmountConversionStatus amount_conversion_money_new(int32_t amount,
+                                                        const char *currency,
+                                                        struct AmountConversionMoney **out);
+
+// Creates a money handle by converting a major unit amount, e.g. `10.5` USD, into subunits.
+//
+// # Safety
+//
+// `currency` must be null or a valid NUL-terminated string, `out` must be null or writable.
+enum AmountConversionStatus amount_conversion_money_from_major(double amount,
+                                                               const char *currency,
+                                                               struct AmountConversionMoney **out);
+
+// Creates a money handle by parsing a major unit decimal string like `"10.50"` exactly.
+//
+// # Safety
+//
+// `amount` and `currency` must be null or valid NUL-terminated strings, `out` must be null or
+// writable.
+enum AmountConversionStatus amount_conversion_money_parse(const char *amount,
+                                                          const char *currency,
+                                                          struct AmountConversionMoney **out);
+
+// Writes the amount in subunits to `out`.
+//
+// # Safety
+//
+// `money` must be null or a handle returned by this library, `out` must be null or writable.
+enum AmountConversionStatus amount_conversion_money_amount(const struct AmountConversionMoney *money,
+                                                           int32_t *out);
+
+// Writes the amount converted to major units to `out`.
+//
+// # Safety
+//
+// `money` must be null or a handle returned by this library, `out` must be null or writable.
+enum AmountConversionStatus amount_conversion_money_to_major(const struct AmountConversionMoney *money,
+                                                             double *out);
+
+// Writes the amount in major units with exactly the currency's decimals, e.g. `"10.50"`.
+//
+// The string must be released with [`amount_conversion_string_free`].
+//
+// # Safety
+//
+// `money` must be null or a handle returned by this library, `out` must be null or writable.
+enum AmountConversionStatus amount_conversion_money_format(const struct AmountConversionMoney *money,
+                                                           char **out);
+
+// Releases a money handle. Passing null is a no-op.
+//
+// # Safety
+//
+// `money` must be null or a handle returned by this library that was not freed yet.
+void amount_conversion_money_free(struct AmountConversionMoney *money);
+
+// Releases a string returned by this library. Passing null is a no-op.
+//
+// # Safety
+//
+// `s` must be null or a string returned by this library that was not freed yet.
+void amount_conversion_string_free(char *s);
+
+#endif  /* AMOUNT_CONVERSION_H */
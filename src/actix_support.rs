@@ -0,0 +1,102 @@
+//! `actix-web` integration, enabled with the `actix` feature.
+//!
+//! The crate's error types implement `ResponseError`, answering with `422 Unprocessable Entity`
+//! and a JSON body `{"code": .., "message": ..}`, so handlers can `?` them directly.
+//!
+//! # Examples
+//!
+//! ```
+//! use actix_web::web::Json;
+//! use amount_conversion::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+//! use amount_conversion::factor::Currency;
+//!
+//! type Money = MoneyInner<LowestSubunit, Currency>;
+//!
+//! async fn double(Json(money): Json<Money>) -> Result<Json<Money>, MoneyConversionError<Currency>> {
+//!     Ok(Json(money.checked_mul(2)?))
+//! }
+//!
+//! let app = actix_web::App::new().route("/double", actix_web::web::post().to(double));
+//! ```
+use std::fmt;
+
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+
+use crate::amount::MoneyConversionError;
+use crate::factor::{FromCurrency, ParseCurrencyError};
+use crate::limits::LimitViolation;
+use crate::response::ErrorBody;
+
+impl<T: fmt::Debug> ResponseError for MoneyConversionError<T> {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ErrorBody::from(self))
+    }
+}
+
+impl ResponseError for ParseCurrencyError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ErrorBody::from(self))
+    }
+}
+
+impl<Cur: FromCurrency + fmt::Debug> ResponseError for LimitViolation<Cur> {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::UNPROCESSABLE_ENTITY
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code()).json(ErrorBody::from(self))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::body::MessageBody;
+
+    use super::*;
+    use crate::amount::{LowestSubunit, MoneyInner};
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    fn body(response: HttpResponse) -> serde_json::Value {
+        let bytes = response.into_body().try_into_bytes().unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[test]
+    fn conversion_error() {
+        let response = MoneyConversionError::CurrencyMismatch(USD, INR).error_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body(response)["code"], "currency_mismatch");
+    }
+
+    #[test]
+    fn parse_and_limit_errors() {
+        let err = "XYZ".parse::<Currency>().unwrap_err();
+        assert_eq!(body(err.error_response())["code"], "unknown_currency");
+
+        let violation = LimitViolation::AboveMaximum {
+            amount: Money::new(11, &USD),
+            max: Money::new(10, &USD),
+        };
+        let response = violation.error_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(
+            body(response),
+            serde_json::json!({
+                "code": "above_maximum",
+                "message": "amount of 11 USD is above the maximum of 10",
+            })
+        );
+    }
+}
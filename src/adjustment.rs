@@ -0,0 +1,169 @@
+use crate::amount::{i128_to_i32, LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::FromCurrency;
+use crate::percent::BasisPoints;
+use crate::rounding::RoundingMode;
+
+/// A price adjustment, either a share of the amount or a fixed amount of money.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Adjustment<Cur: FromCurrency> {
+    /// A share of the amount, e.g. 10% off.
+    Percent(BasisPoints),
+    /// A fixed amount in the same currency, e.g. 5.00 USD off.
+    Fixed(MoneyInner<LowestSubunit, Cur>),
+}
+
+impl<Cur: FromCurrency> From<BasisPoints> for Adjustment<Cur> {
+    fn from(rate: BasisPoints) -> Self {
+        Self::Percent(rate)
+    }
+}
+
+impl<Cur: FromCurrency> From<MoneyInner<LowestSubunit, Cur>> for Adjustment<Cur> {
+    fn from(amount: MoneyInner<LowestSubunit, Cur>) -> Self {
+        Self::Fixed(amount)
+    }
+}
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// Lowers the amount by `adjustment`, never going below zero.
+    ///
+    /// A percent discount is rounded to whole subunits with `rounding` before it is taken off,
+    /// so the original amount is always exactly the result plus the discount applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    /// use amount_conversion::percent::BasisPoints;
+    /// use amount_conversion::rounding::RoundingMode;
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// let price = Money::new(1999, &USD);
+    /// assert_eq!(
+    ///     price.apply_discount(BasisPoints::from_percent(10), RoundingMode::HalfUp),
+    ///     Ok(Money::new(1799, &USD))
+    /// );
+    /// assert_eq!(
+    ///     price.apply_discount(Money::new(2500, &USD), RoundingMode::HalfUp),
+    ///     Ok(Money::new(0, &USD))
+    /// );
+    /// ```
+    pub fn apply_discount(
+        self,
+        adjustment: impl Into<Adjustment<Cur>>,
+        rounding: RoundingMode,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        let discount = self.adjustment_amount(adjustment.into(), rounding)?;
+        let capped = discount.amount.min(self.amount.max(0));
+        self.checked_sub(Self::new(capped, &discount.currency))
+    }
+
+    /// Raises the amount by `adjustment`, rounding a percent surcharge with `rounding`.
+    pub fn apply_surcharge(
+        self,
+        adjustment: impl Into<Adjustment<Cur>>,
+        rounding: RoundingMode,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        let surcharge = self.adjustment_amount(adjustment.into(), rounding)?;
+        self.checked_add(surcharge)
+    }
+
+    pub(crate) fn adjustment_amount(
+        self,
+        adjustment: Adjustment<Cur>,
+        rounding: RoundingMode,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        let amount = match adjustment {
+            Adjustment::Percent(rate) if rate.get() < 0 => None,
+            Adjustment::Percent(rate) => Some(Self::new(
+                i128_to_i32(rate.of(i128::from(self.amount), rounding), self.currency)?,
+                &self.currency,
+            )),
+            Adjustment::Fixed(amount) if amount.amount < 0 => None,
+            Adjustment::Fixed(amount) => Some(amount),
+        };
+        amount.ok_or(MoneyConversionError::NegativeAdjustment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn discount() -> Result<(), MoneyConversionError<Currency>> {
+        let price = Money::new(1000, &USD);
+        let rate = BasisPoints::from_percent(15);
+        assert_eq!(
+            price.apply_discount(rate, RoundingMode::HalfEven)?,
+            Money::new(850, &USD)
+        );
+        assert_eq!(
+            Money::new(5, &USD).apply_discount(BasisPoints::new(1000), RoundingMode::Floor)?,
+            Money::new(5, &USD)
+        );
+        assert_eq!(
+            Money::new(5, &USD).apply_discount(BasisPoints::new(1000), RoundingMode::Ceil)?,
+            Money::new(4, &USD)
+        );
+        assert_eq!(
+            price.apply_discount(Money::new(250, &USD), RoundingMode::HalfEven)?,
+            Money::new(750, &USD)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn discount_floors_at_zero() -> Result<(), MoneyConversionError<Currency>> {
+        let price = Money::new(1000, &USD);
+        assert_eq!(
+            price.apply_discount(BasisPoints::from_percent(150), RoundingMode::HalfEven)?,
+            Money::new(0, &USD)
+        );
+        assert_eq!(
+            price.apply_discount(Money::new(1001, &USD), RoundingMode::HalfEven)?,
+            Money::new(0, &USD)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn surcharge() -> Result<(), MoneyConversionError<Currency>> {
+        let price = Money::new(1999, &EUR);
+        assert_eq!(
+            price.apply_surcharge(BasisPoints::new(250), RoundingMode::HalfEven)?,
+            Money::new(2049, &EUR)
+        );
+        assert_eq!(
+            price.apply_surcharge(Money::new(1, &EUR), RoundingMode::HalfEven)?,
+            Money::new(2000, &EUR)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_adjustments() {
+        let price = Money::new(1000, &USD);
+        assert_eq!(
+            price.apply_discount(BasisPoints::new(-1), RoundingMode::HalfEven),
+            Err(MoneyConversionError::NegativeAdjustment)
+        );
+        assert_eq!(
+            price.apply_surcharge(Money::new(-1, &USD), RoundingMode::HalfEven),
+            Err(MoneyConversionError::NegativeAdjustment)
+        );
+        assert_eq!(
+            price.apply_discount(Money::new(1, &EUR), RoundingMode::HalfEven),
+            Err(MoneyConversionError::CurrencyMismatch(USD, EUR))
+        );
+        assert_eq!(
+            Money::new(i32::MAX, &USD).apply_surcharge(Money::new(1, &USD), RoundingMode::HalfEven),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+    }
+}
@@ -0,0 +1,290 @@
+use crate::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::FromCurrency;
+
+/// The parts of an amount split by [`MoneyInner::split`] or [`MoneyInner::allocate`].
+///
+/// Only those methods build one, and only from parts that sum exactly to the total, so no
+/// subunit is created or destroyed; [`Allocation::is_conserved`] re-checks it for audits.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Allocation<Cur: FromCurrency> {
+    total: MoneyInner<LowestSubunit, Cur>,
+    parts: Vec<MoneyInner<LowestSubunit, Cur>>,
+    remainder: MoneyInner<LowestSubunit, Cur>,
+    remainder_parts: Vec<usize>,
+}
+
+impl<Cur: FromCurrency> Allocation<Cur> {
+    /// The parts, in the order they were asked for.
+    pub fn parts(&self) -> &[MoneyInner<LowestSubunit, Cur>] {
+        &self.parts
+    }
+
+    /// The amount that was split.
+    pub fn total(&self) -> MoneyInner<LowestSubunit, Cur> {
+        self.total
+    }
+
+    /// The subunits that did not divide evenly, spread with the remainder strategy.
+    pub fn remainder(&self) -> MoneyInner<LowestSubunit, Cur> {
+        self.remainder
+    }
+
+    /// The indices of the parts that received the remainder, in ascending order.
+    pub fn remainder_parts(&self) -> &[usize] {
+        &self.remainder_parts
+    }
+
+    /// Whether the parts sum to the total in the total's currency, which always holds.
+    pub fn is_conserved(&self) -> bool {
+        let sum: i64 = self.parts.iter().map(|part| i64::from(part.amount)).sum();
+        sum == i64::from(self.total.amount)
+            && self
+                .parts
+                .iter()
+                .all(|part| part.currency == self.total.currency)
+    }
+
+    pub fn into_parts(self) -> Vec<MoneyInner<LowestSubunit, Cur>> {
+        self.parts
+    }
+}
+
+/// Where [`MoneyInner::split`] and [`MoneyInner::allocate`] put the subunits that do not divide
+/// evenly between the parts.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum RemainderStrategy {
+    /// The whole remainder goes to the first part, e.g. a first installment that is larger.
+    #[default]
+    FrontLoad,
+    /// The whole remainder goes to the last part.
+    BackLoad,
+    /// One subunit per part in turn, starting from the first.
+    RoundRobin,
+    /// One subunit each to parts picked at random, the same `seed` always picking the same parts.
+    Random { seed: u64 },
+}
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// Splits the amount into `n` parts that differ only by the remainder, placed with
+    /// `strategy`. The parts always sum to the amount.
+    ///
+    /// Fails with `DivisionByZero` when `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::allocation::RemainderStrategy;
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// let amounts = |strategy| {
+    ///     Money::new(1_000, &USD)
+    ///         .split(3, strategy)
+    ///         .map(|split| split.parts().iter().map(Money::amount).collect::<Vec<_>>())
+    /// };
+    /// assert_eq!(amounts(RemainderStrategy::FrontLoad)?, [334, 333, 333]);
+    /// assert_eq!(amounts(RemainderStrategy::BackLoad)?, [333, 333, 334]);
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn split(
+        self,
+        n: u32,
+        strategy: RemainderStrategy,
+    ) -> Result<Allocation<Cur>, MoneyConversionError<Cur>> {
+        let (part, _) = self.div_rem(n)?;
+        let parts = vec![i64::from(part.amount); n as usize];
+        let eligible: Vec<usize> = (0..parts.len()).collect();
+        Ok(self.allocation(parts, &eligible, strategy))
+    }
+
+    /// Allocates the amount in proportion to `ratios`, e.g. `[70, 30]` for a 70/30 revenue
+    /// share, placing the remainder with `strategy` among the parts with a non-zero ratio. The
+    /// parts always sum to the amount.
+    ///
+    /// Fails with `DivisionByZero` when `ratios` is empty or all zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::allocation::RemainderStrategy;
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, EUR};
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// let shares = Money::new(1_001, &EUR).allocate(&[1, 0, 1, 1], RemainderStrategy::RoundRobin)?;
+    /// let amounts: Vec<_> = shares.parts().iter().map(Money::amount).collect();
+    /// assert_eq!(amounts, [334, 0, 334, 333]);
+    /// assert_eq!(shares.remainder(), Money::new(2, &EUR));
+    /// assert_eq!(shares.remainder_parts(), [0, 2]);
+    /// assert!(shares.is_conserved());
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn allocate(
+        self,
+        ratios: &[u32],
+        strategy: RemainderStrategy,
+    ) -> Result<Allocation<Cur>, MoneyConversionError<Cur>> {
+        let total: i128 = ratios.iter().copied().map(i128::from).sum();
+        if total == 0 {
+            return Err(MoneyConversionError::DivisionByZero);
+        }
+        let amount = i128::from(self.amount);
+        // each share is at most the amount in magnitude, so it fits an i64
+        let parts: Vec<i64> = ratios
+            .iter()
+            .map(|&ratio| (amount * i128::from(ratio) / total) as i64)
+            .collect();
+        let eligible: Vec<usize> = (0..ratios.len()).filter(|&i| ratios[i] != 0).collect();
+        Ok(self.allocation(parts, &eligible, strategy))
+    }
+
+    /// Spreads what `parts` leave of the amount over the `eligible` parts with `strategy`.
+    fn allocation(
+        self,
+        mut parts: Vec<i64>,
+        eligible: &[usize],
+        strategy: RemainderStrategy,
+    ) -> Allocation<Cur> {
+        let remainder = i64::from(self.amount) - parts.iter().sum::<i64>();
+        let remainder_parts = distribute(&mut parts, eligible, remainder, strategy);
+        // every part lies between zero and the amount, so it fits the amount's type
+        let parts = parts
+            .into_iter()
+            .map(|part| Self::new(part as LowestSubunit, &self.currency))
+            .collect();
+        let allocation = Allocation {
+            total: self,
+            parts,
+            remainder: Self::new(remainder as LowestSubunit, &self.currency),
+            remainder_parts,
+        };
+        debug_assert!(allocation.is_conserved());
+        allocation
+    }
+}
+
+/// Adds `remainder` subunits to the `eligible` parts with `strategy`. The remainder is smaller
+/// in magnitude than the number of eligible parts, so each gets at most one subunit.
+///
+/// Returns the indices of the parts that received some, in ascending order.
+fn distribute(
+    parts: &mut [i64],
+    eligible: &[usize],
+    remainder: i64,
+    strategy: RemainderStrategy,
+) -> Vec<usize> {
+    let (Some(&first), Some(&last)) = (eligible.first(), eligible.last()) else {
+        return Vec::new();
+    };
+    if remainder == 0 {
+        return Vec::new();
+    }
+    let units = remainder.unsigned_abs() as usize;
+    let unit = remainder.signum();
+    match strategy {
+        RemainderStrategy::FrontLoad => {
+            parts[first] += remainder;
+            vec![first]
+        }
+        RemainderStrategy::BackLoad => {
+            parts[last] += remainder;
+            vec![last]
+        }
+        RemainderStrategy::RoundRobin => {
+            let receivers: Vec<usize> = eligible.iter().copied().take(units).collect();
+            for &index in &receivers {
+                parts[index] += unit;
+            }
+            receivers
+        }
+        RemainderStrategy::Random { seed } => {
+            // a partial Fisher-Yates shuffle driven by SplitMix64
+            let mut state = seed;
+            let mut order = eligible.to_vec();
+            for i in 0..units.min(order.len()) {
+                state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+                let mut z = state;
+                z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+                z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+                z ^= z >> 31;
+                let j = i + (z % (order.len() - i) as u64) as usize;
+                order.swap(i, j);
+                parts[order[i]] += unit;
+            }
+            let mut receivers = order;
+            receivers.truncate(units);
+            receivers.sort_unstable();
+            receivers
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    fn amounts(allocation: Allocation<Currency>) -> Vec<i32> {
+        allocation.parts().iter().map(Money::amount).collect()
+    }
+
+    #[test]
+    fn split_strategies() -> Result<(), MoneyConversionError<Currency>> {
+        let money = Money::new(-1_003, &USD);
+        let split = |strategy| money.split(4, strategy).map(amounts);
+        assert_eq!(
+            split(RemainderStrategy::FrontLoad)?,
+            [-253, -250, -250, -250]
+        );
+        assert_eq!(
+            split(RemainderStrategy::BackLoad)?,
+            [-250, -250, -250, -253]
+        );
+        assert_eq!(
+            split(RemainderStrategy::RoundRobin)?,
+            [-251, -251, -251, -250]
+        );
+        for seed in 0..20 {
+            let random = money.split(4, RemainderStrategy::Random { seed })?;
+            assert_eq!(random, money.split(4, RemainderStrategy::Random { seed })?);
+            assert!(random.is_conserved());
+            assert_eq!(random.remainder(), Money::new(-3, &USD));
+            assert_eq!(random.remainder_parts().len(), 3);
+            for (index, part) in random.parts().iter().enumerate() {
+                let received = random.remainder_parts().contains(&index);
+                assert_eq!(part.amount(), if received { -251 } else { -250 });
+            }
+        }
+        let even = money.split(1, RemainderStrategy::RoundRobin)?;
+        assert!(even.remainder_parts().is_empty());
+        assert_eq!(even.into_parts(), [money]);
+        assert_eq!(
+            money.split(0, RemainderStrategy::FrontLoad),
+            Err(MoneyConversionError::DivisionByZero)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn allocate_by_ratio() -> Result<(), MoneyConversionError<Currency>> {
+        let money = Money::new(i32::MAX, &INR);
+        let parts = amounts(money.allocate(&[u32::MAX, 0, u32::MAX], RemainderStrategy::BackLoad)?);
+        assert_eq!(parts, [1_073_741_823, 0, 1_073_741_824]);
+        assert_eq!(
+            amounts(Money::new(100, &INR).allocate(&[3, 7], RemainderStrategy::FrontLoad)?),
+            [30, 70]
+        );
+        for ratios in [&[][..], &[0, 0]] {
+            assert_eq!(
+                money.allocate(ratios, RemainderStrategy::FrontLoad),
+                Err(MoneyConversionError::DivisionByZero)
+            );
+        }
+        Ok(())
+    }
+}
@@ -1,4 +1,12 @@
-use crate::factor::{get_factor, FromCurrency};
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+use crate::backend::AmountBackend;
+use crate::decimal::format_subunits;
+use crate::factor::{self, factors_of, get_exponent, get_factor, to_major, FromCurrency};
+use crate::observer::{observe, ConversionKind};
+use crate::rounding::{noise_tolerance, round_f64, snap_to_whole, CurrencyRounding, RoundingMode};
 
 /// This library supports number till i32::MAX
 static MAX_F64_ALLOWED: f64 = {
@@ -12,12 +20,18 @@ static MIN_F64_ALLOWED: f64 = {
     small as f64
 };
 
+/// Subunit products closer than this to a whole number are float noise, e.g. `0.29 * 100`; large
+/// products allow a few ULPs instead, see `rounding::noise_tolerance`.
+pub(crate) const SUBUNIT_EPSILON: f64 = 1e-6;
+
 /// `MoneyInner` is a generic struct which combines amount and currency bounded to a single struct.
 ///
 /// `amount` field also generic so that it can hold i16,i32,f32,f64 etc.
 ///
 /// `currency` field also generic, since the user of the library can create their own enums for currency.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+///
+/// `Hash` is available for integer amounts such as `LowestSubunit`, so money can key maps and sets.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct MoneyInner<Amt, Cur: FromCurrency> {
     pub(crate) amount: Amt,
     pub(crate) currency: Cur,
@@ -31,23 +45,571 @@ pub enum MoneyConversionError<T> {
     CurrencyNotFoundInSubunitMap(T),
 
     /// `F64ToI32ConversionFailed` - The max number this library can process is i32::MAX, when a f64 is
-    ///                              large than that this error will arise.
-    F64ToI32ConversionFailed,
+    ///                              large than that this error will arise. The subunit `value`,
+    ///                              the `bound` it exceeded and the `currency` are carried along.
+    F64ToI32ConversionFailed { value: f64, bound: i32, currency: T },
+
+    /// `InvalidDecimalString` - The string is not a plain decimal number like `-12.34`.
+    InvalidDecimalString,
+
+    /// `TooManyDecimals` - The string has more decimal places than the currency's subunit allows,
+    ///                     the allowed number of decimals is carried along.
+    TooManyDecimals(u32),
+
+    /// `AmountOverflow` - The resulting subunit amount does not fit in the supported range.
+    AmountOverflow,
+
+    /// `I128ToI32ConversionFailed` - An exact integer result, e.g. of a percentage or a parsed
+    ///                               decimal, does not fit in i32. The subunit `value`, the
+    ///                               `bound` it exceeded and the `currency` are carried along.
+    I128ToI32ConversionFailed {
+        value: i128,
+        bound: i32,
+        currency: T,
+    },
+
+    /// `CurrencyMismatch` - Both operands of an arithmetic operation must have the same currency.
+    CurrencyMismatch(T, T),
+
+    /// `InvalidExchangeRate` - Exchange rates must be finite and greater than zero.
+    InvalidExchangeRate,
+
+    /// `SubunitMismatch` - The currencies have different subunit factors.
+    SubunitMismatch(T, T),
+
+    /// `MinorUnitsOutOfRange` - The minor part must be smaller than the currency's subunit factor,
+    ///                          e.g. at most 99 for USD and 0 for JPY.
+    MinorUnitsOutOfRange(u32),
+
+    /// `InvalidRate` - The rate in basis points is not allowed for the operation, e.g. a negative tax.
+    InvalidRate(i32),
+
+    /// `NegativeAdjustment` - A discount or surcharge was given as a negative rate or amount.
+    NegativeAdjustment,
+
+    /// `DivisionByZero` - The operation divides by zero, e.g. a percentage change from zero or
+    /// splitting into zero parts.
+    DivisionByZero,
+
+    /// `InvalidFactor` - A factor override of zero, or an exponent override above
+    /// `MAX_EXPONENT`, was given for a conversion.
+    InvalidFactor(u32),
+
+    /// `InvalidDenomination` - A note or coin denomination is zero or negative.
+    InvalidDenomination(i32),
+
+    /// `NoBreakdown` - The amount cannot be paid exactly with the given denominations.
+    NoBreakdown,
+
+    /// `ValueDateMismatch` - The value dates of two amounts differ, or a rate is not valid on
+    /// the value date of an amount.
+    ValueDateMismatch,
+
+    /// `RedenominationCycle` - Following the redenominations from the currency leads back to it.
+    RedenominationCycle(T),
+
+    /// `EmptySum` - There are no amounts to total, so the total has no currency.
+    EmptySum,
+
+    /// `FractionalAmountNotAllowed` - A fractional major unit amount was converted into a
+    ///                                currency without decimals, e.g. 10.5 JPY, with no rounding
+    ///                                mode given.
+    FractionalAmountNotAllowed(T),
+
+    /// `AmountOutOfBounds` - The subunit `value`, before any saturation, is outside the bounds
+    ///                       configured in a `ConversionPolicy`; the `limit` it exceeded and the
+    ///                       `currency` are carried along.
+    AmountOutOfBounds { value: f64, limit: i32, currency: T },
 }
 
+impl<T> MoneyConversionError<T> {
+    /// A stable snake_case identifier of the error kind, e.g. `"currency_mismatch"`, for API
+    /// responses and metrics.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::CurrencyNotFoundInSubunitMap(_) => "currency_not_found_in_subunit_map",
+            Self::F64ToI32ConversionFailed { .. } => "f64_to_i32_conversion_failed",
+            Self::InvalidDecimalString => "invalid_decimal_string",
+            Self::TooManyDecimals(_) => "too_many_decimals",
+            Self::AmountOverflow => "amount_overflow",
+            Self::I128ToI32ConversionFailed { .. } => "i128_to_i32_conversion_failed",
+            Self::CurrencyMismatch(..) => "currency_mismatch",
+            Self::InvalidExchangeRate => "invalid_exchange_rate",
+            Self::SubunitMismatch(..) => "subunit_mismatch",
+            Self::MinorUnitsOutOfRange(_) => "minor_units_out_of_range",
+            Self::InvalidRate(_) => "invalid_rate",
+            Self::NegativeAdjustment => "negative_adjustment",
+            Self::DivisionByZero => "division_by_zero",
+            Self::InvalidFactor(_) => "invalid_factor",
+            Self::InvalidDenomination(_) => "invalid_denomination",
+            Self::NoBreakdown => "no_breakdown",
+            Self::ValueDateMismatch => "value_date_mismatch",
+            Self::RedenominationCycle(_) => "redenomination_cycle",
+            Self::EmptySum => "empty_sum",
+            Self::FractionalAmountNotAllowed(_) => "fractional_amount_not_allowed",
+            Self::AmountOutOfBounds { .. } => "amount_out_of_bounds",
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for MoneyConversionError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CurrencyNotFoundInSubunitMap(currency) => {
+                write!(f, "currency {currency:?} not found in the subunit map")
+            }
+            Self::F64ToI32ConversionFailed {
+                value,
+                bound,
+                currency,
+            } => write_out_of_range(f, value, *bound, currency),
+            Self::InvalidDecimalString => f.write_str("amount is not a valid decimal string"),
+            Self::TooManyDecimals(allowed) => {
+                write!(f, "amount has more than {allowed} decimal places")
+            }
+            Self::AmountOverflow => f.write_str("amount overflows the supported range"),
+            Self::I128ToI32ConversionFailed {
+                value,
+                bound,
+                currency,
+            } => write_out_of_range(f, value, *bound, currency),
+            Self::CurrencyMismatch(lhs, rhs) => {
+                write!(f, "currency mismatch between {lhs:?} and {rhs:?}")
+            }
+            Self::InvalidExchangeRate => f.write_str("exchange rate must be finite and positive"),
+            Self::SubunitMismatch(lhs, rhs) => {
+                write!(
+                    f,
+                    "currencies {lhs:?} and {rhs:?} have different subunit factors"
+                )
+            }
+            Self::MinorUnitsOutOfRange(minor) => {
+                write!(f, "minor units {minor} exceed the currency's subunit range")
+            }
+            Self::InvalidRate(bps) => write!(f, "rate of {bps} basis points is not allowed"),
+            Self::NegativeAdjustment => write!(f, "discounts and surcharges must not be negative"),
+            Self::DivisionByZero => f.write_str("cannot divide by zero"),
+            Self::InvalidFactor(factor) => {
+                write!(f, "factor {factor} is not a valid subunit factor")
+            }
+            Self::InvalidDenomination(denomination) => {
+                write!(f, "denomination {denomination} must be greater than zero")
+            }
+            Self::NoBreakdown => write!(f, "amount cannot be paid with the given denominations"),
+            Self::ValueDateMismatch => f.write_str("value dates do not match"),
+            Self::RedenominationCycle(currency) => {
+                write!(f, "redenominations of {currency:?} form a cycle")
+            }
+            Self::EmptySum => f.write_str("there are no amounts to total"),
+            Self::FractionalAmountNotAllowed(currency) => {
+                write!(f, "{currency:?} has no decimals for a fractional amount")
+            }
+            Self::AmountOutOfBounds {
+                value,
+                limit,
+                currency,
+            } => {
+                let side = if *value < f64::from(*limit) {
+                    "minimum"
+                } else {
+                    "maximum"
+                };
+                write!(
+                    f,
+                    "{value} subunits of {currency:?} exceed the configured {side} {limit}"
+                )
+            }
+        }
+    }
+}
+
+/// `"{value} subunits of {currency} exceed the i32 maximum/minimum {bound}"`.
+fn write_out_of_range(
+    f: &mut fmt::Formatter<'_>,
+    value: &dyn fmt::Display,
+    bound: i32,
+    currency: &dyn fmt::Debug,
+) -> fmt::Result {
+    let limit = if bound < 0 { "minimum" } else { "maximum" };
+    write!(
+        f,
+        "{value} subunits of {currency:?} exceed the i32 {limit} {bound}"
+    )
+}
+
+impl<T: fmt::Debug> std::error::Error for MoneyConversionError<T> {}
+
 pub type LowestSubunit = i32;
+/// Major units. `==` on `MoneyInner<HighestUnit, _>` compares the raw floats, so amounts equal in
+/// subunits can differ; compare them with [`MoneyInner::eq_exact`] or [`MoneyInner::approx_eq`],
+/// and wrap them in [`OrderedMoney`] to key maps and sets.
 pub type HighestUnit = f64;
+/// Subunits wide enough to hold the difference of any two `LowestSubunit` amounts.
+pub type SignedSubunit = i64;
+/// The result of [`MoneyInner::diff`], which cannot overflow.
+pub type SignedMoney<Cur> = MoneyInner<SignedSubunit, Cur>;
+/// Subunits wide enough to total any number of `LowestSubunit` or `SignedSubunit` amounts.
+pub type WideSubunit = i128;
+/// A total from [`sum_to_i128`], e.g. a month-end aggregate.
+pub type WideMoney<Cur> = MoneyInner<WideSubunit, Cur>;
 
-impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
-    pub fn new(amount: i32, currency: &Cur) -> Self {
+impl<Amt, Cur: FromCurrency> MoneyInner<Amt, Cur> {
+    pub fn currency(&self) -> Cur {
+        self.currency
+    }
+
+    /// Splits the money into its amount and currency.
+    pub fn into_parts(self) -> (Amt, Cur) {
+        (self.amount, self.currency)
+    }
+
+    /// Applies `f` to the amount, keeping the currency.
+    pub fn map_amount<B, F: FnOnce(Amt) -> B>(self, f: F) -> MoneyInner<B, Cur> {
+        MoneyInner {
+            amount: f(self.amount),
+            currency: self.currency,
+        }
+    }
+
+    /// Replaces the amount, keeping the currency.
+    pub fn with_amount(self, amount: Amt) -> Self {
         Self {
             amount,
+            currency: self.currency,
+        }
+    }
+
+    /// Moves the amount to another currency with the same subunit factor, so the amount keeps its
+    /// meaning, e.g. `USD` to `EUR` is allowed but `USD` to `JPY` fails with `SubunitMismatch`.
+    pub fn recast_currency(self, currency: &Cur) -> Result<Self, MoneyConversionError<Cur>> {
+        let target = MoneyInner {
+            amount: (),
             currency: *currency,
+        };
+        if get_factor(&self)? != get_factor(&target)? {
+            return Err(MoneyConversionError::SubunitMismatch(
+                self.currency,
+                *currency,
+            ));
         }
+        Ok(Self {
+            amount: self.amount,
+            currency: *currency,
+        })
     }
 
-    pub fn convert(self) -> Result<MoneyInner<HighestUnit, Cur>, MoneyConversionError<Cur>> {
-        self.try_into()
+    /// Moves the money to another currency type, e.g. when crossing into a service with its own
+    /// currency enum. Fails with `CurrencyMismatch` unless both map to the same ISO currency
+    /// under the same code, so a private code never passes for its ISO currency, and with
+    /// `SubunitMismatch` when one overrides its exponent so the subunits differ.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::{Currency, FromCurrency};
+    ///
+    /// #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+    /// struct Dollar;
+    ///
+    /// impl FromCurrency for Dollar {
+    ///     fn currency(&self) -> Currency {
+    ///         Currency::USD
+    ///     }
+    /// }
+    ///
+    /// let money = MoneyInner::<LowestSubunit, _>::new(1_050, &Currency::USD);
+    /// assert_eq!(
+    ///     money.recast(&Dollar)?,
+    ///     MoneyInner::<LowestSubunit, _>::new(1_050, &Dollar)
+    /// );
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn recast<Cur2: FromCurrency>(
+        self,
+        currency: &Cur2,
+    ) -> Result<MoneyInner<Amt, Cur2>, MoneyConversionError<factor::Currency>> {
+        let (from, to) = (self.currency.currency(), currency.currency());
+        if from != to || self.currency.currency_code() != currency.currency_code() {
+            return Err(MoneyConversionError::CurrencyMismatch(from, to));
+        }
+        let target = MoneyInner {
+            amount: (),
+            currency: *currency,
+        };
+        match (get_exponent(&self), get_exponent(&target)) {
+            (Ok(source), Ok(target)) if source == target => {}
+            (Err(MoneyConversionError::InvalidFactor(exponent)), _)
+            | (_, Err(MoneyConversionError::InvalidFactor(exponent))) => {
+                return Err(MoneyConversionError::InvalidFactor(exponent))
+            }
+            _ => return Err(MoneyConversionError::SubunitMismatch(from, to)),
+        }
+        Ok(MoneyInner {
+            amount: self.amount,
+            currency: *currency,
+        })
+    }
+}
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// Builds an amount from its sign, major and minor parts without going through f64, e.g.
+    /// `from_major_minor(false, 12, 34, &USD)` is 1234 cents.
+    ///
+    /// The sign is separate from the parts so amounts below one major unit keep it: `(true, 0, 34)`
+    /// is -0.34. The minor part must fit the currency's exponent, so `(false, 12, 34)` fails for JPY
+    /// with `MinorUnitsOutOfRange`.
+    pub fn from_major_minor(
+        negative: bool,
+        major: u32,
+        minor: u32,
+        currency: &Cur,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        let parts = MajorMinor::check(negative, major, minor, currency)?;
+        if let Some(error) = parts.minor {
+            return Err(error);
+        }
+        Ok(Self::new(parts.amount?, currency))
+    }
+
+    /// One major unit of `currency` expressed in subunits, e.g. 100 cents for USD.
+    ///
+    /// Fails with `AmountOverflow` when the factor does not fit the subunit type.
+    pub fn one_major_unit(currency: &Cur) -> Result<Self, MoneyConversionError<Cur>> {
+        let factor = get_factor(&Self::zero(currency))? as i64;
+        let factor = i32::try_from(factor).map_err(|_| MoneyConversionError::AmountOverflow)?;
+        Ok(Self::new(factor, currency))
+    }
+
+    /// Converts with `factor` subunits per major unit instead of the currency's own factor, for
+    /// sources using a non-standard scale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, JPY};
+    ///
+    /// // a gateway reporting JPY in hundredths
+    /// let major = MoneyInner::<LowestSubunit, Currency>::new(150_050, &JPY).convert_with_factor(100);
+    /// assert_eq!(major.map(|major| major.amount()), Ok(1500.5));
+    /// ```
+    pub fn convert_with_factor(
+        self,
+        factor: u32,
+    ) -> Result<MoneyInner<HighestUnit, Cur>, MoneyConversionError<Cur>> {
+        let major = checked_factor(factor).map(|factor| {
+            MoneyInner::<HighestUnit, Cur>::new(f64::from(self.amount) / factor, &self.currency)
+        });
+        observe(ConversionKind::ToMajorUnits, &self.currency, major)
+    }
+
+    /// Splits the amount into `n` equal parts, returning one part and the subunits left over,
+    /// so that `part * n + remainder` is the amount. Both round toward zero and the remainder
+    /// has the amount's sign; fails with `DivisionByZero` when `n` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// let (installment, remainder) = Money::new(10_000, &USD).div_rem(3)?;
+    /// assert_eq!(installment, Money::new(3_333, &USD));
+    /// assert_eq!(remainder, Money::new(1, &USD));
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn div_rem(self, n: u32) -> Result<(Self, Self), MoneyConversionError<Cur>> {
+        if n == 0 {
+            return Err(MoneyConversionError::DivisionByZero);
+        }
+        let (amount, n) = (i64::from(self.amount), i64::from(n));
+        // |amount / n| and |amount % n| never exceed |amount|
+        let part = Self::new((amount / n) as i32, &self.currency);
+        let remainder = Self::new((amount % n) as i32, &self.currency);
+        Ok((part, remainder))
+    }
+
+    /// `self - other` for amounts of the same currency, widened so it never overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// let diff = Money::new(i32::MIN, &USD).diff(Money::new(i32::MAX, &USD)).unwrap();
+    /// assert_eq!(diff.amount(), -4_294_967_295);
+    /// ```
+    pub fn diff(self, other: Self) -> Result<SignedMoney<Cur>, MoneyConversionError<Cur>> {
+        self.ensure_same_currency(&other)?;
+        let diff = SignedSubunit::from(self.amount) - SignedSubunit::from(other.amount);
+        Ok(SignedMoney::new(diff, &self.currency))
+    }
+
+    /// The change from `other` to `self` in percent, e.g. `25.0` when `self` is 1.25 times `other`.
+    ///
+    /// Fails on a currency mismatch or when `other` is zero.
+    pub fn percent_change(self, other: Self) -> Result<f64, MoneyConversionError<Cur>> {
+        let diff = self.diff(other)?;
+        if other.amount == 0 {
+            return Err(MoneyConversionError::DivisionByZero);
+        }
+        Ok(diff.amount as f64 / f64::from(other.amount).abs() * 100.0)
+    }
+}
+
+/// Adds two amounts of the same currency.
+///
+/// # Panics
+///
+/// Panics on a currency mismatch or overflow, use `checked_add` to handle those as errors.
+impl<B: AmountBackend, Cur: FromCurrency + fmt::Debug> Add for MoneyInner<B, Cur> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+/// Subtracts two amounts of the same currency.
+///
+/// # Panics
+///
+/// Panics on a currency mismatch or overflow, use `checked_sub` to handle those as errors.
+impl<B: AmountBackend, Cur: FromCurrency + fmt::Debug> Sub for MoneyInner<B, Cur> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .unwrap_or_else(|err| panic!("{err}"))
+    }
+}
+
+/// # Panics
+///
+/// Panics on a currency mismatch or overflow, use `try_add_assign` to handle those as errors.
+impl<B: AmountBackend, Cur: FromCurrency + fmt::Debug> AddAssign for MoneyInner<B, Cur> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+/// # Panics
+///
+/// Panics on a currency mismatch or overflow, use `try_sub_assign` to handle those as errors.
+impl<B: AmountBackend, Cur: FromCurrency + fmt::Debug> SubAssign for MoneyInner<B, Cur> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// Subunit money is ordered by currency first and amount second, so sorting a mixed-currency
+/// list groups each currency together in ascending amount order.
+impl<Cur: FromCurrency + Ord> Ord for MoneyInner<LowestSubunit, Cur> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.currency
+            .cmp(&other.currency)
+            .then_with(|| self.amount.cmp(&other.amount))
+    }
+}
+
+impl<Cur: FromCurrency + Ord> PartialOrd for MoneyInner<LowestSubunit, Cur> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Major-unit money with a sound `Eq`, `Hash` and total `Ord`, so it can key maps and sets.
+///
+/// Amounts compare by their float value, with `-0.0` equal to `0.0` and every `NaN` equal to
+/// every other and greater than all numbers, as `f64::total_cmp` orders them. Amounts equal in
+/// subunits but not as floats, such as `0.1 + 0.2` and `0.3`, still differ; wrap the `value` of
+/// [`MoneyInner::normalize`] to compare those. Like subunit money, it is ordered by currency
+/// first.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeSet;
+///
+/// use amount_conversion::amount::{HighestUnit, MoneyInner, OrderedMoney};
+/// use amount_conversion::factor::Currency::{self, USD};
+///
+/// type MoneyH = MoneyInner<HighestUnit, Currency>;
+///
+/// let prices: BTreeSet<_> = [10.5, f64::NAN, -0.0, 0.0, 2.25]
+///     .into_iter()
+///     .map(|amount| OrderedMoney::new(MoneyH::new(amount, &USD)))
+///     .collect();
+/// let amounts: Vec<_> = prices.iter().map(|price| price.get().amount()).collect();
+/// assert_eq!(amounts[..3], [0.0, 2.25, 10.5]);
+/// assert!(amounts[3].is_nan());
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct OrderedMoney<Cur: FromCurrency>(MoneyInner<HighestUnit, Cur>);
+
+impl<Cur: FromCurrency> OrderedMoney<Cur> {
+    pub fn new(money: MoneyInner<HighestUnit, Cur>) -> Self {
+        Self(money)
+    }
+
+    pub fn get(&self) -> MoneyInner<HighestUnit, Cur> {
+        self.0
+    }
+
+    /// The amount with `-0.0` and every `NaN` collapsed, so equal amounts have equal bits.
+    fn canonical_amount(&self) -> f64 {
+        let amount = self.0.amount;
+        if amount.is_nan() {
+            f64::NAN
+        } else if amount == 0.0 {
+            0.0
+        } else {
+            amount
+        }
+    }
+}
+
+impl<Cur: FromCurrency> From<MoneyInner<HighestUnit, Cur>> for OrderedMoney<Cur> {
+    fn from(money: MoneyInner<HighestUnit, Cur>) -> Self {
+        Self::new(money)
+    }
+}
+
+impl<Cur: FromCurrency> PartialEq for OrderedMoney<Cur> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.currency == other.0.currency
+            && self.canonical_amount().to_bits() == other.canonical_amount().to_bits()
+    }
+}
+
+impl<Cur: FromCurrency> Eq for OrderedMoney<Cur> {}
+
+impl<Cur: FromCurrency> std::hash::Hash for OrderedMoney<Cur> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.currency.hash(state);
+        self.canonical_amount().to_bits().hash(state);
+    }
+}
+
+impl<Cur: FromCurrency + Ord> Ord for OrderedMoney<Cur> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .currency
+            .cmp(&other.0.currency)
+            .then_with(|| self.canonical_amount().total_cmp(&other.canonical_amount()))
+    }
+}
+
+impl<Cur: FromCurrency + Ord> PartialOrd for OrderedMoney<Cur> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
     }
 }
 
@@ -55,24 +617,102 @@ impl<Cur: FromCurrency> TryFrom<MoneyInner<LowestSubunit, Cur>> for MoneyInner<H
     type Error = MoneyConversionError<Cur>;
 
     fn try_from(value: MoneyInner<LowestSubunit, Cur>) -> Result<Self, Self::Error> {
-        let factor = get_factor(&value)?;
-        Ok(MoneyInner::<HighestUnit, Cur>::new(
-            (value.amount as f64) / factor,
+        observe(
+            ConversionKind::ToMajorUnits,
             &value.currency,
-        ))
+            to_major_units(value),
+        )
     }
 }
 
-impl<Cur: FromCurrency> TryFrom<MoneyInner<HighestUnit, Cur>> for MoneyInner<LowestSubunit, Cur> {
+impl<B: AmountBackend, Cur: FromCurrency> TryFrom<MoneyInner<HighestUnit, Cur>>
+    for MoneyInner<B, Cur>
+{
     type Error = MoneyConversionError<Cur>;
 
     fn try_from(value: MoneyInner<HighestUnit, Cur>) -> Result<Self, Self::Error> {
-        let factor = get_factor(&value)?;
-        Ok(MoneyInner::<LowestSubunit, Cur>::new(
-            f64_to_i32(value.amount * factor)?,
+        observe(
+            ConversionKind::ToSubunits,
             &value.currency,
-        ))
+            to_whole_subunits(value),
+        )
+    }
+}
+
+/// [`to_subunits`], failing with `FractionalAmountNotAllowed` instead of rounding a fraction of
+/// a currency without decimals when it has no registered rounding.
+pub(crate) fn to_whole_subunits<B: AmountBackend, Cur: FromCurrency>(
+    value: MoneyInner<HighestUnit, Cur>,
+) -> Result<MoneyInner<B, Cur>, MoneyConversionError<Cur>> {
+    let fractional = snap_to_whole(value.amount).fract() != 0.0;
+    if fractional
+        && get_exponent(&value)? == 0
+        && value.currency.currency().default_rounding().is_none()
+    {
+        return Err(MoneyConversionError::FractionalAmountNotAllowed(
+            value.currency,
+        ));
     }
+    to_subunits(value)
+}
+
+/// Converts subunits to major units without notifying the conversion observer.
+pub(crate) fn to_major_units<B: AmountBackend, Cur: FromCurrency>(
+    value: MoneyInner<B, Cur>,
+) -> Result<MoneyInner<HighestUnit, Cur>, MoneyConversionError<Cur>> {
+    let factors = factors_of(get_exponent(&value)?);
+    let major = to_major(value.amount.to_f64(), factors);
+    #[cfg(feature = "tracing")]
+    tracing::debug!(
+        currency = value.currency.currency_code(),
+        factor = factors.0,
+        subunits = ?value.amount,
+        major,
+        "converted subunits to major units"
+    );
+    Ok(MoneyInner::<HighestUnit, Cur>::new(major, &value.currency))
+}
+
+/// Converts major units to subunits without notifying the conversion observer, with the
+/// currency's registered default rounding or else rounding half to even.
+pub(crate) fn to_subunits<B: AmountBackend, Cur: FromCurrency>(
+    value: MoneyInner<HighestUnit, Cur>,
+) -> Result<MoneyInner<B, Cur>, MoneyConversionError<Cur>> {
+    let factor = get_factor(&value)?;
+    let exact = value.amount * factor;
+    let subunits = match value.currency.currency().default_rounding() {
+        Some(rounding) => B::from_f64(rounding.round(exact), value.currency)?,
+        None => B::from_f64(exact, value.currency)?,
+    };
+    #[cfg(feature = "tracing")]
+    trace_narrowing(&value, factor, exact, subunits);
+    Ok(MoneyInner::<B, Cur>::new(subunits, &value.currency))
+}
+
+/// A major-unit amount snapped to a whole number of subunits by [`MoneyInner::normalize`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Normalized<Cur: FromCurrency, B = LowestSubunit> {
+    /// The canonical amount in major units.
+    pub value: MoneyInner<HighestUnit, Cur>,
+    /// The canonical amount in subunits, for storage.
+    pub subunits: MoneyInner<B, Cur>,
+    /// `value` minus the original amount, in major units.
+    pub delta: HighestUnit,
+}
+
+/// What [`MoneyInner::convert_detailed`] did to reach its subunits, for audit logs and
+/// dispute resolution.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ConversionOutcome<Cur: FromCurrency> {
+    /// The subunits, as `convert` returns them.
+    pub result: MoneyInner<LowestSubunit, Cur>,
+    /// The subunits per major unit the amount was multiplied by, overrides included.
+    pub factor_used: i64,
+    /// The rounding that changed the amount, `None` when the product was already the result
+    /// up to float noise.
+    pub rounding_applied: Option<CurrencyRounding>,
+    /// The result minus the exact product, in subunits.
+    pub delta_subunits: f64,
 }
 
 impl<Cur: FromCurrency> MoneyInner<HighestUnit, Cur> {
@@ -87,18 +727,409 @@ impl<Cur: FromCurrency> MoneyInner<HighestUnit, Cur> {
         self.amount
     }
 
-    pub fn convert(self) -> Result<MoneyInner<LowestSubunit, Cur>, MoneyConversionError<Cur>> {
+    pub fn zero(currency: &Cur) -> Self {
+        Self::new(0.0, currency)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount == 0.0
+    }
+
+    /// Whether the amounts are at most `tolerance_subunits` apart once each is rounded to
+    /// subunits as `convert` does, so float noise from converted values does not break
+    /// comparisons.
+    ///
+    /// Fails with `CurrencyMismatch` when the currencies differ; `NaN` equals nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{HighestUnit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    ///
+    /// type MoneyH = MoneyInner<HighestUnit, Currency>;
+    ///
+    /// let total = MoneyH::new(0.1 + 0.2, &USD);
+    /// assert_ne!(total, MoneyH::new(0.3, &USD));
+    /// assert!(total.approx_eq(&MoneyH::new(0.3, &USD), 0)?);
+    /// assert!(!total.approx_eq(&MoneyH::new(0.32, &USD), 1)?);
+    /// assert!(total.approx_eq(&MoneyH::new(0.32, &USD), 2)?);
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn approx_eq(
+        &self,
+        other: &Self,
+        tolerance_subunits: u32,
+    ) -> Result<bool, MoneyConversionError<Cur>> {
+        if self.currency != other.currency {
+            return Err(MoneyConversionError::CurrencyMismatch(
+                self.currency,
+                other.currency,
+            ));
+        }
+        let diff = (self.rounded_subunits()? - other.rounded_subunits()?).abs();
+        Ok(diff <= f64::from(tolerance_subunits))
+    }
+
+    /// Whether the amounts are the same number of subunits once each is rounded as `convert`
+    /// does, e.g. `0.1 + 0.2` and `0.3` USD are both 30 cents.
+    ///
+    /// The derived `==` compares the raw floats, which tells such amounts apart; prefer this for
+    /// money that went through float math. Amounts beyond the `LowestSubunit` range compare too;
+    /// fails with `CurrencyMismatch` when the currencies differ, and `NaN` equals nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{HighestUnit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, JPY, USD};
+    ///
+    /// type MoneyH = MoneyInner<HighestUnit, Currency>;
+    ///
+    /// assert!(MoneyH::new(0.1 + 0.2, &USD).eq_exact(&MoneyH::new(0.3, &USD))?);
+    /// assert!(!MoneyH::new(0.301, &USD).eq_exact(&MoneyH::new(0.31, &USD))?);
+    /// assert!(MoneyH::new(1e12, &JPY).eq_exact(&MoneyH::new(1e12 + 1e-4, &JPY))?);
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn eq_exact(&self, other: &Self) -> Result<bool, MoneyConversionError<Cur>> {
+        self.approx_eq(other, 0)
+    }
+
+    /// Renders the amount with exactly the currency's number of decimals, independent of locale,
+    /// rounding to subunits as `convert` does, for receipts and gateway payloads.
+    ///
+    /// Fails like `convert` when the amount does not fit the subunit range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{HighestUnit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, *};
+    ///
+    /// type MoneyH = MoneyInner<HighestUnit, Currency>;
+    ///
+    /// assert_eq!(MoneyH::new(10.0, &USD).to_decimal_string()?, "10.00");
+    /// assert_eq!(MoneyH::new(10.0, &BHD).to_decimal_string()?, "10.000");
+    /// assert_eq!(MoneyH::new(10.0, &JPY).to_decimal_string()?, "10");
+    /// assert_eq!(MoneyH::new(-0.1 - 0.2, &USD).to_decimal_string()?, "-0.30");
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn to_decimal_string(&self) -> Result<String, MoneyConversionError<Cur>> {
+        let subunits = to_subunits::<LowestSubunit, _>(*self)?;
+        let exponent = get_exponent(&subunits)?;
+        Ok(format_subunits(subunits.amount.into(), exponent))
+    }
+
+    /// The amount in whole subunits, rounded like `to_subunits` but not narrowed to `i32`.
+    fn rounded_subunits(&self) -> Result<f64, MoneyConversionError<Cur>> {
+        let exact = self.amount * get_factor(self)?;
+        Ok(match self.currency.currency().default_rounding() {
+            Some(rounding) => rounding.round(exact),
+            None => round_f64(exact, RoundingMode::HalfEven),
+        })
+    }
+
+    /// Converts to subunits with the currency's registered default rounding, or else rounding
+    /// half to even, e.g. 10.125 USD is 1012 cents and 10.135 USD is 1014.
+    ///
+    /// Fractions of currencies without decimals are not rounded: 10.5 JPY fails with
+    /// `FractionalAmountNotAllowed` unless a rounding is registered for JPY or given with
+    /// `convert_floor`, `convert_ceil` or `convert_trunc`.
+    ///
+    /// The subunits land in any [`AmountBackend`], e.g. `convert::<i64>()` for balances beyond
+    /// the `i32` range.
+    pub fn convert<B: AmountBackend>(
+        self,
+    ) -> Result<MoneyInner<B, Cur>, MoneyConversionError<Cur>> {
         self.try_into()
     }
+
+    /// Converts like `convert`, also reporting the factor, the rounding and the adjustment it
+    /// made.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{HighestUnit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    /// use amount_conversion::rounding::{CurrencyRounding, RoundingMode};
+    ///
+    /// let outcome = MoneyInner::<HighestUnit, _>::new(10.125, &USD).convert_detailed()?;
+    /// assert_eq!(outcome.result.amount(), 1012);
+    /// assert_eq!(outcome.factor_used, 100);
+    /// assert_eq!(outcome.rounding_applied, Some(CurrencyRounding::new(RoundingMode::HalfEven)));
+    /// assert!((outcome.delta_subunits + 0.5).abs() < 1e-9);
+    ///
+    /// let exact = MoneyInner::<HighestUnit, _>::new(0.29, &USD).convert_detailed()?;
+    /// assert_eq!((exact.result.amount(), exact.rounding_applied), (29, None));
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn convert_detailed(self) -> Result<ConversionOutcome<Cur>, MoneyConversionError<Cur>> {
+        let result = self.convert::<LowestSubunit>()?;
+        let factor = get_factor(&self)?;
+        let delta_subunits = f64::from(result.amount) - self.amount * factor;
+        let rounding = self
+            .currency
+            .currency()
+            .default_rounding()
+            .unwrap_or_else(|| CurrencyRounding::new(RoundingMode::HalfEven));
+        Ok(ConversionOutcome {
+            result,
+            factor_used: factor as i64,
+            rounding_applied: (delta_subunits.abs() >= noise_tolerance(self.amount * factor))
+                .then_some(rounding),
+            delta_subunits,
+        })
+    }
+
+    /// Converts to subunits rounding toward negative infinity, e.g. 10.129 USD is 1012 cents.
+    ///
+    /// Products that are a whole subunit up to float noise are taken as that subunit, so
+    /// `0.29` USD is 29 cents with every direction.
+    pub fn convert_floor<B: AmountBackend>(
+        self,
+    ) -> Result<MoneyInner<B, Cur>, MoneyConversionError<Cur>> {
+        self.convert_rounding(RoundingMode::Floor)
+    }
+
+    /// Converts to subunits rounding toward positive infinity, e.g. 10.121 USD is 1013 cents.
+    pub fn convert_ceil<B: AmountBackend>(
+        self,
+    ) -> Result<MoneyInner<B, Cur>, MoneyConversionError<Cur>> {
+        self.convert_rounding(RoundingMode::Ceil)
+    }
+
+    /// Converts to subunits rounding toward zero, e.g. -10.129 USD is -1012 cents.
+    pub fn convert_trunc<B: AmountBackend>(
+        self,
+    ) -> Result<MoneyInner<B, Cur>, MoneyConversionError<Cur>> {
+        self.convert_rounding(RoundingMode::Trunc)
+    }
+
+    /// Snaps the amount to the nearest whole subunit with `rounding`, returning the canonical
+    /// amount together with the adjustment made, so values are compared and stored canonically.
+    ///
+    /// Fails like `convert` when the amount does not fit the subunit range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{HighestUnit, LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    /// use amount_conversion::rounding::RoundingMode;
+    ///
+    /// let normalized = MoneyInner::<HighestUnit, _>::new(10.126, &USD).normalize(RoundingMode::HalfEven)?;
+    /// assert_eq!(normalized.value.amount(), 10.13);
+    /// assert_eq!(normalized.subunits, MoneyInner::<LowestSubunit, _>::new(1013, &USD));
+    /// assert!((normalized.delta - 0.004).abs() < 1e-9);
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn normalize<B: AmountBackend>(
+        self,
+        rounding: RoundingMode,
+    ) -> Result<Normalized<Cur, B>, MoneyConversionError<Cur>> {
+        let subunits = self.round_to_subunits(rounding)?;
+        let value = to_major_units(subunits)?;
+        Ok(Normalized {
+            value,
+            subunits,
+            delta: value.amount - self.amount,
+        })
+    }
+
+    /// Converts with `factor` subunits per major unit instead of the currency's own factor.
+    pub fn convert_with_factor(
+        self,
+        factor: u32,
+    ) -> Result<MoneyInner<LowestSubunit, Cur>, MoneyConversionError<Cur>> {
+        let subunits = checked_factor(factor).and_then(|factor| {
+            let subunits = f64_to_i32(self.amount * factor, self.currency)?;
+            Ok(MoneyInner::<LowestSubunit, Cur>::new(
+                subunits,
+                &self.currency,
+            ))
+        });
+        observe(ConversionKind::ToSubunits, &self.currency, subunits)
+    }
+
+    fn convert_rounding<B: AmountBackend>(
+        self,
+        rounding: RoundingMode,
+    ) -> Result<MoneyInner<B, Cur>, MoneyConversionError<Cur>> {
+        observe(
+            ConversionKind::ToSubunits,
+            &self.currency,
+            self.round_to_subunits(rounding),
+        )
+    }
+
+    fn round_to_subunits<B: AmountBackend>(
+        self,
+        rounding: RoundingMode,
+    ) -> Result<MoneyInner<B, Cur>, MoneyConversionError<Cur>> {
+        let factor = get_factor(&self)?;
+        let exact = self.amount * factor;
+        let subunits = B::from_f64(round_f64(snap_to_whole(exact), rounding), self.currency)?;
+        #[cfg(feature = "tracing")]
+        trace_narrowing(&self, factor, exact, subunits);
+        Ok(MoneyInner::<B, Cur>::new(subunits, &self.currency))
+    }
+}
+
+/// Records a major-unit amount narrowed to whole subunits, with the rounding delta in subunits.
+#[cfg(feature = "tracing")]
+fn trace_narrowing<B: AmountBackend, Cur: FromCurrency>(
+    value: &MoneyInner<HighestUnit, Cur>,
+    factor: f64,
+    exact: f64,
+    subunits: B,
+) {
+    tracing::debug!(
+        currency = value.currency.currency_code(),
+        factor,
+        major = value.amount,
+        subunits = ?subunits,
+        delta = subunits.to_f64() - exact,
+        "converted major units to subunits"
+    );
+}
+
+fn checked_factor<T>(factor: u32) -> Result<f64, MoneyConversionError<T>> {
+    if factor == 0 {
+        return Err(MoneyConversionError::InvalidFactor(factor));
+    }
+    Ok(f64::from(factor))
+}
+
+impl<Cur: FromCurrency> MoneyInner<WideSubunit, Cur> {
+    /// The total back in `LowestSubunit`, failing with `I128ToI32ConversionFailed` when it does
+    /// not fit.
+    pub fn narrow(self) -> Result<MoneyInner<LowestSubunit, Cur>, MoneyConversionError<Cur>> {
+        let amount = i128_to_i32(self.amount, self.currency)?;
+        Ok(MoneyInner::<LowestSubunit, _>::new(amount, &self.currency))
+    }
+}
+
+impl<Cur: FromCurrency> From<(WideSubunit, Cur)> for WideMoney<Cur> {
+    fn from((amount, currency): (WideSubunit, Cur)) -> Self {
+        Self::new(amount, &currency)
+    }
+}
+
+impl<Cur: FromCurrency> From<MoneyInner<LowestSubunit, Cur>> for WideMoney<Cur> {
+    fn from(money: MoneyInner<LowestSubunit, Cur>) -> Self {
+        Self::new(money.amount.into(), &money.currency)
+    }
+}
+
+impl<Cur: FromCurrency> From<SignedMoney<Cur>> for WideMoney<Cur> {
+    fn from(money: SignedMoney<Cur>) -> Self {
+        Self::new(money.amount.into(), &money.currency)
+    }
+}
+
+/// Totals subunit amounts of one currency in `i128`, which no realistic number of `i32` or
+/// `i64` amounts can overflow.
+///
+/// Fails with `CurrencyMismatch` on the first amount in another currency than the first one,
+/// and with `EmptySum` when there are no amounts.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{sum_to_i128, LowestSubunit, MoneyInner, WideMoney};
+/// use amount_conversion::factor::Currency::{self, USD};
+///
+/// type Money = MoneyInner<LowestSubunit, Currency>;
+///
+/// let month = vec![Money::new(i32::MAX, &USD); 3];
+/// let (total, currency) = sum_to_i128(month)?;
+/// assert_eq!((total, currency), (6_442_450_941, USD));
+/// let total = WideMoney::from((total, currency));
+/// assert!(total.narrow().is_err());
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+/// ```
+pub fn sum_to_i128<Amt, Cur>(
+    money: impl IntoIterator<Item = MoneyInner<Amt, Cur>>,
+) -> Result<(WideSubunit, Cur), MoneyConversionError<Cur>>
+where
+    Amt: Into<WideSubunit>,
+    Cur: FromCurrency,
+{
+    let mut money = money.into_iter();
+    let first = money.next().ok_or(MoneyConversionError::EmptySum)?;
+    let currency = first.currency;
+    let mut total: WideSubunit = first.amount.into();
+    for money in money {
+        if money.currency != currency {
+            return Err(MoneyConversionError::CurrencyMismatch(
+                currency,
+                money.currency,
+            ));
+        }
+        total = total
+            .checked_add(money.amount.into())
+            .ok_or(MoneyConversionError::AmountOverflow)?;
+    }
+    Ok((total, currency))
 }
 
-fn f64_to_i32<T>(f: f64) -> Result<i32, MoneyConversionError<T>> {
-    if f > MAX_F64_ALLOWED || f < MIN_F64_ALLOWED {
-        return Err(MoneyConversionError::F64ToI32ConversionFailed);
+/// Rounds a subunit value half to even and narrows it, so float noise like `1099.9999999`
+/// becomes 1100 rather than losing a subunit.
+pub(crate) fn f64_to_i32<T>(f: f64, currency: T) -> Result<i32, MoneyConversionError<T>> {
+    let f = round_f64(f, RoundingMode::HalfEven);
+    // NaN fails the range check and reports the maximum, like `ConversionPolicy`
+    if !(MIN_F64_ALLOWED..=MAX_F64_ALLOWED).contains(&f) {
+        return Err(MoneyConversionError::F64ToI32ConversionFailed {
+            value: f,
+            bound: if f < 0.0 { i32::MIN } else { i32::MAX },
+            currency,
+        });
     }
     Ok(f as i32)
 }
 
+/// Narrows an exact intermediate result back into the subunit range.
+/// The checked parts of an amount given as sign, major and minor units, kept apart so builders
+/// can report both: the minor part against the currency's exponent, and the amount, without an
+/// invalid minor part, against the subunit range.
+pub(crate) struct MajorMinor<Cur> {
+    pub(crate) minor: Option<MoneyConversionError<Cur>>,
+    pub(crate) amount: Result<i32, MoneyConversionError<Cur>>,
+}
+
+impl<Cur: FromCurrency> MajorMinor<Cur> {
+    /// Fails only when the currency's factor cannot be used.
+    pub(crate) fn check(
+        negative: bool,
+        major: u32,
+        minor: u32,
+        currency: &Cur,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        let factor = get_factor(&MoneyInner::<LowestSubunit, _>::zero(currency))? as i128;
+        let mut magnitude = i128::from(major) * factor;
+        let minor = if i128::from(minor) < factor {
+            magnitude += i128::from(minor);
+            None
+        } else {
+            Some(MoneyConversionError::MinorUnitsOutOfRange(minor))
+        };
+        let amount = if negative { -magnitude } else { magnitude };
+        Ok(Self {
+            minor,
+            amount: i128_to_i32(amount, *currency),
+        })
+    }
+}
+
+pub(crate) fn i128_to_i32<T>(value: i128, currency: T) -> Result<i32, MoneyConversionError<T>> {
+    i32::try_from(value).map_err(|_| MoneyConversionError::I128ToI32ConversionFailed {
+        value,
+        bound: if value < 0 { i32::MIN } else { i32::MAX },
+        currency,
+    })
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -115,7 +1146,7 @@ mod tests {
         }
     }
 
-    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, serde::Deserialize)]
+    #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, PartialOrd, Ord, serde::Deserialize)]
     enum Currency {
         Inr,
         Usd,
@@ -156,6 +1187,37 @@ mod tests {
         Ok(())
     }
 
+    fn assert_quotients(subunits: impl Iterator<Item = i32>, exponent: u32) {
+        let factors = factor::factors_of(exponent);
+        for subunits in subunits {
+            let subunits = f64::from(subunits);
+            assert_eq!(
+                factor::to_major(subunits, factors).to_bits(),
+                (subunits / factors.0).to_bits(),
+                "{subunits} at exponent {exponent}"
+            );
+        }
+    }
+
+    #[test]
+    fn reciprocal_factors_match_division() {
+        for exponent in 0..=factor::MAX_EXPONENT {
+            assert_quotients((i32::MIN..=i32::MAX).step_by(4_099), exponent);
+            assert_quotients(
+                (-100_000..=100_000).chain([i32::MAX - 1, i32::MAX]),
+                exponent,
+            );
+        }
+    }
+
+    #[test]
+    #[ignore = "walks every i32 for every exponent, which takes minutes"]
+    fn reciprocal_factors_match_division_for_every_i32() {
+        for exponent in 0..=factor::MAX_EXPONENT {
+            assert_quotients(i32::MIN..=i32::MAX, exponent);
+        }
+    }
+
     #[test]
     fn i32_max_number_without_amount() {
         let amount_lhs = i32::MAX;
@@ -193,10 +1255,37 @@ mod tests {
         let lowest_unit: Result<Money, _> = amount_lhs.convert();
         assert_eq!(
             lowest_unit,
-            Err(MoneyConversionError::F64ToI32ConversionFailed)
+            Err(MoneyConversionError::F64ToI32ConversionFailed {
+                value: f64::INFINITY,
+                bound: i32::MAX,
+                currency: Currency::Usd
+            })
+        );
+        assert_eq!(
+            lowest_unit.unwrap_err().to_string(),
+            "inf subunits of Usd exceed the i32 maximum 2147483647"
         );
     }
 
+    #[test]
+    fn nan_is_rejected() {
+        let nan = MoneyHD::new(f64::NAN, &Currency::Usd);
+        let failed = |result: Result<Money, _>| {
+            matches!(
+                result,
+                Err(MoneyConversionError::F64ToI32ConversionFailed {
+                    value,
+                    bound: i32::MAX,
+                    currency: Currency::Usd
+                }) if value.is_nan()
+            )
+        };
+        assert!(failed(nan.convert()));
+        assert!(failed(nan.convert_floor()));
+        assert!(failed(nan.convert_trunc()));
+        assert!(failed(nan.convert_with_factor(100)));
+    }
+
     #[test]
     fn f64_max_number_without_amount() {
         let amount_lhs = f64::MAX;
@@ -205,6 +1294,559 @@ mod tests {
         assert_ne!(amount_lhs, highest_unit); // This is invalid but as_conversion fails here
     }
 
+    #[test]
+    fn accessors() {
+        let amount = Money::new(1050, &Currency::Usd);
+        assert_eq!(amount.amount(), 1050);
+        assert_eq!(amount.currency(), Currency::Usd);
+        assert_eq!(amount.into_parts(), (1050, Currency::Usd));
+
+        let amount = MoneyHD::new(10.5, &Currency::Inr);
+        assert_eq!(amount.amount(), 10.5);
+        assert_eq!(amount.currency(), Currency::Inr);
+        assert_eq!(amount.into_parts(), (10.5, Currency::Inr));
+    }
+
+    #[test]
+    fn hash_set_dedup() {
+        let refunds = [
+            Money::new(500, &Currency::Inr),
+            Money::new(500, &Currency::Usd),
+            Money::new(500, &Currency::Inr),
+        ];
+        let unique: std::collections::HashSet<_> = refunds.into_iter().collect();
+        assert_eq!(unique.len(), 2);
+        assert!(unique.contains(&Money::new(500, &Currency::Usd)));
+    }
+
+    #[test]
+    fn ordering_currency_then_amount() {
+        let mut amounts = vec![
+            Money::new(5, &Currency::Usd),
+            Money::new(700, &Currency::Inr),
+            Money::new(-3, &Currency::Usd),
+            Money::new(20, &Currency::Inr),
+        ];
+        amounts.sort();
+        assert_eq!(
+            amounts,
+            [
+                Money::new(20, &Currency::Inr),
+                Money::new(700, &Currency::Inr),
+                Money::new(-3, &Currency::Usd),
+                Money::new(5, &Currency::Usd),
+            ]
+        );
+
+        let report: std::collections::BTreeMap<_, _> = amounts
+            .iter()
+            .map(|amount| (*amount, amount.amount()))
+            .collect();
+        assert_eq!(report.keys().next(), Some(&Money::new(20, &Currency::Inr)));
+    }
+
+    #[test]
+    fn combinators() {
+        use crate::factor::Currency::*;
+
+        let amount = MoneyInner::<LowestSubunit, _>::new(1050, &USD);
+        assert_eq!(
+            amount.map_amount(|amount| amount * 2),
+            MoneyInner::<LowestSubunit, _>::new(2100, &USD)
+        );
+        assert_eq!(amount.map_amount(i64::from).into_parts(), (1050_i64, USD));
+        assert_eq!(
+            amount.with_amount(5),
+            MoneyInner::<LowestSubunit, _>::new(5, &USD)
+        );
+        assert_eq!(
+            amount.recast_currency(&EUR),
+            Ok(MoneyInner::<LowestSubunit, _>::new(1050, &EUR))
+        );
+        assert_eq!(
+            amount.recast_currency(&JPY),
+            Err(MoneyConversionError::SubunitMismatch(USD, JPY))
+        );
+    }
+
+    #[test]
+    fn zero_and_one_major_unit() -> Result<(), MoneyConversionError<factor::Currency>> {
+        use crate::factor::Currency::*;
+
+        type Money = MoneyInner<LowestSubunit, factor::Currency>;
+        assert!(Money::zero(&USD).is_zero());
+        assert!(!Money::new(1, &USD).is_zero());
+        assert!(MoneyInner::<HighestUnit, _>::zero(&USD).is_zero());
+        assert_eq!(Money::one_major_unit(&USD)?, Money::new(100, &USD));
+        assert_eq!(Money::one_major_unit(&JPY)?, Money::new(1, &JPY));
+        assert_eq!(Money::one_major_unit(&KWD)?.convert()?.amount(), 1.0);
+        Ok(())
+    }
+
+    #[test]
+    fn from_major_minor() {
+        use crate::factor::Currency::*;
+
+        type Money = MoneyInner<LowestSubunit, factor::Currency>;
+        assert_eq!(
+            Money::from_major_minor(false, 12, 34, &USD),
+            Ok(Money::new(1234, &USD))
+        );
+        assert_eq!(
+            Money::from_major_minor(true, 12, 5, &USD),
+            Ok(Money::new(-1205, &USD))
+        );
+        assert_eq!(
+            Money::from_major_minor(true, 0, 34, &USD),
+            Ok(Money::new(-34, &USD))
+        );
+        assert_eq!(
+            Money::from_major_minor(true, 0, 0, &USD),
+            Ok(Money::new(0, &USD))
+        );
+        assert_eq!(
+            Money::from_major_minor(false, 12, 345, &BHD),
+            Ok(Money::new(12345, &BHD))
+        );
+        assert_eq!(
+            Money::from_major_minor(false, 12, 0, &JPY),
+            Ok(Money::new(12, &JPY))
+        );
+        assert_eq!(
+            Money::from_major_minor(false, 12, 34, &JPY),
+            Err(MoneyConversionError::MinorUnitsOutOfRange(34))
+        );
+        assert_eq!(
+            Money::from_major_minor(false, 1, 100, &USD),
+            Err(MoneyConversionError::MinorUnitsOutOfRange(100))
+        );
+        assert_eq!(
+            Money::from_major_minor(false, 21_474_837, 0, &USD),
+            Err(MoneyConversionError::I128ToI32ConversionFailed {
+                value: 2_147_483_700,
+                bound: i32::MAX,
+                currency: USD
+            })
+        );
+        assert_eq!(
+            Money::from_major_minor(true, 21_474_836, 48, &USD),
+            Ok(Money::new(i32::MIN, &USD))
+        );
+    }
+
+    #[test]
+    fn assign_operators() {
+        let mut total = Money::zero(&Currency::Inr);
+        for amount in [100, 250, -50] {
+            total += Money::new(amount, &Currency::Inr);
+        }
+        total -= Money::new(100, &Currency::Inr);
+        assert_eq!(total, Money::new(200, &Currency::Inr));
+        assert_eq!(
+            total + Money::new(1, &Currency::Inr) - Money::new(2, &Currency::Inr),
+            Money::new(199, &Currency::Inr)
+        );
+
+        assert_eq!(
+            total.try_add_assign(Money::new(1, &Currency::Usd)),
+            Err(MoneyConversionError::CurrencyMismatch(
+                Currency::Inr,
+                Currency::Usd
+            ))
+        );
+        assert_eq!(
+            total.try_sub_assign(Money::new(i32::MAX, &Currency::Inr)),
+            Ok(())
+        );
+        assert_eq!(
+            total.try_sub_assign(Money::new(i32::MAX, &Currency::Inr)),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+        assert_eq!(total, Money::new(200 - i32::MAX, &Currency::Inr));
+    }
+
+    #[test]
+    #[should_panic(expected = "currency mismatch between Inr and Usd")]
+    fn add_assign_currency_mismatch_panics() {
+        let mut total = Money::zero(&Currency::Inr);
+        total += Money::new(1, &Currency::Usd);
+    }
+
+    #[test]
+    fn diff() -> Result<(), MoneyConversionError<Currency>> {
+        let diff = Money::new(1500, &Currency::Usd).diff(Money::new(1000, &Currency::Usd))?;
+        assert_eq!(diff.amount(), 500);
+        assert_eq!(diff.currency(), Currency::Usd);
+        assert_eq!(
+            Money::new(i32::MIN, &Currency::Usd)
+                .diff(Money::new(1, &Currency::Usd))?
+                .amount(),
+            i64::from(i32::MIN) - 1
+        );
+        assert_eq!(
+            Money::new(1, &Currency::Usd).diff(Money::new(1, &Currency::Inr)),
+            Err(MoneyConversionError::CurrencyMismatch(
+                Currency::Usd,
+                Currency::Inr
+            ))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn percent_change() -> Result<(), MoneyConversionError<Currency>> {
+        let base = Money::new(1000, &Currency::Usd);
+        assert_eq!(Money::new(1250, &Currency::Usd).percent_change(base)?, 25.0);
+        assert_eq!(Money::new(500, &Currency::Usd).percent_change(base)?, -50.0);
+        assert_eq!(
+            Money::new(-500, &Currency::Usd).percent_change(Money::new(-1000, &Currency::Usd))?,
+            50.0
+        );
+        assert_eq!(
+            base.percent_change(Money::zero(&Currency::Usd)),
+            Err(MoneyConversionError::DivisionByZero)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn convert_with_factor() -> Result<(), MoneyConversionError<Currency>> {
+        let major = Money::new(12345, &Currency::Usd).convert_with_factor(1000)?;
+        assert_eq!(major, MoneyHD::new(12.345, &Currency::Usd));
+        assert_eq!(
+            major.convert_with_factor(10)?,
+            Money::new(123, &Currency::Usd)
+        );
+        assert_eq!(
+            Money::new(1, &Currency::Usd).convert_with_factor(0),
+            Err(MoneyConversionError::InvalidFactor(0))
+        );
+        assert_eq!(
+            MoneyHD::new(1.0, &Currency::Usd).convert_with_factor(0),
+            Err(MoneyConversionError::InvalidFactor(0))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn directed_conversions() -> Result<(), MoneyConversionError<Currency>> {
+        let cases = [
+            (10.129, (1012, 1013, 1012)),
+            (-10.129, (-1013, -1012, -1012)),
+            (0.29, (29, 29, 29)),
+            (1.1, (110, 110, 110)),
+        ];
+        for (major, (floor, ceil, trunc)) in cases {
+            let amount = MoneyHD::new(major, &Currency::Usd);
+            assert_eq!(
+                amount.convert_floor::<LowestSubunit>()?.amount(),
+                floor,
+                "{major} floor"
+            );
+            assert_eq!(
+                amount.convert_ceil::<LowestSubunit>()?.amount(),
+                ceil,
+                "{major} ceil"
+            );
+            assert_eq!(
+                amount.convert_trunc::<LowestSubunit>()?.amount(),
+                trunc,
+                "{major} trunc"
+            );
+        }
+        assert_eq!(
+            MoneyHD::new(f64::from(i32::MAX), &Currency::Usd).convert_ceil::<LowestSubunit>(),
+            Err(MoneyConversionError::F64ToI32ConversionFailed {
+                value: 214_748_364_700.0,
+                bound: i32::MAX,
+                currency: Currency::Usd
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn directed_conversions_of_large_amounts() -> Result<(), MoneyConversionError<Currency>> {
+        // products this large land a ULP off the whole number, far more than a fixed epsilon
+        let floor = MoneyHD::new(10_000_039_595.05, &Currency::Usd);
+        let ceil = MoneyHD::new(10_000_023_757.03, &Currency::Usd);
+        assert_eq!(floor.convert_floor::<i64>()?.amount(), 1_000_003_959_505);
+        assert_eq!(floor.convert_floor::<i128>()?.amount(), 1_000_003_959_505);
+        assert_eq!(ceil.convert_ceil::<i64>()?.amount(), 1_000_002_375_703);
+        assert_eq!(ceil.convert_ceil::<i128>()?.amount(), 1_000_002_375_703);
+        assert_eq!(
+            MoneyHD::new(-10_000_039_595.05, &Currency::Usd)
+                .convert_ceil::<i64>()?
+                .amount(),
+            -1_000_003_959_505
+        );
+        assert_eq!(
+            MoneyHD::new(10_000_039_595.051, &Currency::Usd)
+                .convert_floor::<i64>()?
+                .amount(),
+            1_000_003_959_505
+        );
+        assert_eq!(
+            MoneyHD::new(10_000_039_595.051, &Currency::Usd)
+                .convert_ceil::<i64>()?
+                .amount(),
+            1_000_003_959_506
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn error_codes() {
+        let mismatch = MoneyConversionError::CurrencyMismatch(Currency::Usd, Currency::Inr);
+        assert_eq!(mismatch.code(), "currency_mismatch");
+        assert_eq!(
+            MoneyConversionError::<Currency>::TooManyDecimals(2).code(),
+            "too_many_decimals"
+        );
+    }
+
+    #[test]
+    fn large_exponent_override() {
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+        struct Token(u32);
+
+        impl FromCurrency for Token {
+            fn currency(&self) -> factor::Currency {
+                USD
+            }
+
+            fn exponent_override(&self) -> Option<u32> {
+                Some(self.0)
+            }
+        }
+
+        type Money = MoneyInner<LowestSubunit, Token>;
+        let wei = Token(18);
+        assert_eq!(
+            Money::from_str_exact("0.000000001", &wei),
+            Ok(Money::new(1_000_000_000, &wei))
+        );
+        assert_eq!(
+            Money::one_major_unit(&wei),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+        assert_eq!(
+            Money::from_major_minor(false, 1, 5, &wei),
+            Err(MoneyConversionError::I128ToI32ConversionFailed {
+                value: 1_000_000_000_000_000_005,
+                bound: i32::MAX,
+                currency: wei
+            })
+        );
+        assert_eq!(
+            Money::from_str_exact("1", &Token(19)),
+            Err(MoneyConversionError::InvalidFactor(19))
+        );
+    }
+
+    #[test]
+    fn recast_between_currency_types() {
+        let iso = MoneyInner::<LowestSubunit, _>::new(1_050, &USD);
+        assert_eq!(
+            iso.recast(&Currency::Usd),
+            Ok(Money::new(1_050, &Currency::Usd))
+        );
+        assert_eq!(
+            MoneyHD::new(10.5, &Currency::Inr).recast(&INR),
+            Ok(MoneyInner::<HighestUnit, _>::new(10.5, &INR))
+        );
+        assert_eq!(
+            iso.recast(&Currency::Inr),
+            Err(MoneyConversionError::CurrencyMismatch(USD, INR))
+        );
+        let cents = factor::CurrencyCode::register("RCC", USD, 2).unwrap();
+        assert_eq!(
+            iso.recast(&cents),
+            Err(MoneyConversionError::CurrencyMismatch(USD, USD))
+        );
+        assert_eq!(
+            iso.recast(&factor::CurrencyCode::from(USD)),
+            Ok(MoneyInner::<LowestSubunit, _>::new(
+                1_050,
+                &factor::CurrencyCode::from(USD)
+            ))
+        );
+    }
+
+    #[test]
+    fn div_rem() -> Result<(), MoneyConversionError<Currency>> {
+        let parts = |amount, n| {
+            Money::new(amount, &Currency::Inr)
+                .div_rem(n)
+                .map(|(part, remainder)| (part.amount(), remainder.amount()))
+        };
+        assert_eq!(parts(100, 4)?, (25, 0));
+        assert_eq!(parts(-101, 4)?, (-25, -1));
+        assert_eq!(parts(5, 7)?, (0, 5));
+        assert_eq!(parts(i32::MIN, u32::MAX)?, (0, i32::MIN));
+        assert_eq!(parts(i32::MIN, 1)?, (i32::MIN, 0));
+        assert_eq!(parts(1, 0), Err(MoneyConversionError::DivisionByZero));
+        Ok(())
+    }
+
+    #[test]
+    fn sums_to_i128() -> Result<(), MoneyConversionError<Currency>> {
+        let month = vec![Money::new(i32::MIN, &Currency::Usd); 4];
+        let (total, currency) = sum_to_i128(month)?;
+        assert_eq!((total, currency), (4 * i128::from(i32::MIN), Currency::Usd));
+        let signed = [
+            SignedMoney::new(i64::MAX, &Currency::Inr),
+            SignedMoney::new(i64::MAX, &Currency::Inr),
+        ];
+        assert_eq!(sum_to_i128(signed)?.0, 2 * i128::from(i64::MAX));
+        assert_eq!(
+            sum_to_i128([Money::new(1, &Currency::Usd), Money::new(1, &Currency::Inr)]),
+            Err(MoneyConversionError::CurrencyMismatch(
+                Currency::Usd,
+                Currency::Inr
+            ))
+        );
+        assert_eq!(
+            sum_to_i128(Vec::<Money>::new()),
+            Err(MoneyConversionError::EmptySum)
+        );
+        assert_eq!(
+            WideMoney::from((-5, Currency::Inr)).narrow(),
+            Ok(Money::new(-5, &Currency::Inr))
+        );
+        assert_eq!(
+            WideMoney::from(Money::new(i32::MAX, &Currency::Usd)).amount(),
+            i128::from(i32::MAX)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn fractional_zero_decimal_amounts() {
+        type Major = MoneyInner<HighestUnit, factor::Currency>;
+        let subunits =
+            |converted: Result<MoneyInner<LowestSubunit, _>, _>| converted.map(|m| m.amount());
+        assert_eq!(
+            Major::new(10.5, &JPY).convert::<LowestSubunit>(),
+            Err(MoneyConversionError::FractionalAmountNotAllowed(JPY))
+        );
+        assert_eq!(subunits(Major::new(10.0, &JPY).convert()), Ok(10));
+        assert_eq!(subunits(Major::new(10.5, &JPY).convert_floor()), Ok(10));
+        assert_eq!(subunits(Major::new(10.125, &USD).convert()), Ok(1012));
+        factor::Currency::register_rounding(PYG, RoundingMode::HalfUp);
+        assert_eq!(subunits(Major::new(10.5, &PYG).convert()), Ok(11));
+    }
+
+    #[test]
+    fn approx_eq_highest_units() {
+        let money = |amount| MoneyInner::<HighestUnit, _>::new(amount, &KWD);
+        assert_eq!(money(1.0004).approx_eq(&money(1.0006), 0), Ok(false));
+        assert_eq!(money(1.0004).approx_eq(&money(1.0006), 1), Ok(true));
+        assert_eq!(
+            money(f64::NAN).approx_eq(&money(f64::NAN), u32::MAX),
+            Ok(false)
+        );
+        assert_eq!(
+            money(1.0).approx_eq(&MoneyInner::<HighestUnit, _>::new(1.0, &BHD), 0),
+            Err(MoneyConversionError::CurrencyMismatch(KWD, BHD))
+        );
+    }
+
+    #[test]
+    fn normalize_snaps_to_subunits() {
+        let normalize = |amount, rounding| {
+            MoneyInner::<HighestUnit, _>::new(amount, &KWD)
+                .normalize::<LowestSubunit>(rounding)
+                .map(|normalized| (normalized.subunits.amount(), normalized.value.amount()))
+        };
+        assert_eq!(normalize(1.2345, RoundingMode::HalfEven), Ok((1234, 1.234)));
+        assert_eq!(normalize(1.2345, RoundingMode::Ceil), Ok((1235, 1.235)));
+        assert_eq!(normalize(0.1 + 0.2, RoundingMode::Floor), Ok((300, 0.3)));
+        assert_eq!(
+            MoneyInner::<HighestUnit, _>::new(5.0, &KWD)
+                .normalize::<LowestSubunit>(RoundingMode::Trunc)
+                .map(|normalized| normalized.delta),
+            Ok(0.0)
+        );
+        assert!(normalize(3e6, RoundingMode::HalfEven).is_err());
+    }
+
+    #[test]
+    fn ordered_money() {
+        use std::collections::HashSet;
+
+        let ordered = |amount| OrderedMoney::new(MoneyInner::<HighestUnit, _>::new(amount, &USD));
+        assert_eq!(ordered(-0.0), ordered(0.0));
+        assert_eq!(ordered(f64::NAN), ordered(-f64::NAN));
+        assert_ne!(ordered(0.1 + 0.2), ordered(0.3));
+        assert!(ordered(f64::NEG_INFINITY) < ordered(-1.0));
+        assert!(ordered(-f64::NAN) > ordered(f64::INFINITY));
+        assert!(OrderedMoney::new(MoneyInner::<HighestUnit, _>::new(9.0, &EUR)) < ordered(1.0));
+        let set: HashSet<_> = [
+            ordered(0.0),
+            ordered(-0.0),
+            ordered(f64::NAN),
+            ordered(f64::NAN),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn convert_detailed_reports_rounding() -> Result<(), MoneyConversionError<factor::Currency>> {
+        factor::Currency::register_rounding(
+            MYR,
+            CurrencyRounding::new(RoundingMode::HalfUp).with_increment(5),
+        );
+        let outcome = MoneyInner::<HighestUnit, _>::new(10.12, &MYR).convert_detailed()?;
+        assert_eq!(outcome.result.amount(), 1010);
+        assert_eq!(outcome.rounding_applied.map(|r| r.increment()), Some(5));
+        assert!((outcome.delta_subunits + 2.0).abs() < 1e-9);
+        let outcome = MoneyInner::<HighestUnit, _>::new(1.5, &KWD).convert_detailed()?;
+        assert_eq!(
+            (outcome.factor_used, outcome.rounding_applied),
+            (1_000, None)
+        );
+        assert_eq!(
+            MoneyInner::<HighestUnit, _>::new(1.5, &JPY).convert_detailed(),
+            Err(MoneyConversionError::FractionalAmountNotAllowed(JPY))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn checked_arithmetic() -> Result<(), MoneyConversionError<Currency>> {
+        let amount = Money::new(150, &Currency::Inr);
+        assert_eq!(
+            amount.checked_add(Money::new(50, &Currency::Inr))?,
+            Money::new(200, &Currency::Inr)
+        );
+        assert_eq!(
+            amount.checked_sub(Money::new(200, &Currency::Inr))?,
+            Money::new(-50, &Currency::Inr)
+        );
+        assert_eq!(amount.checked_mul(3)?, Money::new(450, &Currency::Inr));
+        assert_eq!(amount.checked_neg()?, Money::new(-150, &Currency::Inr));
+
+        assert_eq!(
+            amount.checked_add(Money::new(50, &Currency::Usd)),
+            Err(MoneyConversionError::CurrencyMismatch(
+                Currency::Inr,
+                Currency::Usd
+            ))
+        );
+        assert_eq!(
+            Money::new(i32::MAX, &Currency::Inr).checked_add(Money::new(1, &Currency::Inr)),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+        assert_eq!(
+            Money::new(i32::MIN, &Currency::Inr).checked_neg(),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+        Ok(())
+    }
+
     #[test]
     fn deserialize() -> Result<(), serde_json::Error> {
         let amount_str = r#"{
@@ -0,0 +1,168 @@
+//! `axum` integration, enabled with the `axum` feature.
+//!
+//! [`MoneyJson`] is a drop-in for `axum::Json` whose rejection, like `MoneyConversionError`
+//! itself, responds with a JSON body `{"code": .., "message": ..}` instead of plain text, so
+//! handlers can use `?` on conversions and still return structured errors.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+//! use amount_conversion::axum_support::MoneyJson;
+//! use amount_conversion::factor::Currency;
+//!
+//! type Money = MoneyInner<LowestSubunit, Currency>;
+//!
+//! async fn double(
+//!     MoneyJson(money): MoneyJson<Money>,
+//! ) -> Result<MoneyJson<Money>, MoneyConversionError<Currency>> {
+//!     Ok(MoneyJson(money.checked_mul(2)?))
+//! }
+//!
+//! let app: axum::Router = axum::Router::new().route("/double", axum::routing::post(double));
+//! ```
+use std::fmt;
+
+use axum::extract::rejection::JsonRejection;
+use axum::extract::{FromRequest, Request};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde::Serialize;
+
+use crate::amount::MoneyConversionError;
+use crate::response::ErrorBody;
+
+/// JSON extractor and response that rejects with [`MoneyRejection`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct MoneyJson<T>(pub T);
+
+/// Why a [`MoneyJson`] payload could not be extracted.
+///
+/// Payloads that are valid JSON but not valid money, e.g. an unknown currency or a string
+/// amount, respond with `422 Unprocessable Entity` and code `invalid_money_payload`.
+#[derive(Debug)]
+pub struct MoneyRejection(JsonRejection);
+
+impl MoneyRejection {
+    pub fn status(&self) -> StatusCode {
+        self.0.status()
+    }
+
+    /// The code used in the response body.
+    pub fn code(&self) -> &'static str {
+        match self.0 {
+            JsonRejection::JsonDataError(_) => "invalid_money_payload",
+            JsonRejection::JsonSyntaxError(_) => "invalid_json",
+            JsonRejection::MissingJsonContentType(_) => "missing_json_content_type",
+            _ => "invalid_request_body",
+        }
+    }
+}
+
+impl From<JsonRejection> for MoneyRejection {
+    fn from(rejection: JsonRejection) -> Self {
+        Self(rejection)
+    }
+}
+
+impl fmt::Display for MoneyRejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.body_text())
+    }
+}
+
+impl std::error::Error for MoneyRejection {}
+
+impl IntoResponse for MoneyRejection {
+    fn into_response(self) -> Response {
+        let body = ErrorBody {
+            code: self.code(),
+            message: self.0.body_text(),
+        };
+        (self.status(), Json(body)).into_response()
+    }
+}
+
+impl<S, T> FromRequest<S> for MoneyJson<T>
+where
+    Json<T>: FromRequest<S, Rejection = JsonRejection>,
+    S: Send + Sync,
+{
+    type Rejection = MoneyRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state).await?;
+        Ok(Self(value))
+    }
+}
+
+impl<T: Serialize> IntoResponse for MoneyJson<T> {
+    fn into_response(self) -> Response {
+        Json(self.0).into_response()
+    }
+}
+
+/// Responds with `422 Unprocessable Entity` and the error's code and message.
+impl<T: fmt::Debug> IntoResponse for MoneyConversionError<T> {
+    fn into_response(self) -> Response {
+        let body = ErrorBody::from(&self);
+        (StatusCode::UNPROCESSABLE_ENTITY, Json(body)).into_response()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    use axum::body::Body;
+    use axum::http::header;
+
+    use super::*;
+    use crate::amount::{LowestSubunit, MoneyInner};
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    /// Polls a future that completes without waiting, as extraction from an in-memory body does.
+    fn ready<F: Future>(future: F) -> F::Output {
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(output) => output,
+            Poll::Pending => panic!("future is not ready"),
+        }
+    }
+
+    fn extract(body: &'static str) -> Result<MoneyJson<Money>, MoneyRejection> {
+        let request = Request::post("/")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .unwrap();
+        ready(MoneyJson::<Money>::from_request(request, &()))
+    }
+
+    #[test]
+    fn extracts_money() {
+        let MoneyJson(money) = extract(r#"{"amount": 1050, "currency": "USD"}"#).unwrap();
+        assert_eq!(money, Money::new(1050, &USD));
+    }
+
+    #[test]
+    fn rejects_invalid_payloads() {
+        let rejection = extract(r#"{"amount": 1050, "currency": "XYZ"}"#).unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(rejection.code(), "invalid_money_payload");
+
+        let rejection = extract(r#"{"amount": "#).unwrap_err();
+        assert_eq!(rejection.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(rejection.code(), "invalid_json");
+    }
+
+    #[test]
+    fn conversion_error_response() {
+        let response = MoneyConversionError::CurrencyMismatch(USD, INR).into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+    }
+}
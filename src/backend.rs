@@ -0,0 +1,503 @@
+//! The integer types subunit amounts can be held in.
+//!
+//! Construction, exact parsing, checked arithmetic, conversion between major units and
+//! subunits and decimal formatting are generic over [`AmountBackend`], so they work the same for
+//! `LowestSubunit`, `SignedSubunit` and `WideSubunit` money, and for `rust_decimal::Decimal`
+//! subunits with the `rust-decimal` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{MoneyInner, SignedMoney, WideMoney};
+//! use amount_conversion::factor::Currency::{self, USD};
+//!
+//! let balance = SignedMoney::from_str_exact("30000000000.00", &USD)?;
+//! let total = balance.checked_add(SignedMoney::new(1, &USD))?;
+//! assert_eq!(total.format_spreadsheet(Default::default())?, "30000000000.01");
+//! assert_eq!(total.convert()?.amount(), 30_000_000_000.01);
+//!
+//! let wide: WideMoney<Currency> = total.to_backend()?;
+//! assert_eq!(wide.amount(), 3_000_000_000_001);
+//! assert!(wide.to_backend::<i32>().is_err());
+//!
+//! let cents = MoneyInner::<f64, _>::new(30_000_000_000.01, &USD).convert::<i64>()?;
+//! assert_eq!(cents, SignedMoney::new(3_000_000_000_001, &USD));
+//! # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+//! ```
+use std::fmt;
+use std::hash::Hash;
+
+use crate::amount::{
+    f64_to_i32, i128_to_i32, to_major_units, HighestUnit, MoneyConversionError, MoneyInner,
+};
+use crate::decimal;
+use crate::factor::{get_exponent, FromCurrency};
+use crate::observer::{observe, ConversionKind};
+use crate::rounding::{round_f64, RoundingMode};
+
+/// A numeric type holding an amount in whole subunits.
+pub trait AmountBackend: Copy + Eq + Ord + Hash + fmt::Debug {
+    /// Zero subunits.
+    const ZERO: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sub(self, other: Self) -> Option<Self>;
+
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    fn checked_neg(self) -> Option<Self>;
+
+    /// The amount widened to `i128`, which holds every supported backend exactly.
+    fn to_i128(self) -> i128;
+
+    /// The amount as a float, for conversion to major units.
+    fn to_f64(self) -> f64;
+
+    /// Narrows an exact intermediate result into the backend, failing with an error for
+    /// `currency` when it does not fit.
+    fn narrow<T>(value: i128, currency: T) -> Result<Self, MoneyConversionError<T>>;
+
+    /// Rounds a float subunit value half to even and narrows it into the backend, failing with
+    /// an error for `currency` when it is not finite or does not fit.
+    fn from_f64<T>(value: f64, currency: T) -> Result<Self, MoneyConversionError<T>> {
+        let value = round_f64(value, RoundingMode::HalfEven);
+        // 2^127, the first float beyond the `i128` range
+        if !value.is_finite() || value.abs() >= 1.7014118346046923e38 {
+            return Err(MoneyConversionError::AmountOverflow);
+        }
+        Self::narrow(value as i128, currency)
+    }
+}
+
+macro_rules! impl_amount_backend {
+    ($($ty:ty => $narrow:expr $(, from_f64: $from_f64:expr)?);+ $(;)?) => {
+        $(
+            impl AmountBackend for $ty {
+                const ZERO: Self = 0;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$ty>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$ty>::checked_mul(self, other)
+                }
+
+                fn checked_neg(self) -> Option<Self> {
+                    <$ty>::checked_neg(self)
+                }
+
+                fn to_i128(self) -> i128 {
+                    self.into()
+                }
+
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn narrow<T>(value: i128, currency: T) -> Result<Self, MoneyConversionError<T>> {
+                    $narrow(value, currency)
+                }
+                $(
+                    fn from_f64<T>(value: f64, currency: T) -> Result<Self, MoneyConversionError<T>> {
+                        $from_f64(value, currency)
+                    }
+                )?
+            }
+        )+
+    };
+}
+
+impl_amount_backend! {
+    i32 => i128_to_i32, from_f64: f64_to_i32;
+    i64 => |value: i128, _| {
+        i64::try_from(value).map_err(|_| MoneyConversionError::AmountOverflow)
+    };
+    i128 => |value, _| Ok(value);
+}
+
+/// Whole subunits in a `Decimal`, e.g. `1050` for 10.50 USD; fractions are truncated toward
+/// zero when formatting or moving to another backend.
+#[cfg(feature = "rust-decimal")]
+impl AmountBackend for rust_decimal::Decimal {
+    const ZERO: Self = rust_decimal::Decimal::ZERO;
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        rust_decimal::Decimal::checked_add(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        rust_decimal::Decimal::checked_sub(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        rust_decimal::Decimal::checked_mul(self, other)
+    }
+
+    fn checked_neg(self) -> Option<Self> {
+        Some(-self)
+    }
+
+    fn to_i128(self) -> i128 {
+        self.trunc().mantissa()
+    }
+
+    fn to_f64(self) -> f64 {
+        rust_decimal::prelude::ToPrimitive::to_f64(&self).expect("every decimal fits an f64")
+    }
+
+    fn narrow<T>(value: i128, _: T) -> Result<Self, MoneyConversionError<T>> {
+        rust_decimal::Decimal::try_from_i128_with_scale(value, 0)
+            .map_err(|_| MoneyConversionError::AmountOverflow)
+    }
+}
+
+impl<B: AmountBackend, Cur: FromCurrency> MoneyInner<B, Cur> {
+    pub fn new(amount: B, currency: &Cur) -> Self {
+        Self {
+            amount,
+            currency: *currency,
+        }
+    }
+
+    pub fn amount(&self) -> B {
+        self.amount
+    }
+
+    /// Zero subunits of `currency`, the identity for `checked_add`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::USD;
+    ///
+    /// type Money = MoneyInner<LowestSubunit, amount_conversion::factor::Currency>;
+    ///
+    /// let refunds = [Money::new(250, &USD), Money::new(1000, &USD)];
+    /// let total = refunds
+    ///     .into_iter()
+    ///     .try_fold(Money::zero(&USD), Money::checked_add)
+    ///     .unwrap();
+    /// assert_eq!(total, Money::new(1250, &USD));
+    /// ```
+    pub fn zero(currency: &Cur) -> Self {
+        Self::new(B::ZERO, currency)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.amount == B::ZERO
+    }
+
+    /// Parses a major unit decimal string like `"12.34"` into subunits using integer math only.
+    ///
+    /// Accepts an optional sign and at most as many decimals as the currency allows; exponent
+    /// notation, grouping separators and whitespace are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, *};
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// assert_eq!(Money::from_str_exact("12.34", &USD), Ok(Money::new(1234, &USD)));
+    /// assert_eq!(Money::from_str_exact("12.3", &USD), Ok(Money::new(1230, &USD)));
+    /// assert_eq!(
+    ///     Money::from_str_exact("12.345", &USD),
+    ///     Err(MoneyConversionError::TooManyDecimals(2))
+    /// );
+    /// ```
+    pub fn from_str_exact(s: &str, currency: &Cur) -> Result<Self, MoneyConversionError<Cur>> {
+        let exponent = get_exponent(&Self::zero(currency))?;
+        let amount = decimal::parse_subunits(s, exponent)?;
+        Ok(Self::new(B::narrow(amount.into(), *currency)?, currency))
+    }
+
+    /// Parses a subunit amount written as a plain integer string such as `"-1050"`, as sent by
+    /// gateways that take stringified minor units.
+    ///
+    /// Accepts an optional sign and ASCII digits only; decimals, exponent notation, grouping
+    /// separators and whitespace fail with `InvalidDecimalString`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// let money = Money::from_minor_string("-1050", &USD)?;
+    /// assert_eq!(money, Money::new(-1_050, &USD));
+    /// assert_eq!(money.to_minor_string(), "-1050");
+    /// assert_eq!(
+    ///     Money::from_minor_string("10.50", &USD),
+    ///     Err(MoneyConversionError::InvalidDecimalString)
+    /// );
+    /// # Ok::<(), MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn from_minor_string(s: &str, currency: &Cur) -> Result<Self, MoneyConversionError<Cur>> {
+        let digits = s.strip_prefix(['-', '+']).unwrap_or(s);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(MoneyConversionError::InvalidDecimalString);
+        }
+        let amount = s
+            .parse::<i128>()
+            .map_err(|_| MoneyConversionError::AmountOverflow)?;
+        Ok(Self::new(B::narrow(amount, *currency)?, currency))
+    }
+
+    /// The subunit amount as a plain integer string with a leading `-` when negative, the
+    /// inverse of [`from_minor_string`](Self::from_minor_string).
+    pub fn to_minor_string(&self) -> String {
+        self.amount.to_i128().to_string()
+    }
+
+    /// Converts to major units, e.g. 1050 cents to 10.5 USD.
+    pub fn convert(self) -> Result<MoneyInner<HighestUnit, Cur>, MoneyConversionError<Cur>> {
+        observe(
+            ConversionKind::ToMajorUnits,
+            &self.currency,
+            to_major_units(self),
+        )
+    }
+
+    /// Moves the amount to another backend, failing when it does not fit, e.g. a `WideMoney`
+    /// total back into `LowestSubunit`.
+    pub fn to_backend<B2: AmountBackend>(
+        self,
+    ) -> Result<MoneyInner<B2, Cur>, MoneyConversionError<Cur>> {
+        let amount = B2::narrow(self.amount.to_i128(), self.currency)?;
+        Ok(MoneyInner::<B2, _>::new(amount, &self.currency))
+    }
+
+    /// Adds two amounts of the same currency, failing on a currency mismatch or overflow.
+    pub fn checked_add(self, other: Self) -> Result<Self, MoneyConversionError<Cur>> {
+        self.ensure_same_currency(&other)?;
+        self.with_checked_amount(self.amount.checked_add(other.amount))
+    }
+
+    /// Subtracts two amounts of the same currency, failing on a currency mismatch or overflow.
+    pub fn checked_sub(self, other: Self) -> Result<Self, MoneyConversionError<Cur>> {
+        self.ensure_same_currency(&other)?;
+        self.with_checked_amount(self.amount.checked_sub(other.amount))
+    }
+
+    /// Multiplies the amount by an integer factor, failing on overflow.
+    pub fn checked_mul(self, rhs: B) -> Result<Self, MoneyConversionError<Cur>> {
+        self.with_checked_amount(self.amount.checked_mul(rhs))
+    }
+
+    /// Negates the amount, failing on overflow for the backend's minimum.
+    pub fn checked_neg(self) -> Result<Self, MoneyConversionError<Cur>> {
+        self.with_checked_amount(self.amount.checked_neg())
+    }
+
+    /// Adds `other` in place, leaving `self` untouched on a currency mismatch or overflow.
+    pub fn try_add_assign(&mut self, other: Self) -> Result<(), MoneyConversionError<Cur>> {
+        *self = self.checked_add(other)?;
+        Ok(())
+    }
+
+    /// Subtracts `other` in place, leaving `self` untouched on a currency mismatch or overflow.
+    pub fn try_sub_assign(&mut self, other: Self) -> Result<(), MoneyConversionError<Cur>> {
+        *self = self.checked_sub(other)?;
+        Ok(())
+    }
+
+    /// Renders the amount in major units with exactly the currency's number of decimals.
+    #[cfg_attr(
+        not(any(
+            feature = "cli",
+            feature = "csv",
+            feature = "ffi",
+            feature = "python",
+            feature = "wasm"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn format_decimal(&self) -> Result<String, MoneyConversionError<Cur>> {
+        let exponent = get_exponent(self)?;
+        Ok(decimal::format_subunits(self.amount.to_i128(), exponent))
+    }
+
+    pub(crate) fn ensure_same_currency(
+        &self,
+        other: &Self,
+    ) -> Result<(), MoneyConversionError<Cur>> {
+        if self.currency != other.currency {
+            return Err(MoneyConversionError::CurrencyMismatch(
+                self.currency,
+                other.currency,
+            ));
+        }
+        Ok(())
+    }
+
+    fn with_checked_amount(self, amount: Option<B>) -> Result<Self, MoneyConversionError<Cur>> {
+        let amount = amount.ok_or(MoneyConversionError::AmountOverflow)?;
+        Ok(Self::new(amount, &self.currency))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amount::{LowestSubunit, SignedMoney, WideMoney};
+    use crate::factor::Currency::{self, *};
+    use crate::rounding::RoundingMode;
+
+    fn add_and_format<B: AmountBackend>(
+        a: B,
+        b: B,
+    ) -> Result<String, MoneyConversionError<Currency>> {
+        MoneyInner::<B, _>::new(a, &KWD)
+            .checked_add(MoneyInner::<B, _>::new(b, &KWD))?
+            .format_decimal()
+    }
+
+    #[test]
+    fn backends_agree() {
+        assert_eq!(add_and_format(1_000_i32, 234).as_deref(), Ok("1.234"));
+        assert_eq!(add_and_format(1_000_i64, 234).as_deref(), Ok("1.234"));
+        assert_eq!(add_and_format(1_000_i128, 234).as_deref(), Ok("1.234"));
+        assert_eq!(
+            add_and_format(i32::MAX, 1),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+        assert_eq!(
+            add_and_format(i128::MAX, -1).as_deref(),
+            Ok("170141183460469231731687303715884105.726")
+        );
+    }
+
+    #[test]
+    fn parse_and_narrow() {
+        assert_eq!(
+            MoneyInner::<LowestSubunit, _>::from_str_exact("30000000.00", &USD),
+            Err(MoneyConversionError::I128ToI32ConversionFailed {
+                value: 3_000_000_000,
+                bound: i32::MAX,
+                currency: USD
+            })
+        );
+        let wide = WideMoney::new(i128::from(i64::MIN) - 1, &USD);
+        assert_eq!(
+            wide.to_backend::<i64>(),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+        assert_eq!(
+            SignedMoney::new(-5, &USD).checked_mul(3),
+            Ok(SignedMoney::new(-15, &USD))
+        );
+        assert_eq!(
+            SignedMoney::zero(&JPY).convert().map(|m| m.amount()),
+            Ok(0.0)
+        );
+    }
+
+    #[test]
+    fn major_units_into_any_backend() {
+        let major = MoneyInner::<HighestUnit, _>::new(30_000_000_000.015, &USD);
+        assert_eq!(
+            major.convert::<i64>(),
+            Ok(SignedMoney::new(3_000_000_000_002, &USD))
+        );
+        assert_eq!(
+            major.convert_floor::<i128>(),
+            Ok(WideMoney::new(3_000_000_000_001, &USD))
+        );
+        assert_eq!(
+            major.convert_ceil::<i64>().map(|money| money.amount()),
+            Ok(3_000_000_000_002)
+        );
+        assert!(matches!(
+            major.convert_trunc::<LowestSubunit>(),
+            Err(MoneyConversionError::F64ToI32ConversionFailed { .. })
+        ));
+        let normalized = major.normalize::<i64>(RoundingMode::Trunc).unwrap();
+        assert_eq!(
+            normalized.subunits,
+            SignedMoney::new(3_000_000_000_001, &USD)
+        );
+        assert_eq!(normalized.value.amount(), 30_000_000_000.01);
+        assert_eq!(
+            MoneyInner::<HighestUnit, _>::new(1e30, &USD).convert::<i64>(),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+        assert_eq!(
+            MoneyInner::<HighestUnit, _>::new(f64::NAN, &USD).convert::<i128>(),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+    }
+
+    #[cfg(feature = "rust-decimal")]
+    #[test]
+    fn decimal_backend() {
+        use rust_decimal::Decimal;
+
+        type DecimalMoney = MoneyInner<Decimal, Currency>;
+
+        assert_eq!(
+            add_and_format(Decimal::from(1_000), Decimal::from(234)).as_deref(),
+            Ok("1.234")
+        );
+        let money = DecimalMoney::from_str_exact("-12.34", &USD).unwrap();
+        assert_eq!(money.amount(), Decimal::from(-1_234));
+        assert_eq!(money.to_minor_string(), "-1234");
+        assert_eq!(money.convert().map(|major| major.amount()), Ok(-12.34));
+        assert_eq!(
+            MoneyInner::<HighestUnit, _>::new(10.125, &USD).convert::<Decimal>(),
+            Ok(DecimalMoney::new(Decimal::from(1_012), &USD))
+        );
+        assert_eq!(
+            money.checked_mul(Decimal::from(3)),
+            Ok(DecimalMoney::new(Decimal::from(-3_702), &USD))
+        );
+        assert_eq!(
+            money.to_backend::<i32>(),
+            Ok(MoneyInner::<LowestSubunit, _>::new(-1_234, &USD))
+        );
+        assert_eq!(
+            WideMoney::new(i128::MAX, &USD).to_backend::<Decimal>(),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+        assert_eq!(
+            DecimalMoney::new(Decimal::MAX, &USD)
+                .checked_add(DecimalMoney::new(Decimal::ONE, &USD)),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+    }
+
+    #[test]
+    fn minor_strings() {
+        let parse = MoneyInner::<LowestSubunit, _>::from_minor_string;
+        assert_eq!(
+            parse("+0042", &JPY).map(|m| m.to_minor_string()),
+            Ok("42".into())
+        );
+        for invalid in ["", "-", "1e3", "1,050", " 1050", "--5", "10.50"] {
+            assert_eq!(
+                parse(invalid, &USD),
+                Err(MoneyConversionError::InvalidDecimalString)
+            );
+        }
+        assert!(matches!(
+            parse("2147483648", &USD),
+            Err(MoneyConversionError::I128ToI32ConversionFailed { .. })
+        ));
+        let wide = WideMoney::from_minor_string("-30000000000000000000", &USD).unwrap();
+        assert_eq!(wide.to_minor_string(), "-30000000000000000000");
+        assert_eq!(
+            WideMoney::from_minor_string(&format!("{}0", i128::MAX), &USD),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+    }
+}
@@ -0,0 +1,178 @@
+use crate::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::decimal::format_subunits;
+use crate::factor::{get_exponent, FromCurrency};
+
+/// A list of labelled amounts in any mix of currencies, e.g. the lines of a report.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::bag::MoneyBag;
+/// use amount_conversion::factor::Currency::{self, INR, USD};
+///
+/// type Money = MoneyInner<LowestSubunit, Currency>;
+///
+/// let bag = MoneyBag::new()
+///     .with("coffee", Money::new(450, &USD))
+///     .with("rent", Money::new(2_500_000, &INR))
+///     .with("lunch", Money::new(1_200, &USD));
+/// assert_eq!(bag.totals()?, [Money::new(1_650, &USD), Money::new(2_500_000, &INR)]);
+/// assert_eq!(
+///     bag.render_table()?,
+///     "\
+/// Item        Amount  Currency
+/// ----------------------------
+/// coffee        4.50  USD
+/// lunch        12.00  USD
+/// Subtotal     16.50  USD
+/// rent      25000.00  INR
+/// Subtotal  25000.00  INR
+/// "
+/// );
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoneyBag<Cur: FromCurrency> {
+    items: Vec<(String, MoneyInner<LowestSubunit, Cur>)>,
+}
+
+impl<Cur: FromCurrency> MoneyBag<Cur> {
+    pub fn new() -> Self {
+        Self { items: Vec::new() }
+    }
+
+    /// Adds `money` under `label`.
+    pub fn with(mut self, label: impl Into<String>, money: MoneyInner<LowestSubunit, Cur>) -> Self {
+        self.push(label, money);
+        self
+    }
+
+    /// Adds `money` under `label`.
+    pub fn push(&mut self, label: impl Into<String>, money: MoneyInner<LowestSubunit, Cur>) {
+        self.items.push((label.into(), money));
+    }
+
+    /// The labelled amounts, in insertion order.
+    pub fn items(&self) -> impl Iterator<Item = (&str, MoneyInner<LowestSubunit, Cur>)> {
+        self.items
+            .iter()
+            .map(|(label, money)| (label.as_str(), *money))
+    }
+
+    /// The sum per currency, in order of each currency's first appearance.
+    ///
+    /// Fails with `AmountOverflow` when a sum does not fit the subunit type.
+    pub fn totals(&self) -> Result<Vec<MoneyInner<LowestSubunit, Cur>>, MoneyConversionError<Cur>> {
+        let mut totals: Vec<MoneyInner<LowestSubunit, Cur>> = Vec::new();
+        for (_, money) in &self.items {
+            match totals
+                .iter_mut()
+                .find(|total| total.currency == money.currency)
+            {
+                Some(total) => *total = total.checked_add(*money)?,
+                None => totals.push(*money),
+            }
+        }
+        Ok(totals)
+    }
+
+    /// Renders an aligned plain-text table for CLI reports and log summaries.
+    ///
+    /// Items are grouped by currency, in order of each currency's first appearance, and each
+    /// group ends with a `Subtotal` row. Amounts are right-aligned in major units.
+    pub fn render_table(&self) -> Result<String, MoneyConversionError<Cur>> {
+        let format = |money: &MoneyInner<LowestSubunit, Cur>| {
+            Ok::<_, MoneyConversionError<Cur>>(format_subunits(
+                i128::from(money.amount),
+                get_exponent(money)?,
+            ))
+        };
+        let mut rows = Vec::new();
+        for total in self.totals()? {
+            let code = total.currency.currency_code().to_string();
+            for (label, money) in &self.items {
+                if money.currency == total.currency {
+                    rows.push((label.as_str(), format(money)?, code.clone()));
+                }
+            }
+            rows.push(("Subtotal", format(&total)?, code));
+        }
+
+        let header = ("Item", "Amount", "Currency");
+        let label_width = rows
+            .iter()
+            .map(|(label, _, _)| label.chars().count())
+            .fold(header.0.len(), usize::max);
+        let amount_width = rows
+            .iter()
+            .map(|(_, amount, _)| amount.len())
+            .fold(header.1.len(), usize::max);
+
+        let mut table = format!(
+            "{:<label_width$}  {:>amount_width$}  {}\n",
+            header.0, header.1, header.2
+        );
+        let rule_width = label_width + amount_width + header.2.len() + 4;
+        table.push_str(&"-".repeat(rule_width));
+        table.push('\n');
+        for (label, amount, code) in rows {
+            table.push_str(&format!(
+                "{label:<label_width$}  {amount:>amount_width$}  {code}\n"
+            ));
+        }
+        Ok(table)
+    }
+}
+
+impl<Cur: FromCurrency> Default for MoneyBag<Cur> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn totals() -> Result<(), MoneyConversionError<Currency>> {
+        let mut bag = MoneyBag::new();
+        assert_eq!(bag.totals()?, []);
+        bag.push("a", Money::new(5, &EUR));
+        bag.push("b", Money::new(-7, &EUR));
+        bag.push("c", Money::new(1, &JPY));
+        assert_eq!(bag.totals()?, [Money::new(-2, &EUR), Money::new(1, &JPY)]);
+
+        bag.push("d", Money::new(i32::MAX, &EUR));
+        bag.push("e", Money::new(i32::MAX, &EUR));
+        assert_eq!(bag.totals(), Err(MoneyConversionError::AmountOverflow));
+        Ok(())
+    }
+
+    #[test]
+    fn table_widths() -> Result<(), MoneyConversionError<Currency>> {
+        let bag = MoneyBag::new()
+            .with("café au lait", Money::new(-350, &EUR))
+            .with("x", Money::new(12_345, &KWD));
+        assert_eq!(
+            bag.render_table()?,
+            "\
+Item          Amount  Currency
+------------------------------
+café au lait   -3.50  EUR
+Subtotal       -3.50  EUR
+x             12.345  KWD
+Subtotal      12.345  KWD
+"
+        );
+        assert_eq!(
+            MoneyBag::<Currency>::new().render_table()?,
+            "Item  Amount  Currency\n----------------------\n"
+        );
+        Ok(())
+    }
+}
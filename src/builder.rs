@@ -0,0 +1,177 @@
+use std::fmt;
+
+use crate::amount::{LowestSubunit, MajorMinor, MoneyConversionError, MoneyInner};
+use crate::factor::FromCurrency;
+
+/// Builds subunit money from separately entered fields, such as a form with a currency picker
+/// and whole and fractional amount inputs, reporting every invalid field at once.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::builder::MoneyBuilderError;
+/// use amount_conversion::factor::Currency::{self, USD};
+///
+/// type Money = MoneyInner<LowestSubunit, Currency>;
+///
+/// let money = Money::builder().currency(USD).major(12).minor(34).build().unwrap();
+/// assert_eq!(money, Money::new(1_234, &USD));
+///
+/// let errors = Money::builder().currency(USD).major(u32::MAX).minor(345).build().unwrap_err();
+/// assert!(matches!(
+///     errors[..],
+///     [MoneyBuilderError::Minor(_), MoneyBuilderError::Amount(_)]
+/// ));
+/// assert_eq!(
+///     Money::builder().major(12).build().unwrap_err(),
+///     [MoneyBuilderError::MissingCurrency]
+/// );
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct MoneyBuilder<Cur: FromCurrency> {
+    currency: Option<Cur>,
+    negative: bool,
+    major: u32,
+    minor: u32,
+}
+
+/// An invalid field of a [`MoneyBuilder`].
+#[derive(Debug, PartialEq)]
+pub enum MoneyBuilderError<Cur> {
+    /// No currency was given.
+    MissingCurrency,
+    /// The currency cannot be used, e.g. it is missing from the subunit map.
+    Currency(MoneyConversionError<Cur>),
+    /// The minor units do not fit the currency's exponent, e.g. 345 cents.
+    Minor(MoneyConversionError<Cur>),
+    /// The amount does not fit the subunit range.
+    Amount(MoneyConversionError<Cur>),
+}
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// A builder for an amount of whole major units and minor units, both zero until given.
+    pub fn builder() -> MoneyBuilder<Cur> {
+        MoneyBuilder {
+            currency: None,
+            negative: false,
+            major: 0,
+            minor: 0,
+        }
+    }
+}
+
+impl<Cur: FromCurrency> MoneyBuilder<Cur> {
+    pub fn currency(mut self, currency: Cur) -> Self {
+        self.currency = Some(currency);
+        self
+    }
+
+    /// Whether the amount is negative, which also applies below one major unit.
+    pub fn negative(mut self, negative: bool) -> Self {
+        self.negative = negative;
+        self
+    }
+
+    /// The whole major units.
+    pub fn major(mut self, major: u32) -> Self {
+        self.major = major;
+        self
+    }
+
+    /// The minor units, e.g. 34 for 12.34 USD.
+    pub fn minor(mut self, minor: u32) -> Self {
+        self.minor = minor;
+        self
+    }
+
+    /// The money, like [`MoneyInner::from_major_minor`], or the error of every invalid field.
+    ///
+    /// Without a usable currency the amount cannot be checked, so only the currency is reported.
+    pub fn build(self) -> Result<MoneyInner<LowestSubunit, Cur>, Vec<MoneyBuilderError<Cur>>> {
+        let Some(currency) = self.currency else {
+            return Err(vec![MoneyBuilderError::MissingCurrency]);
+        };
+        let parts = MajorMinor::check(self.negative, self.major, self.minor, &currency)
+            .map_err(|error| vec![MoneyBuilderError::Currency(error)])?;
+
+        let mut errors: Vec<_> = parts
+            .minor
+            .map(MoneyBuilderError::Minor)
+            .into_iter()
+            .collect();
+        match parts.amount {
+            Ok(amount) if errors.is_empty() => {
+                Ok(MoneyInner::<LowestSubunit, _>::new(amount, &currency))
+            }
+            Ok(_) => Err(errors),
+            Err(error) => {
+                errors.push(MoneyBuilderError::Amount(error));
+                Err(errors)
+            }
+        }
+    }
+}
+
+impl<Cur: fmt::Debug> fmt::Display for MoneyBuilderError<Cur> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingCurrency => f.write_str("currency: is required"),
+            Self::Currency(error) => write!(f, "currency: {error}"),
+            Self::Minor(error) => write!(f, "minor: {error}"),
+            Self::Amount(error) => write!(f, "amount: {error}"),
+        }
+    }
+}
+
+impl<Cur: fmt::Debug> std::error::Error for MoneyBuilderError<Cur> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn collects_every_invalid_field() {
+        assert_eq!(
+            Money::builder()
+                .currency(JPY)
+                .negative(true)
+                .major(500)
+                .build(),
+            Ok(Money::new(-500, &JPY))
+        );
+        assert_eq!(
+            Money::builder()
+                .currency(USD)
+                .negative(true)
+                .minor(34)
+                .build(),
+            Ok(Money::new(-34, &USD))
+        );
+        let errors = Money::builder()
+            .currency(KWD)
+            .negative(true)
+            .major(i32::MIN.unsigned_abs())
+            .minor(1_000)
+            .build()
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            [
+                MoneyBuilderError::Minor(MoneyConversionError::MinorUnitsOutOfRange(1_000)),
+                MoneyBuilderError::Amount(MoneyConversionError::I128ToI32ConversionFailed {
+                    value: i128::from(i32::MIN) * 1_000,
+                    bound: i32::MIN,
+                    currency: KWD
+                }),
+            ]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "minor: minor units 1000 exceed the currency's subunit range"
+        );
+    }
+}
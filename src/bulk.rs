@@ -0,0 +1,102 @@
+//! Column-wise conversions of many amounts in one currency, enabled with the `simd` feature.
+//!
+//! The currency's factor is looked up once and the amounts are converted in fixed-size batches
+//! that the compiler vectorizes on stable Rust. Results are bit-identical to converting each
+//! amount on its own with `MoneyInner::convert`.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::bulk;
+//! use amount_conversion::factor::Currency::{self, KWD};
+//!
+//! let column = [1_050, -7, 0, 2_147_483_647];
+//! assert_eq!(
+//!     bulk::to_major_units(&column, &KWD)?,
+//!     [1.05, -0.007, 0.0, 2_147_483.647]
+//! );
+//! # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+//! ```
+use crate::amount::{HighestUnit, LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::{factors_of, get_exponent, to_major, FromCurrency};
+use crate::observer::{observe, ConversionKind};
+
+/// Amounts converted per batch, enough to fill the widest common vector registers.
+const LANES: usize = 8;
+
+/// Converts subunit `amounts` of `currency` into major units.
+///
+/// The conversion observer is notified once for the whole slice.
+pub fn to_major_units<Cur: FromCurrency>(
+    amounts: &[LowestSubunit],
+    currency: &Cur,
+) -> Result<Vec<HighestUnit>, MoneyConversionError<Cur>> {
+    let mut major = vec![0.0; amounts.len()];
+    to_major_units_into(amounts, currency, &mut major)?;
+    Ok(major)
+}
+
+/// Converts subunit `amounts` of `currency` into major units written to `out`, reusing its
+/// allocation across columns.
+///
+/// The conversion observer is notified once for the whole slice.
+///
+/// # Panics
+///
+/// Panics when `out` and `amounts` have different lengths.
+pub fn to_major_units_into<Cur: FromCurrency>(
+    amounts: &[LowestSubunit],
+    currency: &Cur,
+    out: &mut [HighestUnit],
+) -> Result<(), MoneyConversionError<Cur>> {
+    assert_eq!(
+        amounts.len(),
+        out.len(),
+        "output length must match the number of amounts"
+    );
+    let converted = get_exponent(&MoneyInner::<LowestSubunit, _>::zero(currency)).map(|exponent| {
+        let factors = factors_of(exponent);
+        let mut batches = amounts.chunks_exact(LANES);
+        let mut outputs = out.chunks_exact_mut(LANES);
+        for (batch, output) in (&mut batches).zip(&mut outputs) {
+            let batch: &[LowestSubunit; LANES] = batch.try_into().expect("batch has LANES amounts");
+            for (major, &subunits) in output.iter_mut().zip(batch) {
+                *major = to_major(f64::from(subunits), factors);
+            }
+        }
+        let remainder = batches.remainder();
+        for (major, &subunits) in outputs.into_remainder().iter_mut().zip(remainder) {
+            *major = to_major(f64::from(subunits), factors);
+        }
+    });
+    observe(ConversionKind::ToMajorUnits, currency, converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn matches_single_conversions() -> Result<(), MoneyConversionError<Currency>> {
+        // long enough for full batches and a remainder
+        let amounts: Vec<i32> = (-13..=13).map(|i| i * 7_919 + i32::MAX / 27).collect();
+        for currency in [JPY, USD, BHD] {
+            let major = to_major_units(&amounts, &currency)?;
+            for (&subunits, major) in amounts.iter().zip(major) {
+                let single = Money::new(subunits, &currency).convert()?.amount();
+                assert_eq!(major.to_bits(), single.to_bits());
+            }
+        }
+        assert_eq!(to_major_units(&[], &USD)?, Vec::<f64>::new());
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "output length must match")]
+    fn into_checks_length() {
+        let _ = to_major_units_into(&[1, 2], &USD, &mut [0.0]);
+    }
+}
@@ -0,0 +1,378 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::FromCurrency;
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// Splits the amount into notes and coins from `denominations`, largest first.
+    ///
+    /// Returns each denomination used with its count, skipping unused ones, using the fewest
+    /// pieces possible, so series that greedy splitting gets wrong, such as 25-10 or 4-3-1, are
+    /// handled too. Fails with `NoBreakdown` when the amount is negative or cannot be paid
+    /// exactly, at once when it is not a multiple of the denominations' greatest common divisor.
+    ///
+    /// The search takes time proportional to the largest denomination rather than the amount,
+    /// except for amounts smaller than the other denominations can make up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, EUR};
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// let series = [5000, 2000, 1000, 500, 200, 100, 50, 20, 10, 5, 2, 1].map(|d| Money::new(d, &EUR));
+    /// let pieces = Money::new(8_765, &EUR).breakdown(&series)?;
+    /// let pieces: Vec<_> = pieces.iter().map(|(piece, count)| (piece.amount(), *count)).collect();
+    /// assert_eq!(pieces, [(5000, 1), (2000, 1), (1000, 1), (500, 1), (200, 1), (50, 1), (10, 1), (5, 1)]);
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn breakdown(
+        self,
+        denominations: &[Self],
+    ) -> Result<Vec<(Self, u32)>, MoneyConversionError<Cur>> {
+        let mut sorted = denominations.to_vec();
+        for denomination in &sorted {
+            self.ensure_same_currency(denomination)?;
+            if denomination.amount <= 0 {
+                return Err(MoneyConversionError::InvalidDenomination(
+                    denomination.amount,
+                ));
+            }
+        }
+        if self.amount < 0 {
+            return Err(MoneyConversionError::NoBreakdown);
+        }
+        sorted.sort_unstable_by_key(|denomination| Reverse(denomination.amount));
+        sorted.dedup();
+
+        if self.amount == 0 {
+            return Ok(Vec::new());
+        }
+        let divisor = sorted
+            .iter()
+            .fold(0, |divisor, denomination| gcd(divisor, denomination.amount));
+        if divisor == 0 || self.amount % divisor != 0 {
+            return Err(MoneyConversionError::NoBreakdown);
+        }
+        let values: Vec<i32> = sorted
+            .iter()
+            .map(|denomination| denomination.amount / divisor)
+            .collect();
+        let counts = Breakdown::new(&values, self.amount / divisor)
+            .counts()
+            .ok_or(MoneyConversionError::NoBreakdown)?;
+        Ok(sorted
+            .into_iter()
+            .zip(counts)
+            .filter(|&(_, count)| count > 0)
+            .collect())
+    }
+}
+
+fn gcd(mut a: i32, mut b: i32) -> i32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// A fewest-pieces breakdown of an amount into `values`, sorted largest first.
+///
+/// Each level splits the amount of one suffix of `values` into its largest value and the rest.
+/// The largest pieces pay whatever the smaller ones leave, so only the residue of the smaller
+/// pieces modulo the largest value matters, and the [`Residues`] of that level give the
+/// fewest pieces without looking at the amount. Levels where trying each count of the largest
+/// pieces costs less, such as a huge largest value, do that instead until the counts tried
+/// add up to what searching the residues would have cost.
+struct Breakdown<'a> {
+    values: &'a [i32],
+    amount: i32,
+    /// Each level but the last.
+    levels: Vec<Level>,
+}
+
+struct Level {
+    residues: Option<Residues>,
+    /// The cost estimate of searching every residue.
+    searching: u64,
+    /// The counts tried so far without residues.
+    tried: u64,
+}
+
+impl<'a> Breakdown<'a> {
+    fn new(values: &'a [i32], amount: i32) -> Self {
+        let mut levels = Vec::new();
+        // the estimated cost of one breakdown on the level below
+        let mut below: u64 = 1;
+        for (i, &value) in values.iter().enumerate().rev().skip(1) {
+            let searching = value as u64 * (values.len() - i) as u64;
+            let trying = ((amount / value + 1) as u64).saturating_mul(below);
+            levels.push(Level {
+                residues: (searching <= trying).then(Residues::new),
+                searching,
+                tried: 0,
+            });
+            below = searching.min(trying);
+        }
+        levels.reverse();
+        Self {
+            values,
+            amount,
+            levels,
+        }
+    }
+
+    /// The count of each value in the fewest pieces, `None` when the amount cannot be paid.
+    fn counts(mut self) -> Option<Vec<u32>> {
+        self.pieces(0, self.amount)?;
+        let mut counts = Vec::with_capacity(self.values.len());
+        self.collect(0, self.amount, &mut counts);
+        Some(counts)
+    }
+
+    /// The fewest pieces of `values[level..]` making up `amount`.
+    fn pieces(&mut self, level: usize, amount: i32) -> Option<u32> {
+        let largest = self.values[level];
+        if level == self.levels.len() {
+            return (amount % largest == 0).then(|| (amount / largest) as u32);
+        }
+        let state = &mut self.levels[level];
+        if state.residues.is_none() && state.tried >= state.searching {
+            state.residues = Some(Residues::new());
+        }
+        if let Some(residues) = &mut state.residues {
+            let path = residues.settle(amount % largest, largest, &self.values[level + 1..])?;
+            if path.worth <= amount as u128 {
+                return Some(((amount as u128 + path.cost) / largest as u128) as u32);
+            }
+        }
+        self.best_count(level, amount).map(|(_, pieces)| pieces)
+    }
+
+    /// The count of the largest value in the fewest pieces of `values[level..]` making up
+    /// `amount` when each count is tried, most first, with the pieces it takes in all.
+    fn best_count(&mut self, level: usize, amount: i32) -> Option<(i32, u32)> {
+        let (largest, next) = (self.values[level], self.values[level + 1]);
+        let mut best: Option<(i32, u32)> = None;
+        for count in (0..=amount / largest).rev() {
+            self.levels[level].tried += 1;
+            let rest = amount - count * largest;
+            // the rest takes at least `rest / next` pieces, more the fewer largest pieces
+            let fewest = (count + rest / next + (rest % next != 0) as i32) as u32;
+            if best.map_or(false, |(_, pieces)| fewest >= pieces) {
+                break;
+            }
+            if let Some(pieces) = self.pieces(level + 1, rest) {
+                let pieces = count as u32 + pieces;
+                if best.map_or(true, |(_, best)| pieces < best) {
+                    best = Some((count, pieces));
+                }
+            }
+        }
+        best
+    }
+
+    /// Pushes the count of each of `values[level..]` in the fewest pieces making up `amount`,
+    /// which [`pieces`](Self::pieces) has found payable.
+    fn collect(&mut self, level: usize, amount: i32, counts: &mut Vec<u32>) {
+        let largest = self.values[level];
+        if level == self.levels.len() {
+            counts.push((amount / largest) as u32);
+            return;
+        }
+        let smaller = &self.values[level + 1..];
+        if let Some(residues) = &mut self.levels[level].residues {
+            let target = amount % largest;
+            let worth = residues
+                .settle(target, largest, smaller)
+                .expect("payable amounts reach their residue")
+                .worth;
+            if worth <= amount as u128 {
+                let start = counts.len();
+                counts.resize(self.values.len(), 0);
+                counts[start] = (amount - worth as i32) as u32 / largest as u32;
+                let mut residue = target;
+                while let Some(index) = residues.paths[&residue].piece {
+                    counts[start + 1 + index] += 1;
+                    residue = (i64::from(residue) - i64::from(smaller[index]))
+                        .rem_euclid(i64::from(largest)) as i32;
+                }
+                return;
+            }
+        }
+        let (count, _) = self
+            .best_count(level, amount)
+            .expect("payable amounts have a best count");
+        counts.push(count as u32);
+        self.collect(level + 1, amount - count * largest, counts);
+    }
+}
+
+/// The cheapest paths of smaller pieces to each residue modulo the largest value, where each
+/// piece costs what it falls short of the largest, so a path costing `cost` to the residue of
+/// an amount makes `(amount + cost) / largest` pieces in all when it is worth no more than the
+/// amount. Ties go to the path worth least, which leaves the most to the largest pieces. Paths
+/// are settled as residues are asked for.
+struct Residues {
+    paths: HashMap<i32, Path>,
+    queue: BinaryHeap<Reverse<(u128, u128, i32)>>,
+}
+
+struct Path {
+    cost: u128,
+    worth: u128,
+    /// The index into the smaller values of the last piece, `None` for the empty path.
+    piece: Option<usize>,
+    settled: bool,
+}
+
+impl Residues {
+    fn new() -> Self {
+        let start = Path {
+            cost: 0,
+            worth: 0,
+            piece: None,
+            settled: false,
+        };
+        Self {
+            paths: HashMap::from([(0, start)]),
+            queue: BinaryHeap::from([Reverse((0, 0, 0))]),
+        }
+    }
+
+    /// The cheapest path to `target`, `None` when no smaller pieces reach it.
+    fn settle(&mut self, target: i32, largest: i32, smaller: &[i32]) -> Option<&Path> {
+        while !self.paths.get(&target).map_or(false, |path| path.settled) {
+            let Reverse((cost, worth, residue)) = self.queue.pop()?;
+            let path = self
+                .paths
+                .get_mut(&residue)
+                .expect("queued residues have paths");
+            if path.settled || (path.cost, path.worth) != (cost, worth) {
+                continue;
+            }
+            path.settled = true;
+            for (index, &value) in smaller.iter().enumerate() {
+                let next = ((i64::from(residue) + i64::from(value)) % i64::from(largest)) as i32;
+                let step = Path {
+                    cost: cost + (largest - value) as u128,
+                    worth: worth + value as u128,
+                    piece: Some(index),
+                    settled: false,
+                };
+                if self.paths.get(&next).map_or(true, |path| {
+                    !path.settled && (step.cost, step.worth) < (path.cost, path.worth)
+                }) {
+                    self.queue.push(Reverse((step.cost, step.worth, next)));
+                    self.paths.insert(next, step);
+                }
+            }
+        }
+        self.paths.get(&target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    fn series(currency: Currency, denominations: &[i32]) -> Vec<Money> {
+        denominations
+            .iter()
+            .map(|&denomination| Money::new(denomination, &currency))
+            .collect()
+    }
+
+    fn counts(pieces: Vec<(Money, u32)>) -> Vec<(i32, u32)> {
+        pieces
+            .into_iter()
+            .map(|(piece, count)| (piece.amount(), count))
+            .collect()
+    }
+
+    #[test]
+    fn breakdown() -> Result<(), MoneyConversionError<Currency>> {
+        let notes = series(INR, &[100, 50_000, 2_000, 10_000, 50_000, 1_000, 20_000]);
+        assert_eq!(
+            counts(Money::new(173_100, &INR).breakdown(&notes)?),
+            [(50_000, 3), (20_000, 1), (2_000, 1), (1_000, 1), (100, 1)]
+        );
+        assert_eq!(counts(Money::new(0, &INR).breakdown(&notes)?), []);
+        Ok(())
+    }
+
+    #[test]
+    fn fewest_pieces_beyond_greedy() -> Result<(), MoneyConversionError<Currency>> {
+        let coins = series(USD, &[25, 10]);
+        assert_eq!(counts(Money::new(30, &USD).breakdown(&coins)?), [(10, 3)]);
+        assert_eq!(
+            counts(Money::new(80, &USD).breakdown(&coins)?),
+            [(25, 2), (10, 3)]
+        );
+        let coins = series(USD, &[4, 3, 1]);
+        assert_eq!(counts(Money::new(6, &USD).breakdown(&coins)?), [(3, 2)]);
+        assert_eq!(
+            counts(Money::new(i32::MAX, &USD).breakdown(&coins)?),
+            [(4, 536_870_911), (3, 1)]
+        );
+        assert_eq!(counts(Money::new(2, &USD).breakdown(&coins)?), [(1, 2)]);
+        let coins = series(USD, &[9_998, 9_996, 9_994, 2]);
+        assert_eq!(
+            counts(Money::new(100_000_000, &USD).breakdown(&coins)?),
+            [(9_998, 7_504), (9_996, 1), (9_994, 2_498)]
+        );
+        let coins = series(USD, &[99_998, 99_996, 3]);
+        assert_eq!(
+            counts(Money::new(i32::MAX, &USD).breakdown(&coins)?),
+            [(99_998, 21_473), (99_996, 2), (3, 8_867)]
+        );
+        let coins = series(USD, &[2_000_000_000, 5, 3]);
+        assert_eq!(
+            counts(Money::new(i32::MAX, &USD).breakdown(&coins)?),
+            [(2_000_000_000, 1), (5, 29_496_727), (3, 4)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn pathological_not_payable() {
+        for (amount, coins) in [
+            (100_000_001, series(USD, &[9_998, 9_996, 9_994, 2])),
+            (i32::MAX, series(USD, &[99_998, 99_996, 2])),
+            (i32::MAX, series(USD, &[2_000_000_000, 3])),
+            (99_999_999, series(USD, &[99_998, 99_997, 99_996])),
+        ] {
+            assert_eq!(
+                Money::new(amount, &USD).breakdown(&coins),
+                Err(MoneyConversionError::NoBreakdown)
+            );
+        }
+    }
+
+    #[test]
+    fn breakdown_errors() {
+        let coins = series(USD, &[25, 10, 5]);
+        assert_eq!(
+            Money::new(7, &USD).breakdown(&coins),
+            Err(MoneyConversionError::NoBreakdown)
+        );
+        assert_eq!(
+            Money::new(-5, &USD).breakdown(&coins),
+            Err(MoneyConversionError::NoBreakdown)
+        );
+        assert_eq!(
+            Money::new(5, &USD).breakdown(&series(USD, &[5, 0])),
+            Err(MoneyConversionError::InvalidDenomination(0))
+        );
+        assert_eq!(
+            Money::new(5, &USD).breakdown(&series(EUR, &[5])),
+            Err(MoneyConversionError::CurrencyMismatch(USD, EUR))
+        );
+    }
+}
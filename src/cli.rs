@@ -0,0 +1,109 @@
+//! Implementation of the `amount-conversion` binary, enabled with the `cli` feature.
+use std::collections::HashMap;
+
+use crate::amount::{LowestSubunit, MoneyInner};
+use crate::exchange::ExchangeRate;
+use crate::factor::Currency;
+
+const USAGE: &str = "\
+usage: amount-conversion <command> [args]
+
+commands:
+  to-major <subunits> <currency>                  1050 USD  -> 10.5
+  to-minor <amount> <currency>                    10.50 USD -> 1050
+  format <subunits> <currency>                    1050 USD  -> 10.50 USD
+  exchange <subunits> <from> <to> --rates <file>  convert using a JSON file of
+                                                  {\"FROM/TO\": rate} pairs";
+
+type Money = MoneyInner<LowestSubunit, Currency>;
+
+/// Runs the command line, returning the text to print on success.
+pub fn run<I: IntoIterator<Item = String>>(args: I) -> Result<String, String> {
+    let args: Vec<String> = args.into_iter().collect();
+    let args: Vec<&str> = args.iter().map(String::as_str).collect();
+    match args.as_slice() {
+        ["to-major", amount, currency] => {
+            let money = money(amount, currency)?;
+            let major = money.convert().map_err(|err| err.to_string())?;
+            Ok(major.amount().to_string())
+        }
+        ["to-minor", amount, currency] => {
+            let money = Money::from_str_exact(amount, &currency_of(currency)?)
+                .map_err(|e| e.to_string())?;
+            Ok(money.amount.to_string())
+        }
+        ["format", amount, currency] => format(money(amount, currency)?),
+        ["exchange", amount, from, to, "--rates", path] => {
+            let rates = std::fs::read_to_string(path)
+                .map_err(|err| format!("cannot read rates file `{path}`: {err}"))?;
+            let rate = find_rate(&rates, currency_of(from)?, currency_of(to)?)?;
+            let converted = rate
+                .convert(money(amount, from)?)
+                .map_err(|err| err.to_string())?;
+            format(converted)
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn currency_of(code: &str) -> Result<Currency, String> {
+    code.parse::<Currency>().map_err(|err| err.to_string())
+}
+
+fn money(amount: &str, currency: &str) -> Result<Money, String> {
+    let amount = amount
+        .parse()
+        .map_err(|_| format!("`{amount}` is not a whole number of subunits"))?;
+    Ok(Money::new(amount, &currency_of(currency)?))
+}
+
+fn format(money: Money) -> Result<String, String> {
+    let formatted = money.format_decimal().map_err(|err| err.to_string())?;
+    Ok(format!("{formatted} {}", money.currency))
+}
+
+/// Looks up `FROM/TO` in a JSON rates object, falling back to the inverse of `TO/FROM`.
+fn find_rate(rates: &str, from: Currency, to: Currency) -> Result<ExchangeRate<Currency>, String> {
+    let rates: HashMap<String, f64> =
+        serde_json::from_str(rates).map_err(|err| format!("invalid rates file: {err}"))?;
+    let lookup = |from: Currency, to: Currency| rates.get(&format!("{from}/{to}")).copied();
+    let rate = match (lookup(from, to), lookup(to, from)) {
+        (Some(rate), _) => ExchangeRate::new(from, to, rate),
+        (None, Some(rate)) => ExchangeRate::new(to, from, rate).map(|rate| rate.inverse()),
+        (None, None) => return Err(format!("no rate for {from}/{to} in the rates file")),
+    };
+    rate.map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run_str(args: &str) -> Result<String, String> {
+        run(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn commands() {
+        assert_eq!(run_str("to-major 1050 USD"), Ok("10.5".to_string()));
+        assert_eq!(run_str("to-minor 10.5 USD"), Ok("1050".to_string()));
+        assert_eq!(run_str("format 12345 BHD"), Ok("12.345 BHD".to_string()));
+        assert!(run_str("to-minor 10.5 JPY").is_err());
+        assert!(run_str("format 10 XXX").is_err());
+        assert_eq!(run_str("frobnicate"), Err(USAGE.to_string()));
+    }
+
+    #[test]
+    fn rates() {
+        let rates = r#"{"USD/INR": 80.0}"#;
+        assert_eq!(
+            find_rate(rates, Currency::USD, Currency::INR).map(|rate| rate.rate()),
+            Ok(80.0)
+        );
+        assert_eq!(
+            find_rate(rates, Currency::INR, Currency::USD).map(|rate| rate.rate()),
+            Ok(0.0125)
+        );
+        assert!(find_rate(rates, Currency::USD, Currency::EUR).is_err());
+    }
+}
@@ -0,0 +1,172 @@
+use crate::adjustment::Adjustment;
+use crate::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::exchange::ExchangeRate;
+use crate::factor::FromCurrency;
+use crate::rounding::RoundingMode;
+
+/// A multi-step conversion of money: an optional FX rate, then an optional fee, with one rounding
+/// mode for every step.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::conversion::Conversion;
+/// use amount_conversion::exchange::ExchangeRate;
+/// use amount_conversion::factor::Currency::{self, EUR, USD};
+/// use amount_conversion::percent::BasisPoints;
+/// use amount_conversion::rounding::RoundingMode;
+///
+/// type Money = MoneyInner<LowestSubunit, Currency>;
+///
+/// let receipt = Conversion::from(Money::new(10_000, &USD))
+///     .with_rate(ExchangeRate::new(USD, EUR, 0.92)?)
+///     .with_fee(BasisPoints::new(150))
+///     .round(RoundingMode::HalfEven)
+///     .execute()?;
+/// assert_eq!(receipt.converted, Money::new(9_200, &EUR));
+/// assert_eq!(receipt.fee, Money::new(138, &EUR));
+/// assert_eq!(receipt.net, Money::new(9_062, &EUR));
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Conversion<Cur: FromCurrency> {
+    source: MoneyInner<LowestSubunit, Cur>,
+    rate: Option<ExchangeRate<Cur>>,
+    fee: Option<Adjustment<Cur>>,
+    rounding: RoundingMode,
+}
+
+/// The outcome of a [`Conversion`], keeping every intermediate amount for auditing.
+///
+/// `converted == net + fee` always holds exactly.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ConversionReceipt<Cur: FromCurrency> {
+    /// The money the conversion started from.
+    pub source: MoneyInner<LowestSubunit, Cur>,
+    /// The rate applied, if any.
+    pub rate: Option<ExchangeRate<Cur>>,
+    /// The source in the target currency, before the fee.
+    pub converted: MoneyInner<LowestSubunit, Cur>,
+    /// The fee taken from the converted amount, never more than the converted amount itself.
+    pub fee: MoneyInner<LowestSubunit, Cur>,
+    /// What is left after the fee.
+    pub net: MoneyInner<LowestSubunit, Cur>,
+    /// The rounding mode used for every step.
+    pub rounding: RoundingMode,
+}
+
+impl<Cur: FromCurrency> From<MoneyInner<LowestSubunit, Cur>> for Conversion<Cur> {
+    fn from(source: MoneyInner<LowestSubunit, Cur>) -> Self {
+        Self {
+            source,
+            rate: None,
+            fee: None,
+            rounding: RoundingMode::default(),
+        }
+    }
+}
+
+impl<Cur: FromCurrency> Conversion<Cur> {
+    /// Converts into another currency with `rate`, which must start from the source currency.
+    pub fn with_rate(self, rate: ExchangeRate<Cur>) -> Self {
+        Self {
+            rate: Some(rate),
+            ..self
+        }
+    }
+
+    /// Takes a fee from the converted amount, as a share or a fixed amount in the target currency.
+    pub fn with_fee(self, fee: impl Into<Adjustment<Cur>>) -> Self {
+        Self {
+            fee: Some(fee.into()),
+            ..self
+        }
+    }
+
+    /// Rounds every step with `rounding` instead of the default half-even.
+    pub fn round(self, rounding: RoundingMode) -> Self {
+        Self { rounding, ..self }
+    }
+
+    /// Runs the rate, then the fee, failing on a currency mismatch at either step or on overflow.
+    pub fn execute(self) -> Result<ConversionReceipt<Cur>, MoneyConversionError<Cur>> {
+        let converted = match &self.rate {
+            Some(rate) => rate.convert_with(self.source, self.rounding)?,
+            None => self.source,
+        };
+        let fee = match self.fee {
+            Some(fee) => {
+                let fee = converted.adjustment_amount(fee, self.rounding)?;
+                let capped = fee.amount.min(converted.amount.max(0));
+                MoneyInner::<LowestSubunit, _>::new(capped, &fee.currency)
+            }
+            None => MoneyInner::<LowestSubunit, _>::new(0, &converted.currency),
+        };
+        Ok(ConversionReceipt {
+            source: self.source,
+            rate: self.rate,
+            converted,
+            fee,
+            net: converted.checked_sub(fee)?,
+            rounding: self.rounding,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn identity() -> Result<(), MoneyConversionError<Currency>> {
+        let receipt = Conversion::from(Money::new(1000, &INR)).execute()?;
+        assert_eq!(receipt.converted, Money::new(1000, &INR));
+        assert_eq!(receipt.fee, Money::new(0, &INR));
+        assert_eq!(receipt.net, Money::new(1000, &INR));
+        assert_eq!(receipt.rate, None);
+        Ok(())
+    }
+
+    #[test]
+    fn rate_fee_and_rounding() -> Result<(), MoneyConversionError<Currency>> {
+        let conversion = Conversion::from(Money::new(3, &USD))
+            .with_rate(ExchangeRate::new(USD, EUR, 0.5)?)
+            .with_fee(Money::new(1, &EUR));
+        let receipt = conversion.execute()?;
+        assert_eq!(receipt.converted, Money::new(2, &EUR));
+        assert_eq!(receipt.net, Money::new(1, &EUR));
+
+        let receipt = conversion.round(RoundingMode::Floor).execute()?;
+        assert_eq!(receipt.converted, Money::new(1, &EUR));
+        assert_eq!(receipt.net, Money::new(0, &EUR));
+        assert_eq!(receipt.rounding, RoundingMode::Floor);
+
+        // the fee never takes more than the converted amount
+        let receipt = conversion.with_fee(Money::new(5, &EUR)).execute()?;
+        assert_eq!(receipt.fee, Money::new(2, &EUR));
+        assert_eq!(receipt.net, Money::new(0, &EUR));
+        Ok(())
+    }
+
+    #[test]
+    fn mismatched_currencies() -> Result<(), MoneyConversionError<Currency>> {
+        assert_eq!(
+            Conversion::from(Money::new(100, &INR))
+                .with_rate(ExchangeRate::new(USD, EUR, 0.5)?)
+                .execute(),
+            Err(MoneyConversionError::CurrencyMismatch(USD, INR))
+        );
+        assert_eq!(
+            Conversion::from(Money::new(100, &USD))
+                .with_rate(ExchangeRate::new(USD, EUR, 0.5)?)
+                .with_fee(Money::new(1, &USD))
+                .execute(),
+            Err(MoneyConversionError::CurrencyMismatch(EUR, USD))
+        );
+        Ok(())
+    }
+}
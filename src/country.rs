@@ -0,0 +1,272 @@
+use crate::factor::Currency::{self, *};
+
+/// The current currency of each country, keyed by ISO 3166-1 alpha-2 code and sorted by it.
+///
+/// Only countries whose currency is supported are listed, e.g. Bhutan is left out. Countries
+/// that adopted another currency, such as Croatia with the euro, map to the one in use now.
+static COUNTRIES: [(&str, Currency); 205] = [
+    ("AD", EUR),
+    ("AE", AED),
+    ("AL", ALL),
+    ("AM", AMD),
+    ("AR", ARS),
+    ("AS", USD),
+    ("AT", EUR),
+    ("AU", AUD),
+    ("AW", AWG),
+    ("AX", EUR),
+    ("AZ", AZN),
+    ("BB", BBD),
+    ("BD", BDT),
+    ("BE", EUR),
+    ("BF", XOF),
+    ("BH", BHD),
+    ("BI", BIF),
+    ("BJ", XOF),
+    ("BL", EUR),
+    ("BM", BMD),
+    ("BN", BND),
+    ("BO", BOB),
+    ("BQ", USD),
+    ("BR", BRL),
+    ("BS", BSD),
+    ("BV", NOK),
+    ("BW", BWP),
+    ("BZ", BZD),
+    ("CA", CAD),
+    ("CC", AUD),
+    ("CF", XAF),
+    ("CG", XAF),
+    ("CH", CHF),
+    ("CI", XOF),
+    ("CK", NZD),
+    ("CL", CLP),
+    ("CM", XAF),
+    ("CN", CNY),
+    ("CO", COP),
+    ("CR", CRC),
+    ("CU", CUP),
+    ("CW", ANG),
+    ("CX", AUD),
+    ("CY", EUR),
+    ("CZ", CZK),
+    ("DE", EUR),
+    ("DJ", DJF),
+    ("DK", DKK),
+    ("DO", DOP),
+    ("DZ", DZD),
+    ("EC", USD),
+    ("EE", EUR),
+    ("EG", EGP),
+    ("EH", MAD),
+    ("ES", EUR),
+    ("ET", ETB),
+    ("FI", EUR),
+    ("FJ", FJD),
+    ("FM", USD),
+    ("FO", DKK),
+    ("FR", EUR),
+    ("GA", XAF),
+    ("GB", GBP),
+    ("GF", EUR),
+    ("GG", GBP),
+    ("GH", GHS),
+    ("GI", GIP),
+    ("GL", DKK),
+    ("GM", GMD),
+    ("GN", GNF),
+    ("GP", EUR),
+    ("GQ", XAF),
+    ("GR", EUR),
+    ("GT", GTQ),
+    ("GU", USD),
+    ("GW", XOF),
+    ("GY", GYD),
+    ("HK", HKD),
+    ("HM", AUD),
+    ("HN", HNL),
+    ("HR", EUR),
+    ("HT", HTG),
+    ("HU", HUF),
+    ("ID", IDR),
+    ("IE", EUR),
+    ("IL", ILS),
+    ("IM", GBP),
+    ("IN", INR),
+    ("IO", USD),
+    ("IT", EUR),
+    ("JE", GBP),
+    ("JM", JMD),
+    ("JO", JOD),
+    ("JP", JPY),
+    ("KE", KES),
+    ("KG", KGS),
+    ("KH", KHR),
+    ("KI", AUD),
+    ("KM", KMF),
+    ("KR", KRW),
+    ("KW", KWD),
+    ("KY", KYD),
+    ("KZ", KZT),
+    ("LA", LAK),
+    ("LB", LBP),
+    ("LI", CHF),
+    ("LK", LKR),
+    ("LR", LRD),
+    ("LS", LSL),
+    ("LT", EUR),
+    ("LU", EUR),
+    ("LV", EUR),
+    ("MA", MAD),
+    ("MC", EUR),
+    ("MD", MDL),
+    ("ME", EUR),
+    ("MF", EUR),
+    ("MG", MGA),
+    ("MH", USD),
+    ("MK", MKD),
+    ("ML", XOF),
+    ("MM", MMK),
+    ("MN", MNT),
+    ("MO", MOP),
+    ("MP", USD),
+    ("MQ", EUR),
+    ("MT", EUR),
+    ("MU", MUR),
+    ("MV", MVR),
+    ("MW", MWK),
+    ("MX", MXN),
+    ("MY", MYR),
+    ("NA", NAD),
+    ("NC", XPF),
+    ("NE", XOF),
+    ("NF", AUD),
+    ("NG", NGN),
+    ("NI", NIO),
+    ("NL", EUR),
+    ("NO", NOK),
+    ("NP", NPR),
+    ("NR", AUD),
+    ("NU", NZD),
+    ("NZ", NZD),
+    ("OM", OMR),
+    ("PA", USD),
+    ("PE", PEN),
+    ("PF", XPF),
+    ("PG", PGK),
+    ("PH", PHP),
+    ("PK", PKR),
+    ("PL", PLN),
+    ("PM", EUR),
+    ("PN", NZD),
+    ("PR", USD),
+    ("PS", ILS),
+    ("PT", EUR),
+    ("PW", USD),
+    ("PY", PYG),
+    ("QA", QAR),
+    ("RE", EUR),
+    ("RU", RUB),
+    ("RW", RWF),
+    ("SA", SAR),
+    ("SC", SCR),
+    ("SE", SEK),
+    ("SG", SGD),
+    ("SI", EUR),
+    ("SJ", NOK),
+    ("SK", EUR),
+    ("SL", SLL),
+    ("SM", EUR),
+    ("SN", XOF),
+    ("SO", SOS),
+    ("SS", SSP),
+    ("SV", USD),
+    ("SX", ANG),
+    ("SZ", SZL),
+    ("TC", USD),
+    ("TD", XAF),
+    ("TF", EUR),
+    ("TG", XOF),
+    ("TH", THB),
+    ("TK", NZD),
+    ("TL", USD),
+    ("TN", TND),
+    ("TT", TTD),
+    ("TV", AUD),
+    ("TW", TWD),
+    ("TZ", TZS),
+    ("UG", UGX),
+    ("UM", USD),
+    ("US", USD),
+    ("UY", UYU),
+    ("UZ", UZS),
+    ("VA", EUR),
+    ("VG", USD),
+    ("VI", USD),
+    ("VN", VND),
+    ("VU", VUV),
+    ("WF", XPF),
+    ("XK", EUR),
+    ("YE", YER),
+    ("YT", EUR),
+    ("ZA", ZAR),
+];
+
+impl Currency {
+    /// The currency in use in the country with ISO 3166-1 alpha-2 code `alpha2`, ignoring ASCII
+    /// case, e.g. to preselect a currency from a billing address or geo-IP lookup.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::factor::Currency;
+    ///
+    /// assert_eq!(Currency::for_country("IN"), Some(Currency::INR));
+    /// assert_eq!(Currency::for_country("fr"), Some(Currency::EUR));
+    /// assert_eq!(Currency::for_country("ZZ"), None);
+    /// assert_eq!(Currency::EUR.countries().count(), 36);
+    /// ```
+    pub fn for_country(alpha2: &str) -> Option<Self> {
+        let alpha2 = alpha2.to_ascii_uppercase();
+        COUNTRIES
+            .binary_search_by(|(country, _)| (*country).cmp(alpha2.as_str()))
+            .ok()
+            .map(|index| COUNTRIES[index].1)
+    }
+
+    /// The alpha-2 codes of the countries using the currency, in code order; none for
+    /// currencies no longer in use, such as HRK.
+    pub fn countries(&self) -> impl Iterator<Item = &'static str> {
+        let currency = *self;
+        COUNTRIES
+            .iter()
+            .filter(move |(_, c)| *c == currency)
+            .map(|(country, _)| *country)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_is_sorted() {
+        assert!(COUNTRIES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert!(COUNTRIES.iter().all(|(country, _)| country.len() == 2));
+    }
+
+    #[test]
+    fn reverse_lookup() {
+        assert_eq!(
+            XOF.countries().collect::<Vec<_>>(),
+            ["BF", "BJ", "CI", "GW", "ML", "NE", "SN", "TG"]
+        );
+        assert_eq!(HRK.countries().count(), 0);
+        for (country, currency) in COUNTRIES {
+            assert_eq!(Currency::for_country(country), Some(currency));
+            assert!(currency.countries().any(|c| c == country));
+        }
+        assert_eq!(Currency::for_country("I"), None);
+        assert_eq!(Currency::for_country(""), None);
+    }
+}
@@ -0,0 +1,330 @@
+//! Reading and writing money columns with the `csv` crate, enabled with the `csv` feature.
+//!
+//! Files have a header row and two columns, either `amount_minor,currency` with integer subunits
+//! or `amount,currency` with decimal major units. Parsing is strict: the header must match the
+//! layout exactly, amounts must be plain numbers without whitespace or grouping, and major-unit
+//! amounts may not have more decimals than the currency allows. Every bad row is reported with
+//! its line number, so a bulk upload can be fixed in one pass.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{LowestSubunit, MoneyInner};
+//! use amount_conversion::csv_support::{read_money, write_money, Layout};
+//! use amount_conversion::factor::Currency::{self, INR, USD};
+//!
+//! type Money = MoneyInner<LowestSubunit, Currency>;
+//!
+//! let upload = "amount,currency\n19.99,USD\n500,INR\n";
+//! let rows = read_money::<_, Currency>(upload.as_bytes(), Layout::MajorUnits).unwrap();
+//! assert_eq!(rows, [Money::new(1_999, &USD), Money::new(50_000, &INR)]);
+//!
+//! let upload = "amount,currency\n1.999,USD\n2,USD\n1e3,USD\n";
+//! let errors = read_money::<_, Currency>(upload.as_bytes(), Layout::MajorUnits).unwrap_err();
+//! let lines: Vec<_> = errors.iter().map(|error| error.line).collect();
+//! assert_eq!(lines, [2, 4]);
+//!
+//! let mut out = Vec::new();
+//! write_money(&mut out, &[Money::new(1_999, &USD)], Layout::MinorUnits).unwrap();
+//! assert_eq!(out, b"amount_minor,currency\n1999,USD\n");
+//! ```
+use std::{fmt, io};
+
+use serde::de::DeserializeOwned;
+
+use crate::amount::{i128_to_i32, LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::decimal::parse_subunits;
+use crate::factor::FromCurrency;
+
+/// The columns of a money CSV file.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Layout {
+    /// `amount_minor,currency`, with the amount as an integer number of subunits.
+    MinorUnits,
+    /// `amount,currency`, with the amount as a decimal number of major units.
+    MajorUnits,
+}
+
+impl Layout {
+    /// The header row of the layout.
+    pub fn headers(self) -> [&'static str; 2] {
+        match self {
+            Self::MinorUnits => ["amount_minor", "currency"],
+            Self::MajorUnits => ["amount", "currency"],
+        }
+    }
+}
+
+/// Why a CSV file or one of its rows was rejected.
+#[derive(Debug)]
+pub enum CsvError<Cur> {
+    /// The header row does not match the layout.
+    InvalidHeader {
+        expected: [&'static str; 2],
+        found: Vec<String>,
+    },
+    /// The row could not be read or written, e.g. it has the wrong number of columns or an
+    /// unknown currency.
+    Csv(csv::Error),
+    /// The amount is not valid for its currency.
+    Money(MoneyConversionError<Cur>),
+}
+
+/// A rejected row of a CSV file.
+#[derive(Debug)]
+pub struct RowError<Cur> {
+    /// The line the row starts on; the header is line 1.
+    pub line: u64,
+    /// Why the row was rejected.
+    pub error: CsvError<Cur>,
+}
+
+impl<Cur> CsvError<Cur> {
+    /// A stable snake_case identifier of the error kind, the money error's own code for an
+    /// invalid amount.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::InvalidHeader { .. } => "invalid_csv_header",
+            Self::Csv(_) => "invalid_csv_row",
+            Self::Money(error) => error.code(),
+        }
+    }
+}
+
+/// Reads every row of `reader` in `layout`.
+///
+/// Fails with the errors of all bad rows, in file order, if any row is rejected.
+pub fn read_money<R: io::Read, Cur: FromCurrency + DeserializeOwned>(
+    reader: R,
+    layout: Layout,
+) -> Result<Vec<MoneyInner<LowestSubunit, Cur>>, Vec<RowError<Cur>>> {
+    let mut reader = csv::Reader::from_reader(reader);
+    let headers = reader.headers().map_err(|error| {
+        vec![RowError {
+            line: 1,
+            error: CsvError::Csv(error),
+        }]
+    })?;
+    if headers != layout.headers().as_slice() {
+        return Err(vec![RowError {
+            line: 1,
+            error: CsvError::InvalidHeader {
+                expected: layout.headers(),
+                found: headers.iter().map(String::from).collect(),
+            },
+        }]);
+    }
+
+    let mut money = Vec::new();
+    let mut errors = Vec::new();
+    for record in reader.records() {
+        let (line, row) = match record {
+            Ok(record) => (line(record.position()), parse_row(&record, layout)),
+            Err(error) => (line(error.position()), Err(CsvError::Csv(error))),
+        };
+        match row {
+            Ok(row) => money.push(row),
+            Err(error) => errors.push(RowError { line, error }),
+        }
+    }
+    if errors.is_empty() {
+        Ok(money)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Writes a header and one row per amount of `money` to `writer` in `layout`.
+pub fn write_money<W: io::Write, Cur: FromCurrency>(
+    writer: W,
+    money: &[MoneyInner<LowestSubunit, Cur>],
+    layout: Layout,
+) -> Result<(), CsvError<Cur>> {
+    let mut writer = csv::Writer::from_writer(writer);
+    writer.write_record(layout.headers())?;
+    for money in money {
+        let amount = match layout {
+            Layout::MinorUnits => money.amount.to_string(),
+            Layout::MajorUnits => money.format_decimal()?,
+        };
+        writer.write_record([amount.as_str(), money.currency.currency_code()])?;
+    }
+    writer.flush().map_err(csv::Error::from)?;
+    Ok(())
+}
+
+fn line(position: Option<&csv::Position>) -> u64 {
+    position.map_or(0, csv::Position::line)
+}
+
+fn parse_row<Cur: FromCurrency + DeserializeOwned>(
+    record: &csv::StringRecord,
+    layout: Layout,
+) -> Result<MoneyInner<LowestSubunit, Cur>, CsvError<Cur>> {
+    let (amount, currency): (&str, Cur) = record.deserialize(None)?;
+    match layout {
+        Layout::MinorUnits => {
+            let amount = i128_to_i32(parse_subunits(amount, 0)?.into(), currency)?;
+            Ok(MoneyInner::<LowestSubunit, _>::new(amount, &currency))
+        }
+        Layout::MajorUnits => Ok(MoneyInner::<LowestSubunit, _>::from_str_exact(
+            amount, &currency,
+        )?),
+    }
+}
+
+impl<Cur> From<csv::Error> for CsvError<Cur> {
+    fn from(error: csv::Error) -> Self {
+        Self::Csv(error)
+    }
+}
+
+impl<Cur> From<MoneyConversionError<Cur>> for CsvError<Cur> {
+    fn from(error: MoneyConversionError<Cur>) -> Self {
+        Self::Money(error)
+    }
+}
+
+impl<Cur: fmt::Debug> fmt::Display for CsvError<Cur> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidHeader { expected, found } => write!(
+                f,
+                "expected header `{}`, found `{}`",
+                expected.join(","),
+                found.join(",")
+            ),
+            Self::Csv(error) => write!(f, "{error}"),
+            Self::Money(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl<Cur: fmt::Debug> fmt::Display for RowError<Cur> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.error)
+    }
+}
+
+impl<Cur: fmt::Debug> std::error::Error for CsvError<Cur> {}
+
+impl<Cur: fmt::Debug> std::error::Error for RowError<Cur> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+    use crate::factor::CurrencyCode;
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    fn read(input: &str, layout: Layout) -> Result<Vec<Money>, Vec<RowError<Currency>>> {
+        read_money(input.as_bytes(), layout)
+    }
+
+    fn write(money: &[Money], layout: Layout) -> String {
+        let mut out = Vec::new();
+        write_money(&mut out, money, layout).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn minor_units() {
+        let input = "amount_minor,currency\n1999,USD\n-5,JPY\n";
+        let money = read(input, Layout::MinorUnits).unwrap();
+        assert_eq!(money, [Money::new(1_999, &USD), Money::new(-5, &JPY)]);
+        assert_eq!(write(&money, Layout::MinorUnits), input);
+    }
+
+    #[test]
+    fn major_units() {
+        let money = read(
+            "amount,currency\n19.9,USD\n5,JPY\n1.234,KWD\n",
+            Layout::MajorUnits,
+        );
+        let money = money.unwrap();
+        assert_eq!(
+            money,
+            [
+                Money::new(1_990, &USD),
+                Money::new(5, &JPY),
+                Money::new(1_234, &KWD)
+            ]
+        );
+        assert_eq!(
+            write(&money, Layout::MajorUnits),
+            "amount,currency\n19.90,USD\n5,JPY\n1.234,KWD\n"
+        );
+    }
+
+    #[test]
+    fn rejects_header() {
+        let errors = read("amount,currency\n1999,USD\n", Layout::MinorUnits).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].to_string(),
+            "line 1: expected header `amount_minor,currency`, found `amount,currency`"
+        );
+    }
+
+    #[test]
+    fn reports_every_bad_row() {
+        let input = "amount,currency\n1.00,USD\n1.001,USD\n 2,USD\n3,XXX\n4\n5.5,JPY\n6,EUR\n";
+        let errors = read(input, Layout::MajorUnits).unwrap_err();
+        let lines: Vec<_> = errors.iter().map(|error| error.line).collect();
+        assert_eq!(lines, [3, 4, 5, 6, 7]);
+        assert!(matches!(
+            errors[0].error,
+            CsvError::Money(MoneyConversionError::TooManyDecimals(2))
+        ));
+        assert!(matches!(
+            errors[1].error,
+            CsvError::Money(MoneyConversionError::InvalidDecimalString)
+        ));
+        assert!(matches!(errors[2].error, CsvError::Csv(_)));
+        assert!(matches!(errors[3].error, CsvError::Csv(_)));
+        assert!(matches!(
+            errors[4].error,
+            CsvError::Money(MoneyConversionError::TooManyDecimals(0))
+        ));
+    }
+
+    #[test]
+    fn minor_units_are_integers() {
+        let input = "amount_minor,currency\n10.5,USD\n3000000000,USD\n";
+        let errors = read(input, Layout::MinorUnits).unwrap_err();
+        assert!(matches!(
+            errors[0].error,
+            CsvError::Money(MoneyConversionError::TooManyDecimals(0))
+        ));
+        assert!(matches!(
+            errors[1].error,
+            CsvError::Money(MoneyConversionError::I128ToI32ConversionFailed {
+                value: 3_000_000_000,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn private_codes_round_trip() {
+        let points = CurrencyCode::register("CSP", USD, 0).unwrap();
+        let usd = CurrencyCode::from(USD);
+        let money = [
+            MoneyInner::<LowestSubunit, _>::new(1_250, &points),
+            MoneyInner::<LowestSubunit, _>::new(1_250, &usd),
+        ];
+        for (layout, output) in [
+            (
+                Layout::MinorUnits,
+                "amount_minor,currency\n1250,CSP\n1250,USD\n",
+            ),
+            (Layout::MajorUnits, "amount,currency\n1250,CSP\n12.50,USD\n"),
+        ] {
+            let mut out = Vec::new();
+            write_money(&mut out, &money, layout).unwrap();
+            assert_eq!(String::from_utf8(out).unwrap(), output);
+            assert_eq!(read_money(output.as_bytes(), layout).unwrap(), money);
+        }
+    }
+}
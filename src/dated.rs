@@ -0,0 +1,186 @@
+//! Amounts with a value date, enabled with the `chrono` feature.
+//!
+//! For accrual accounting an amount is only meaningful together with the date it is booked at,
+//! so dated amounts compare only on the same value date and convert only with a rate valid on
+//! that date.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+//! use amount_conversion::dated::{DatedMoney, DatedRate};
+//! use amount_conversion::exchange::ExchangeRate;
+//! use amount_conversion::factor::Currency::{self, EUR, USD};
+//! use amount_conversion::rounding::RoundingMode;
+//! use chrono::NaiveDate;
+//!
+//! type Money = MoneyInner<LowestSubunit, Currency>;
+//!
+//! let date = NaiveDate::from_ymd_opt(2024, 3, 31).unwrap();
+//! let invoice = DatedMoney::new(Money::new(10_000, &USD), date);
+//! let rate = DatedRate::on(ExchangeRate::new(USD, EUR, 0.92)?, date);
+//! assert_eq!(
+//!     invoice.convert_with(&rate, RoundingMode::HalfEven)?,
+//!     DatedMoney::new(Money::new(9_200, &EUR), date)
+//! );
+//!
+//! let stale = DatedRate::on(ExchangeRate::new(USD, EUR, 0.91)?, date.pred_opt().unwrap());
+//! assert_eq!(
+//!     invoice.convert_with(&stale, RoundingMode::HalfEven),
+//!     Err(MoneyConversionError::ValueDateMismatch)
+//! );
+//! # Ok::<(), MoneyConversionError<Currency>>(())
+//! ```
+use std::cmp::Ordering;
+
+use chrono::NaiveDate;
+
+use crate::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::exchange::ExchangeRate;
+use crate::factor::FromCurrency;
+use crate::rounding::RoundingMode;
+
+/// An amount booked at a value date.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DatedMoney<Cur: FromCurrency> {
+    money: MoneyInner<LowestSubunit, Cur>,
+    value_date: NaiveDate,
+}
+
+/// An exchange rate valid from `valid_from` to `valid_to`, both inclusive.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DatedRate<Cur: FromCurrency> {
+    rate: ExchangeRate<Cur>,
+    valid_from: NaiveDate,
+    valid_to: NaiveDate,
+}
+
+impl<Cur: FromCurrency> DatedMoney<Cur> {
+    pub fn new(money: MoneyInner<LowestSubunit, Cur>, value_date: NaiveDate) -> Self {
+        Self { money, value_date }
+    }
+
+    pub fn money(&self) -> MoneyInner<LowestSubunit, Cur> {
+        self.money
+    }
+
+    pub fn value_date(&self) -> NaiveDate {
+        self.value_date
+    }
+
+    /// Compares two amounts of the same currency and value date.
+    ///
+    /// Fails with `CurrencyMismatch` or `ValueDateMismatch` otherwise.
+    pub fn checked_cmp(&self, other: &Self) -> Result<Ordering, MoneyConversionError<Cur>> {
+        self.money.ensure_same_currency(&other.money)?;
+        if self.value_date != other.value_date {
+            return Err(MoneyConversionError::ValueDateMismatch);
+        }
+        Ok(self.money.amount.cmp(&other.money.amount))
+    }
+
+    /// Converts with `rate`, keeping the value date.
+    ///
+    /// Fails with `ValueDateMismatch` when `rate` is not valid on the value date.
+    pub fn convert_with(
+        &self,
+        rate: &DatedRate<Cur>,
+        rounding: RoundingMode,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        if !rate.is_valid_on(self.value_date) {
+            return Err(MoneyConversionError::ValueDateMismatch);
+        }
+        let money = rate.rate.convert_with(self.money, rounding)?;
+        Ok(Self::new(money, self.value_date))
+    }
+}
+
+impl<Cur: FromCurrency> DatedRate<Cur> {
+    /// A rate valid from `valid_from` to `valid_to`, failing with `ValueDateMismatch` when
+    /// `valid_to` is before `valid_from`.
+    pub fn new(
+        rate: ExchangeRate<Cur>,
+        valid_from: NaiveDate,
+        valid_to: NaiveDate,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        if valid_to < valid_from {
+            return Err(MoneyConversionError::ValueDateMismatch);
+        }
+        Ok(Self {
+            rate,
+            valid_from,
+            valid_to,
+        })
+    }
+
+    /// A rate valid only on `date`, e.g. a daily fixing.
+    pub fn on(rate: ExchangeRate<Cur>, date: NaiveDate) -> Self {
+        Self {
+            rate,
+            valid_from: date,
+            valid_to: date,
+        }
+    }
+
+    pub fn rate(&self) -> ExchangeRate<Cur> {
+        self.rate
+    }
+
+    pub fn valid_from(&self) -> NaiveDate {
+        self.valid_from
+    }
+
+    pub fn valid_to(&self) -> NaiveDate {
+        self.valid_to
+    }
+
+    pub fn is_valid_on(&self, date: NaiveDate) -> bool {
+        (self.valid_from..=self.valid_to).contains(&date)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 1, day).unwrap()
+    }
+
+    #[test]
+    fn compare() {
+        let a = DatedMoney::new(Money::new(100, &USD), date(1));
+        let b = DatedMoney::new(Money::new(200, &USD), date(1));
+        assert_eq!(a.checked_cmp(&b), Ok(Ordering::Less));
+        assert_eq!(
+            a.checked_cmp(&DatedMoney::new(Money::new(100, &USD), date(2))),
+            Err(MoneyConversionError::ValueDateMismatch)
+        );
+        assert_eq!(
+            a.checked_cmp(&DatedMoney::new(Money::new(100, &EUR), date(1))),
+            Err(MoneyConversionError::CurrencyMismatch(USD, EUR))
+        );
+    }
+
+    #[test]
+    fn rate_validity() -> Result<(), MoneyConversionError<Currency>> {
+        let rate = DatedRate::new(ExchangeRate::new(USD, INR, 83.0)?, date(1), date(7))?;
+        assert!(rate.is_valid_on(date(1)));
+        assert!(rate.is_valid_on(date(7)));
+        assert!(!rate.is_valid_on(date(8)));
+
+        let converted = DatedMoney::new(Money::new(100, &USD), date(7))
+            .convert_with(&rate, RoundingMode::HalfUp)?;
+        assert_eq!(converted.money(), Money::new(8_300, &INR));
+        assert_eq!(converted.value_date(), date(7));
+
+        assert_eq!(
+            DatedRate::new(rate.rate(), date(7), date(1)),
+            Err(MoneyConversionError::ValueDateMismatch)
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use crate::amount::MoneyConversionError;
+
+/// Renders a subunit amount as a plain decimal string with exactly `exponent` decimals.
+///
+/// Only integer math is used, so `1050` with exponent `2` is always `"10.50"`.
+pub(crate) fn format_subunits(amount: i128, exponent: u32) -> String {
+    let sign = if amount < 0 { "-" } else { "" };
+    let digits = amount.unsigned_abs().to_string();
+    if exponent == 0 {
+        return format!("{sign}{digits}");
+    }
+    let exponent = exponent as usize;
+    let digits = format!("{digits:0>width$}", width = exponent + 1);
+    let (major, minor) = digits.split_at(digits.len() - exponent);
+    format!("{sign}{major}.{minor}")
+}
+
+/// Parses a plain decimal string into subunits, allowing at most `exponent` decimals.
+///
+/// Accepts an optional sign, at least one integer digit and an optional fraction, e.g.
+/// `"-12.3"`. Exponent notation, grouping separators and surrounding whitespace are rejected.
+pub(crate) fn parse_subunits<T>(s: &str, exponent: u32) -> Result<i64, MoneyConversionError<T>> {
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (major, minor) = match unsigned.split_once('.') {
+        Some((major, minor)) if !minor.is_empty() => (major, minor),
+        Some(_) => return Err(MoneyConversionError::InvalidDecimalString),
+        None => (unsigned, ""),
+    };
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if major.is_empty() || !is_digits(major) || !is_digits(minor) {
+        return Err(MoneyConversionError::InvalidDecimalString);
+    }
+    if minor.len() > exponent as usize {
+        return Err(MoneyConversionError::TooManyDecimals(exponent));
+    }
+
+    let scale = 10_i64.pow(exponent - minor.len() as u32);
+    let mut value: i64 = 0;
+    for digit in major.bytes().chain(minor.bytes()) {
+        value = value
+            .checked_mul(10)
+            .and_then(|v| v.checked_add(i64::from(digit - b'0')))
+            .ok_or(MoneyConversionError::AmountOverflow)?;
+    }
+    let value = value
+        .checked_mul(scale)
+        .ok_or(MoneyConversionError::AmountOverflow)?;
+    Ok(if negative { -value } else { value })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format() {
+        assert_eq!(format_subunits(1050, 2), "10.50");
+        assert_eq!(format_subunits(-5, 2), "-0.05");
+        assert_eq!(format_subunits(1, 3), "0.001");
+        assert_eq!(format_subunits(1050, 0), "1050");
+        assert_eq!(
+            format_subunits(i128::MIN, 2),
+            "-1701411834604692317316873037158841057.28"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(parse_subunits::<()>("10.50", 2), Ok(1050));
+        assert_eq!(parse_subunits::<()>("10.5", 2), Ok(1050));
+        assert_eq!(parse_subunits::<()>("-0.05", 2), Ok(-5));
+        assert_eq!(parse_subunits::<()>("+7", 3), Ok(7000));
+        assert_eq!(parse_subunits::<()>("10", 0), Ok(10));
+    }
+
+    #[test]
+    fn parse_rejects_malformed() {
+        for input in ["", "-", ".5", "10.", "1e5", "1,000", " 1", "1.2.3", "--1"] {
+            assert_eq!(
+                parse_subunits::<()>(input, 2),
+                Err(MoneyConversionError::InvalidDecimalString),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            parse_subunits::<()>("10.5", 0),
+            Err(MoneyConversionError::TooManyDecimals(0))
+        );
+        assert_eq!(
+            parse_subunits::<()>("99999999999999999999", 2),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+    }
+}
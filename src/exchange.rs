@@ -0,0 +1,429 @@
+use crate::amount::{
+    f64_to_i32, to_major_units, to_subunits, HighestUnit, LowestSubunit, MoneyConversionError,
+    MoneyInner,
+};
+use crate::factor::{get_factor, FromCurrency};
+use crate::observer::{observe, ConversionKind};
+use crate::rounding::{round_f64, snap_to_whole, RoundingMode};
+
+/// A rate for converting money from one currency into another.
+///
+/// `rate` is expressed in major units: one major unit of `from` buys `rate` major units of `to`.
+/// A rate quoted with a spread also carries the bid and ask for `from`, `rate` being their mid.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ExchangeRate<Cur: FromCurrency> {
+    from: Cur,
+    to: Cur,
+    rate: f64,
+    bid: f64,
+    ask: f64,
+}
+
+impl<Cur: FromCurrency> ExchangeRate<Cur> {
+    /// Creates a rate, which must be finite and greater than zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::exchange::ExchangeRate;
+    /// use amount_conversion::factor::Currency::*;
+    ///
+    /// let rate = ExchangeRate::new(USD, INR, 83.0).unwrap();
+    /// let converted = rate.convert(MoneyInner::<LowestSubunit, _>::new(150, &USD)).unwrap();
+    /// assert_eq!(converted, MoneyInner::<LowestSubunit, _>::new(12450, &INR));
+    /// ```
+    pub fn new(from: Cur, to: Cur, rate: f64) -> Result<Self, MoneyConversionError<Cur>> {
+        if !rate.is_finite() || rate <= 0.0 {
+            return Err(MoneyConversionError::InvalidExchangeRate);
+        }
+        Ok(Self {
+            from,
+            to,
+            rate,
+            bid: rate,
+            ask: rate,
+        })
+    }
+
+    /// Creates a rate quoted with a spread: the dealer buys one major unit of `from` for `bid`
+    /// major units of `to` and sells it for `ask`. Both must be finite and greater than zero,
+    /// and `bid` must not exceed `ask`.
+    ///
+    /// `convert` and the other conversions use the mid rate; [`ExchangeRate::convert_sell`]
+    /// and [`ExchangeRate::convert_buy`] use the side the customer trades at.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::exchange::ExchangeRate;
+    /// use amount_conversion::factor::Currency::{self, EUR, USD};
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// let quote = ExchangeRate::with_spread(EUR, USD, 1.08, 1.10)?;
+    /// assert_eq!(quote.rate(), 1.09);
+    /// // selling 100.00 EUR yields 108.00 USD, buying 100.00 EUR costs 110.00 USD
+    /// assert_eq!(quote.convert_sell(Money::new(10_000, &EUR))?, Money::new(10_800, &USD));
+    /// assert_eq!(quote.convert_buy(Money::new(11_000, &USD))?, Money::new(10_000, &EUR));
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn with_spread(
+        from: Cur,
+        to: Cur,
+        bid: f64,
+        ask: f64,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        Self::new(from, to, bid)?;
+        Self::new(from, to, ask)?;
+        if bid > ask {
+            return Err(MoneyConversionError::InvalidExchangeRate);
+        }
+        Ok(Self {
+            from,
+            to,
+            rate: bid / 2.0 + ask / 2.0,
+            bid,
+            ask,
+        })
+    }
+
+    pub fn from(&self) -> Cur {
+        self.from
+    }
+
+    pub fn to(&self) -> Cur {
+        self.to
+    }
+
+    /// The mid rate.
+    pub fn rate(&self) -> f64 {
+        self.rate
+    }
+
+    /// The rate the dealer buys `from` at, the mid rate when there is no spread.
+    pub fn bid(&self) -> f64 {
+        self.bid
+    }
+
+    /// The rate the dealer sells `from` at, the mid rate when there is no spread.
+    pub fn ask(&self) -> f64 {
+        self.ask
+    }
+
+    /// The rate for converting in the opposite direction, whose bid is the inverse of this ask.
+    pub fn inverse(&self) -> Self {
+        Self {
+            from: self.to,
+            to: self.from,
+            rate: 1.0 / self.rate,
+            bid: 1.0 / self.ask,
+            ask: 1.0 / self.bid,
+        }
+    }
+
+    /// The rate converting from this rate's `from` straight into `next`'s `to`, multiplying
+    /// the mids, bids and asks; fails unless `next` starts from this rate's `to`.
+    pub fn compose(&self, next: &Self) -> Result<Self, MoneyConversionError<Cur>> {
+        if next.from != self.to {
+            return Err(MoneyConversionError::CurrencyMismatch(self.to, next.from));
+        }
+        Ok(Self {
+            from: self.from,
+            to: next.to,
+            rate: self.rate * next.rate,
+            bid: self.bid * next.bid,
+            ask: self.ask * next.ask,
+        })
+    }
+
+    /// Converts `money` in `from`, sold by the customer, into `to` at the bid.
+    pub fn convert_sell(
+        &self,
+        money: MoneyInner<LowestSubunit, Cur>,
+    ) -> Result<MoneyInner<LowestSubunit, Cur>, MoneyConversionError<Cur>> {
+        Self {
+            rate: self.bid,
+            ..*self
+        }
+        .convert(money)
+    }
+
+    /// Converts `money` in `to`, paid by the customer, into the `from` it buys at the ask.
+    pub fn convert_buy(
+        &self,
+        money: MoneyInner<LowestSubunit, Cur>,
+    ) -> Result<MoneyInner<LowestSubunit, Cur>, MoneyConversionError<Cur>> {
+        let inverse = self.inverse();
+        Self {
+            rate: inverse.bid,
+            ..inverse
+        }
+        .convert(money)
+    }
+
+    /// Converts `money` into the target currency, going through the major units of both.
+    ///
+    /// The result is narrowed to subunits the same way as `MoneyInner::<HighestUnit, _>::convert`.
+    pub fn convert(
+        &self,
+        money: MoneyInner<LowestSubunit, Cur>,
+    ) -> Result<MoneyInner<LowestSubunit, Cur>, MoneyConversionError<Cur>> {
+        #[cfg(feature = "tracing")]
+        let _span = self.span(&money).entered();
+        let converted = self.ensure_source(&money).and_then(|()| {
+            let major = to_major_units(money)?.amount() * self.rate;
+            to_subunits(MoneyInner::<HighestUnit, Cur>::new(major, &self.to))
+        });
+        observe(ConversionKind::Exchange, &money.currency, converted)
+    }
+
+    /// Converts `money` into the target currency, rounding to whole subunits with `rounding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::exchange::ExchangeRate;
+    /// use amount_conversion::factor::Currency::*;
+    /// use amount_conversion::rounding::RoundingMode;
+    ///
+    /// let rate = ExchangeRate::new(USD, EUR, 0.92).unwrap();
+    /// let converted = rate.convert_with(MoneyInner::<LowestSubunit, _>::new(1999, &USD), RoundingMode::HalfUp);
+    /// assert_eq!(converted, Ok(MoneyInner::<LowestSubunit, _>::new(1839, &EUR)));
+    /// ```
+    pub fn convert_with(
+        &self,
+        money: MoneyInner<LowestSubunit, Cur>,
+        rounding: RoundingMode,
+    ) -> Result<MoneyInner<LowestSubunit, Cur>, MoneyConversionError<Cur>> {
+        #[cfg(feature = "tracing")]
+        let _span = self.span(&money).entered();
+        observe(
+            ConversionKind::Exchange,
+            &money.currency,
+            self.round_into_target(money, rounding),
+        )
+    }
+
+    fn round_into_target(
+        &self,
+        money: MoneyInner<LowestSubunit, Cur>,
+        rounding: RoundingMode,
+    ) -> Result<MoneyInner<LowestSubunit, Cur>, MoneyConversionError<Cur>> {
+        let subunits = f64::from(money.amount) * self.subunit_rate(&money)?;
+        let amount = f64_to_i32(round_f64(snap_to_whole(subunits), rounding), self.to)?;
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            ?rounding,
+            exact = subunits,
+            subunits = amount,
+            delta = f64::from(amount) - subunits,
+            "converted between currencies"
+        );
+        Ok(MoneyInner::<LowestSubunit, _>::new(amount, &self.to))
+    }
+
+    #[cfg(feature = "tracing")]
+    fn span(&self, money: &MoneyInner<LowestSubunit, Cur>) -> tracing::Span {
+        tracing::debug_span!(
+            "exchange",
+            from = self.from.currency_code(),
+            to = self.to.currency_code(),
+            rate = self.rate,
+            amount = money.amount,
+        )
+    }
+
+    /// Target subunits per source subunit, after checking `money` is in the source currency.
+    pub(crate) fn subunit_rate(
+        &self,
+        money: &MoneyInner<LowestSubunit, Cur>,
+    ) -> Result<f64, MoneyConversionError<Cur>> {
+        self.ensure_source(money)?;
+        let target = MoneyInner {
+            amount: (),
+            currency: self.to,
+        };
+        Ok(self.rate * get_factor(&target)? / get_factor(money)?)
+    }
+
+    fn ensure_source(
+        &self,
+        money: &MoneyInner<LowestSubunit, Cur>,
+    ) -> Result<(), MoneyConversionError<Cur>> {
+        if money.currency != self.from {
+            return Err(MoneyConversionError::CurrencyMismatch(
+                self.from,
+                money.currency,
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn convert_across_exponents() -> Result<(), MoneyConversionError<Currency>> {
+        let rate = ExchangeRate::new(KWD, JPY, 480.0)?;
+        assert_eq!(
+            rate.convert(Money::new(2500, &KWD))?,
+            Money::new(1200, &JPY)
+        );
+        assert_eq!(
+            rate.inverse().convert(Money::new(1200, &JPY))?,
+            Money::new(2500, &KWD)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn convert_with_rounding() -> Result<(), MoneyConversionError<Currency>> {
+        let rate = ExchangeRate::new(USD, EUR, 0.5)?;
+        assert_eq!(
+            rate.convert_with(Money::new(3, &USD), RoundingMode::HalfEven)?,
+            Money::new(2, &EUR)
+        );
+        assert_eq!(
+            rate.convert_with(Money::new(3, &USD), RoundingMode::Floor)?,
+            Money::new(1, &EUR)
+        );
+        let rate = ExchangeRate::new(KWD, JPY, 480.0)?;
+        assert_eq!(
+            rate.convert_with(Money::new(2501, &KWD), RoundingMode::Ceil)?,
+            Money::new(1201, &JPY)
+        );
+        // 100 * 0.29 is 28.999999999999996 as a float
+        let rate = ExchangeRate::new(USD, EUR, 0.29)?;
+        for rounding in [RoundingMode::Floor, RoundingMode::Ceil, RoundingMode::Trunc] {
+            assert_eq!(
+                rate.convert_with(Money::new(100, &USD), rounding)?,
+                Money::new(29, &EUR)
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        for rate in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                ExchangeRate::new(USD, INR, rate),
+                Err(MoneyConversionError::InvalidExchangeRate)
+            );
+        }
+        let rate = ExchangeRate::new(USD, INR, 83.0).unwrap();
+        assert_eq!(
+            rate.convert(Money::new(100, &EUR)),
+            Err(MoneyConversionError::CurrencyMismatch(USD, EUR))
+        );
+    }
+
+    #[test]
+    fn spread_sides() -> Result<(), MoneyConversionError<Currency>> {
+        let quote = ExchangeRate::with_spread(USD, INR, 83.0, 84.0)?;
+        assert_eq!((quote.bid(), quote.rate(), quote.ask()), (83.0, 83.5, 84.0));
+        assert_eq!(
+            quote.convert(Money::new(100, &USD))?,
+            Money::new(8_350, &INR)
+        );
+        assert_eq!(
+            quote.convert_sell(Money::new(100, &USD))?,
+            Money::new(8_300, &INR)
+        );
+        assert_eq!(
+            quote.convert_buy(Money::new(8_400, &INR))?,
+            Money::new(100, &USD)
+        );
+        assert_eq!(
+            quote.convert_buy(Money::new(100, &USD)),
+            Err(MoneyConversionError::CurrencyMismatch(INR, USD))
+        );
+
+        let inverse = quote.inverse();
+        assert_eq!((inverse.bid(), inverse.ask()), (1.0 / 84.0, 1.0 / 83.0));
+        let cross = ExchangeRate::with_spread(EUR, USD, 1.0, 1.5)?.compose(&quote)?;
+        assert_eq!(
+            (cross.bid(), cross.rate(), cross.ask()),
+            (83.0, 104.375, 126.0)
+        );
+        assert_eq!(
+            quote.compose(&quote),
+            Err(MoneyConversionError::CurrencyMismatch(INR, USD))
+        );
+        let flat = ExchangeRate::new(USD, INR, 83.0)?;
+        assert_eq!((flat.bid(), flat.ask()), (83.0, 83.0));
+
+        for (bid, ask) in [(84.0, 83.0), (0.0, 83.0), (83.0, f64::INFINITY)] {
+            assert_eq!(
+                ExchangeRate::with_spread(USD, INR, bid, ask),
+                Err(MoneyConversionError::InvalidExchangeRate)
+            );
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn traces_span_and_delta() {
+        use std::fmt::{self, Write};
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata};
+
+        struct Recorder(Arc<Mutex<Vec<String>>>);
+        struct Fields<'a>(&'a mut String);
+
+        impl Visit for Fields<'_> {
+            fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+                write!(self.0, " {}={value:?}", field.name()).unwrap();
+            }
+        }
+
+        impl tracing::Subscriber for Recorder {
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut line = span.metadata().name().to_string();
+                span.record(&mut Fields(&mut line));
+                self.0.lock().unwrap().push(line);
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, event: &Event<'_>) {
+                let mut line = String::new();
+                event.record(&mut Fields(&mut line));
+                self.0.lock().unwrap().push(line);
+            }
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let rate = ExchangeRate::new(USD, JPY, 150.0).unwrap();
+        let converted = tracing::subscriber::with_default(Recorder(lines.clone()), || {
+            rate.convert_with(Money::new(333, &USD), RoundingMode::Floor)
+        });
+        assert_eq!(converted, Ok(Money::new(499, &JPY)));
+
+        let lines = lines.lock().unwrap();
+        assert_eq!(
+            lines[0],
+            r#"exchange from="USD" to="JPY" rate=150.0 amount=333"#
+        );
+        assert_eq!(
+            lines[1],
+            " message=converted between currencies rounding=Floor exact=499.5 subunits=499 delta=-0.5"
+        );
+    }
+}
@@ -1,46 +1,95 @@
 use once_cell::sync::Lazy;
 use std::collections::HashMap;
+use std::fmt;
 use std::hash::Hash;
+use std::str::FromStr;
+use std::sync::RwLock;
 
 use crate::amount;
 
 use self::Currency::*;
 
-static SUBUNIT: Lazy<HashMap<Currency, i16>> = Lazy::new(|| {
-    let mut map = HashMap::new();
-    ZERO_DECIMAL_PAIR.into_iter().for_each(|f| {
-        map.insert(f.0, f.1 as i16);
-    });
-    TWO_DECIMAL_PAIR.into_iter().for_each(|f| {
-        map.insert(f.0, f.1 as i16);
-    });
-    THREE_DECIMAL_PAIR.into_iter().for_each(|f| {
-        map.insert(f.0, f.1);
-    });
-    map
-});
+/// The largest supported subunit exponent, so factors up to `10^18` fit an `i64`.
+pub const MAX_EXPONENT: u32 = 18;
 
-static ZERO_DECIMAL_PAIR: Lazy<[(Currency, i8); 16]> = Lazy::new(|| {
-    [
-        BIF, CLP, DJF, GNF, JPY, KMF, KRW, MGA, PYG, RWF, UGX, VND, VUV, XAF, XOF, XPF,
-    ]
-    .map(|currency| (currency, 1))
-});
+/// `10^exponent` for every supported exponent, all exactly representable in an `f64`.
+static POWERS_OF_TEN: [f64; MAX_EXPONENT as usize + 1] = [
+    1e0, 1e1, 1e2, 1e3, 1e4, 1e5, 1e6, 1e7, 1e8, 1e9, 1e10, 1e11, 1e12, 1e13, 1e14, 1e15, 1e16,
+    1e17, 1e18,
+];
 
-static TWO_DECIMAL_PAIR: Lazy<[(Currency, i8); 98]> = Lazy::new(|| {
-    [
-        AED, ALL, AMD, ANG, ARS, AUD, AWG, AZN, BBD, BDT, BMD, BND, BOB, BRL, BSD, BWP, BZD, CAD,
-        CHF, CNY, COP, CRC, CUP, CZK, DKK, DOP, DZD, EGP, ETB, EUR, FJD, GBP, GHS, GIP, GMD, GTQ,
-        GYD, HKD, HNL, HRK, HTG, HUF, IDR, ILS, INR, JMD, KES, KGS, KHR, KYD, KZT, LAK, LBP, LKR,
-        LRD, LSL, MAD, MDL, MKD, MMK, MNT, MOP, MUR, MVR, MWK, MXN, MYR, NAD, NGN, NIO, NOK, NPR,
-        NZD, PEN, PGK, PHP, PKR, PLN, QAR, RUB, SAR, SCR, SEK, SGD, SLL, SOS, SSP, SVC, SZL, THB,
-        TTD, TWD, TZS, USD, UYU, UZS, YER, ZAR,
-    ]
-    .map(|currency| (currency, 100))
-});
+/// `10^-exponent` for every supported exponent, each the correctly rounded `1.0 / 10^exponent`.
+static RECIPROCALS: [f64; MAX_EXPONENT as usize + 1] = [
+    1e0, 1e-1, 1e-2, 1e-3, 1e-4, 1e-5, 1e-6, 1e-7, 1e-8, 1e-9, 1e-10, 1e-11, 1e-12, 1e-13, 1e-14,
+    1e-15, 1e-16, 1e-17, 1e-18,
+];
+
+/// Number of decimals of each currency's subunit, indexed by `Currency::index`; factors are
+/// derived as `10^exponent`.
+///
+/// Built at compile time, so a lookup is a plain array read without hashing.
+static SUBUNIT: [u32; Currency::COUNT] = {
+    let mut exponents = [None; Currency::COUNT];
+    exponents = with_exponent(exponents, &ZERO_DECIMAL, 0);
+    exponents = with_exponent(exponents, &TWO_DECIMAL, 2);
+    exponents = with_exponent(exponents, &THREE_DECIMAL, 3);
+    let mut subunit = [0; Currency::COUNT];
+    let mut index = 0;
+    while index < Currency::COUNT {
+        subunit[index] = match exponents[index] {
+            Some(exponent) => exponent,
+            None => panic!("every currency needs a subunit exponent"),
+        };
+        index += 1;
+    }
+    subunit
+};
+
+const ZERO_DECIMAL: [Currency; 16] = [
+    BIF, CLP, DJF, GNF, JPY, KMF, KRW, MGA, PYG, RWF, UGX, VND, VUV, XAF, XOF, XPF,
+];
 
-static THREE_DECIMAL_PAIR: Lazy<[(Currency, i16); 5]> =
-    Lazy::new(|| [BHD, JOD, KWD, OMR, TND].map(|currency| (currency, 1000)));
+const TWO_DECIMAL: [Currency; 98] = [
+    AED, ALL, AMD, ANG, ARS, AUD, AWG, AZN, BBD, BDT, BMD, BND, BOB, BRL, BSD, BWP, BZD, CAD, CHF,
+    CNY, COP, CRC, CUP, CZK, DKK, DOP, DZD, EGP, ETB, EUR, FJD, GBP, GHS, GIP, GMD, GTQ, GYD, HKD,
+    HNL, HRK, HTG, HUF, IDR, ILS, INR, JMD, KES, KGS, KHR, KYD, KZT, LAK, LBP, LKR, LRD, LSL, MAD,
+    MDL, MKD, MMK, MNT, MOP, MUR, MVR, MWK, MXN, MYR, NAD, NGN, NIO, NOK, NPR, NZD, PEN, PGK, PHP,
+    PKR, PLN, QAR, RUB, SAR, SCR, SEK, SGD, SLL, SOS, SSP, SVC, SZL, THB, TTD, TWD, TZS, USD, UYU,
+    UZS, YER, ZAR,
+];
+
+const THREE_DECIMAL: [Currency; 5] = [BHD, JOD, KWD, OMR, TND];
+
+/// Sets the exponent of `currencies`, failing compilation when one is listed twice.
+const fn with_exponent<const N: usize>(
+    mut exponents: [Option<u32>; Currency::COUNT],
+    currencies: &[Currency; N],
+    exponent: u32,
+) -> [Option<u32>; Currency::COUNT] {
+    let mut i = 0;
+    while i < N {
+        let index = currencies[i].index();
+        if exponents[index].is_some() {
+            panic!("currency listed with two subunit exponents");
+        }
+        exponents[index] = Some(exponent);
+        i += 1;
+    }
+    exponents
+}
+
+/// Non-ISO shorthand seen in external feeds, extended by `Currency::register_alias`.
+static ALIASES: Lazy<RwLock<HashMap<String, Currency>>> = Lazy::new(|| {
+    let builtin = [
+        ("CNH", CNY),
+        ("NIS", ILS),
+        ("NTD", TWD),
+        ("RMB", CNY),
+        ("STG", GBP),
+    ];
+    let aliases = builtin.map(|(alias, currency)| (alias.to_string(), currency));
+    RwLock::new(HashMap::from(aliases))
+});
 
 /// A trait for converting a custom currency type to a `&str`.
 ///
@@ -78,9 +127,151 @@ pub trait FromCurrency: Eq + Hash + Copy {
     /// assert_eq!(currency, custom_currency.currency());
     /// ```
     fn currency(&self) -> Currency;
+
+    /// Overrides the number of decimals of the currency's subunit, for integrations whose
+    /// gateway disagrees with ISO 4217, e.g. one treating HUF as having no decimals.
+    ///
+    /// The override applies everywhere the subunit is used: conversions, parsing, formatting and
+    /// checks such as `from_major_minor`. `None`, the default, keeps the crate's table. Exponents
+    /// above [`MAX_EXPONENT`] make those operations fail with `InvalidFactor`.
+    /// A fixed set of overrides can also be declared with
+    /// [`subunit_overrides!`](crate::subunit_overrides) instead of a custom type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::{Currency, FromCurrency};
+    ///
+    /// /// Currencies as sent to a gateway that has no minor units for HUF and TWD.
+    /// #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+    /// struct GatewayCurrency(Currency);
+    ///
+    /// impl FromCurrency for GatewayCurrency {
+    ///     fn currency(&self) -> Currency {
+    ///         self.0
+    ///     }
+    ///
+    ///     fn exponent_override(&self) -> Option<u32> {
+    ///         match self.0 {
+    ///             Currency::HUF | Currency::TWD => Some(0),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// type Money = MoneyInner<LowestSubunit, GatewayCurrency>;
+    ///
+    /// let huf = GatewayCurrency(Currency::HUF);
+    /// let money = Money::from_str_exact("1500", &huf).unwrap();
+    /// assert_eq!(money.amount(), 1500);
+    /// assert_eq!(money.convert().unwrap().amount(), 1500.0);
+    /// assert!(Money::from_str_exact("1500.50", &huf).is_err());
+    ///
+    /// let usd = Money::from_str_exact("15.50", &GatewayCurrency(Currency::USD)).unwrap();
+    /// assert_eq!(usd.amount(), 1550);
+    /// ```
+    fn exponent_override(&self) -> Option<u32> {
+        None
+    }
+
+    /// The code identifying the currency wherever money is written out: CSV rows, formatted
+    /// and redacted amounts, error messages, observer events and logs. Defaults to the ISO code
+    /// of [`currency`](Self::currency); types with codes of their own, such as a private
+    /// [`CurrencyCode`], return those so the amount keeps its identity.
+    fn currency_code(&self) -> &str {
+        self.currency().code()
+    }
 }
 
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+/// The symbol to print for `currency`, `None` when it has none or reports a code of its own,
+/// which then stands in for the symbol.
+pub(crate) fn symbol_of<Cur: FromCurrency>(currency: &Cur) -> Option<&'static str> {
+    let iso = currency.currency();
+    if currency.currency_code() == iso.code() {
+        iso.symbol()
+    } else {
+        None
+    }
+}
+
+/// The reverse of [`FromCurrency`]: builds the custom currency type from an ISO `Currency`.
+///
+/// Implementing it lets [`serde_helpers::iso_currency`](crate::serde_helpers::iso_currency)
+/// read gateway payloads carrying ISO codes straight into a custom currency type.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::factor::{Currency, FromCurrency, TryFromCurrency};
+///
+/// #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+/// enum UserCurrency {
+///     Inr,
+///     Usd,
+/// }
+///
+/// impl FromCurrency for UserCurrency {
+///     fn currency(&self) -> Currency {
+///         match self {
+///             UserCurrency::Inr => Currency::INR,
+///             UserCurrency::Usd => Currency::USD,
+///         }
+///     }
+/// }
+///
+/// impl TryFromCurrency for UserCurrency {
+///     fn try_from_currency(currency: Currency) -> Option<Self> {
+///         match currency {
+///             Currency::INR => Some(UserCurrency::Inr),
+///             Currency::USD => Some(UserCurrency::Usd),
+///             _ => None,
+///         }
+///     }
+/// }
+///
+/// assert_eq!(UserCurrency::try_from_currency(Currency::USD), Some(UserCurrency::Usd));
+/// assert_eq!(UserCurrency::try_from_currency(Currency::EUR), None);
+/// ```
+pub trait TryFromCurrency: FromCurrency {
+    /// The custom currency for `currency`, or `None` when the type has no such currency.
+    ///
+    /// Implementations should round-trip with [`FromCurrency::currency`].
+    fn try_from_currency(currency: Currency) -> Option<Self>;
+}
+
+/// ISO 4217 currencies known to the subunit map.
+///
+/// Variants are declared in alphabetical order, so the derived `Ord` sorts by currency code.
+///
+/// The `strum` feature also derives strum's `IntoStaticStr`, `EnumIter` and `EnumCount`, e.g.
+/// `Currency::iter()` in code order. `EnumString` is left out because `Currency` already
+/// implements `FromStr`, with suggestions for unknown codes.
+///
+/// The enum is `#[non_exhaustive]` so new ISO codes can be added in minor releases. Matches
+/// outside this crate need a wildcard arm, and codes read at runtime are best looked up with
+/// [`Currency::try_from_code`], which starts returning the new currencies once they are added.
+///
+/// ```
+/// use amount_conversion::factor::Currency;
+///
+/// fn region(currency: Currency) -> &'static str {
+///     match currency {
+///         Currency::EUR | Currency::GBP | Currency::CHF => "europe",
+///         Currency::USD | Currency::CAD => "north_america",
+///         _ => "rest_of_world",
+///     }
+/// }
+///
+/// assert_eq!(Currency::try_from_code("CHF").map(region), Some("europe"));
+/// assert_eq!(Currency::try_from_code("XYZ"), None);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "strum",
+    derive(strum::IntoStaticStr, strum::EnumIter, strum::EnumCount)
+)]
+#[non_exhaustive]
 pub enum Currency {
     AED,
     ALL,
@@ -109,9 +300,9 @@ pub enum Currency {
     CRC,
     CUP,
     CZK,
+    DJF,
     DKK,
     DOP,
-    DJF,
     DZD,
     EGP,
     ETB,
@@ -203,11 +394,1007 @@ pub enum Currency {
     ZAR,
 }
 
+/// How many decimals a currency's subunit has, as returned by [`Currency::decimal_class`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum DecimalClass {
+    /// No subunit, e.g. JPY.
+    Zero,
+    /// Hundredths, e.g. USD.
+    Two,
+    /// Thousandths, e.g. KWD.
+    Three,
+}
+
+impl Currency {
+    /// The number of variants, `ZAR` being the last.
+    pub(crate) const COUNT: usize = ZAR as usize + 1;
+
+    /// Position of the currency in tables indexed by discriminant, such as `SUBUNIT`.
+    pub(crate) const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// The currency with the uppercase ISO 4217 alphabetic code `code`, or `None` when it is
+    /// unknown to this version of the crate. Use `parse` for an error with suggestions.
+    pub fn try_from_code(code: &str) -> Option<Self> {
+        code.parse().ok()
+    }
+
+    /// Returns the ISO 4217 alphabetic code of the currency, e.g. `"USD"`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AED => "AED",
+            ALL => "ALL",
+            AMD => "AMD",
+            ANG => "ANG",
+            ARS => "ARS",
+            AUD => "AUD",
+            AWG => "AWG",
+            AZN => "AZN",
+            BBD => "BBD",
+            BDT => "BDT",
+            BHD => "BHD",
+            BIF => "BIF",
+            BMD => "BMD",
+            BND => "BND",
+            BOB => "BOB",
+            BRL => "BRL",
+            BSD => "BSD",
+            BWP => "BWP",
+            BZD => "BZD",
+            CAD => "CAD",
+            CHF => "CHF",
+            CLP => "CLP",
+            CNY => "CNY",
+            COP => "COP",
+            CRC => "CRC",
+            CUP => "CUP",
+            CZK => "CZK",
+            DJF => "DJF",
+            DKK => "DKK",
+            DOP => "DOP",
+            DZD => "DZD",
+            EGP => "EGP",
+            ETB => "ETB",
+            EUR => "EUR",
+            FJD => "FJD",
+            GBP => "GBP",
+            GHS => "GHS",
+            GIP => "GIP",
+            GMD => "GMD",
+            GNF => "GNF",
+            GTQ => "GTQ",
+            GYD => "GYD",
+            HKD => "HKD",
+            HNL => "HNL",
+            HRK => "HRK",
+            HTG => "HTG",
+            HUF => "HUF",
+            IDR => "IDR",
+            ILS => "ILS",
+            INR => "INR",
+            JMD => "JMD",
+            JOD => "JOD",
+            JPY => "JPY",
+            KES => "KES",
+            KGS => "KGS",
+            KHR => "KHR",
+            KMF => "KMF",
+            KRW => "KRW",
+            KWD => "KWD",
+            KYD => "KYD",
+            KZT => "KZT",
+            LAK => "LAK",
+            LBP => "LBP",
+            LKR => "LKR",
+            LRD => "LRD",
+            LSL => "LSL",
+            MAD => "MAD",
+            MDL => "MDL",
+            MGA => "MGA",
+            MKD => "MKD",
+            MMK => "MMK",
+            MNT => "MNT",
+            MOP => "MOP",
+            MUR => "MUR",
+            MVR => "MVR",
+            MWK => "MWK",
+            MXN => "MXN",
+            MYR => "MYR",
+            NAD => "NAD",
+            NGN => "NGN",
+            NIO => "NIO",
+            NOK => "NOK",
+            NPR => "NPR",
+            NZD => "NZD",
+            OMR => "OMR",
+            PEN => "PEN",
+            PGK => "PGK",
+            PHP => "PHP",
+            PKR => "PKR",
+            PLN => "PLN",
+            PYG => "PYG",
+            QAR => "QAR",
+            RUB => "RUB",
+            RWF => "RWF",
+            SAR => "SAR",
+            SCR => "SCR",
+            SEK => "SEK",
+            SGD => "SGD",
+            SLL => "SLL",
+            SOS => "SOS",
+            SSP => "SSP",
+            SVC => "SVC",
+            SZL => "SZL",
+            THB => "THB",
+            TND => "TND",
+            TTD => "TTD",
+            TWD => "TWD",
+            TZS => "TZS",
+            UGX => "UGX",
+            USD => "USD",
+            UYU => "UYU",
+            UZS => "UZS",
+            VND => "VND",
+            VUV => "VUV",
+            XAF => "XAF",
+            XOF => "XOF",
+            XPF => "XPF",
+            YER => "YER",
+            ZAR => "ZAR",
+        }
+    }
+
+    /// Returns the ISO 4217 numeric code of the currency, e.g. `840` for USD, as used by
+    /// ISO 8583 card messages.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::factor::Currency;
+    ///
+    /// assert_eq!(Currency::USD.numeric_code(), 840);
+    /// assert_eq!(Currency::from_numeric_code(8), Some(Currency::ALL));
+    /// assert_eq!(Currency::from_numeric_code(999), None);
+    /// ```
+    pub fn numeric_code(&self) -> u16 {
+        match self {
+            AED => 784,
+            ALL => 8,
+            AMD => 51,
+            ANG => 532,
+            ARS => 32,
+            AUD => 36,
+            AWG => 533,
+            AZN => 944,
+            BBD => 52,
+            BDT => 50,
+            BHD => 48,
+            BIF => 108,
+            BMD => 60,
+            BND => 96,
+            BOB => 68,
+            BRL => 986,
+            BSD => 44,
+            BWP => 72,
+            BZD => 84,
+            CAD => 124,
+            CHF => 756,
+            CLP => 152,
+            CNY => 156,
+            COP => 170,
+            CRC => 188,
+            CUP => 192,
+            CZK => 203,
+            DJF => 262,
+            DKK => 208,
+            DOP => 214,
+            DZD => 12,
+            EGP => 818,
+            ETB => 230,
+            EUR => 978,
+            FJD => 242,
+            GBP => 826,
+            GHS => 936,
+            GIP => 292,
+            GMD => 270,
+            GNF => 324,
+            GTQ => 320,
+            GYD => 328,
+            HKD => 344,
+            HNL => 340,
+            HRK => 191,
+            HTG => 332,
+            HUF => 348,
+            IDR => 360,
+            ILS => 376,
+            INR => 356,
+            JMD => 388,
+            JOD => 400,
+            JPY => 392,
+            KES => 404,
+            KGS => 417,
+            KHR => 116,
+            KMF => 174,
+            KRW => 410,
+            KWD => 414,
+            KYD => 136,
+            KZT => 398,
+            LAK => 418,
+            LBP => 422,
+            LKR => 144,
+            LRD => 430,
+            LSL => 426,
+            MAD => 504,
+            MDL => 498,
+            MGA => 969,
+            MKD => 807,
+            MMK => 104,
+            MNT => 496,
+            MOP => 446,
+            MUR => 480,
+            MVR => 462,
+            MWK => 454,
+            MXN => 484,
+            MYR => 458,
+            NAD => 516,
+            NGN => 566,
+            NIO => 558,
+            NOK => 578,
+            NPR => 524,
+            NZD => 554,
+            OMR => 512,
+            PEN => 604,
+            PGK => 598,
+            PHP => 608,
+            PKR => 586,
+            PLN => 985,
+            PYG => 600,
+            QAR => 634,
+            RUB => 643,
+            RWF => 646,
+            SAR => 682,
+            SCR => 690,
+            SEK => 752,
+            SGD => 702,
+            SLL => 694,
+            SOS => 706,
+            SSP => 728,
+            SVC => 222,
+            SZL => 748,
+            THB => 764,
+            TND => 788,
+            TTD => 780,
+            TWD => 901,
+            TZS => 834,
+            UGX => 800,
+            USD => 840,
+            UYU => 858,
+            UZS => 860,
+            VND => 704,
+            VUV => 548,
+            XAF => 950,
+            XOF => 952,
+            XPF => 953,
+            YER => 886,
+            ZAR => 710,
+        }
+    }
+
+    /// The currency with the ISO 4217 numeric `code`, if it is supported.
+    pub fn from_numeric_code(code: u16) -> Option<Self> {
+        Self::all().find(|currency| currency.numeric_code() == code)
+    }
+
+    /// Every supported currency.
+    fn all() -> impl Iterator<Item = Self> {
+        ZERO_DECIMAL
+            .into_iter()
+            .chain(TWO_DECIMAL)
+            .chain(THREE_DECIMAL)
+    }
+
+    /// Returns the commonly used symbol of the currency, e.g. `"₹"` for INR, if it has a
+    /// distinctive one.
+    pub fn symbol(&self) -> Option<&'static str> {
+        let symbol = match self {
+            AUD => "A$",
+            BDT => "৳",
+            BRL => "R$",
+            CAD => "CA$",
+            CNY => "CN¥",
+            EUR => "€",
+            GBP => "£",
+            HKD => "HK$",
+            ILS => "₪",
+            INR => "₹",
+            JPY => "¥",
+            KRW => "₩",
+            KZT => "₸",
+            MXN => "MX$",
+            NGN => "₦",
+            NZD => "NZ$",
+            PHP => "₱",
+            PLN => "zł",
+            RUB => "₽",
+            SGD => "S$",
+            THB => "฿",
+            USD => "$",
+            VND => "₫",
+            ZAR => "R",
+            _ => return None,
+        };
+        Some(symbol)
+    }
+
+    /// Returns the number of decimals of the currency's subunit, e.g. `2` for USD.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::factor::Currency;
+    ///
+    /// assert_eq!(Currency::JPY.exponent(), 0);
+    /// assert_eq!(Currency::KWD.exponent(), 3);
+    /// assert_eq!(Currency::KWD.factor(), 1000);
+    /// ```
+    pub fn exponent(&self) -> u32 {
+        SUBUNIT[self.index()]
+    }
+
+    /// Returns the number of subunits in one major unit, `10^exponent`, e.g. `100` for USD.
+    pub fn factor(&self) -> i64 {
+        10_i64.pow(self.exponent())
+    }
+
+    /// Classifies the currency by the number of decimals of its subunit, e.g. to decide whether
+    /// a UI shows a decimal input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::factor::{Currency, DecimalClass};
+    ///
+    /// assert_eq!(Currency::USD.decimal_class(), DecimalClass::Two);
+    /// assert!(Currency::JPY.is_zero_decimal());
+    /// assert!(Currency::KWD.is_three_decimal());
+    /// ```
+    pub fn decimal_class(&self) -> DecimalClass {
+        match self.exponent() {
+            0 => DecimalClass::Zero,
+            3 => DecimalClass::Three,
+            _ => DecimalClass::Two,
+        }
+    }
+
+    /// Whether the currency has no subunit, e.g. JPY or KRW.
+    pub fn is_zero_decimal(&self) -> bool {
+        self.decimal_class() == DecimalClass::Zero
+    }
+
+    /// Whether the currency's subunit is a thousandth, e.g. KWD or BHD.
+    pub fn is_three_decimal(&self) -> bool {
+        self.decimal_class() == DecimalClass::Three
+    }
+
+    /// Resolves an ISO 4217 code or an alias such as `"RMB"`, `"NIS"` or `"STG"`, ignoring ASCII
+    /// case.
+    ///
+    /// ISO codes take precedence over aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::factor::Currency;
+    ///
+    /// assert_eq!(Currency::from_alias("RMB"), Ok(Currency::CNY));
+    /// assert_eq!(Currency::from_alias("stg"), Ok(Currency::GBP));
+    /// assert_eq!(Currency::from_alias("usd"), Ok(Currency::USD));
+    /// assert!(Currency::from_alias("BUCKS").is_err());
+    ///
+    /// Currency::register_alias("BUCKS", Currency::USD);
+    /// assert_eq!(Currency::from_alias("Bucks"), Ok(Currency::USD));
+    /// ```
+    pub fn from_alias(alias: &str) -> Result<Self, ParseCurrencyError> {
+        let normalized = alias.to_ascii_uppercase();
+        if let Ok(currency) = normalized.parse() {
+            return Ok(currency);
+        }
+        let aliases = ALIASES.read().unwrap_or_else(|e| e.into_inner());
+        aliases
+            .get(&normalized)
+            .copied()
+            .ok_or_else(|| ParseCurrencyError::new(alias))
+    }
+
+    /// Registers `alias` for [`Currency::from_alias`] process-wide, ignoring ASCII case and
+    /// replacing any earlier mapping of the same alias, built-in ones included.
+    pub fn register_alias(alias: &str, currency: Self) {
+        let mut aliases = ALIASES.write().unwrap_or_else(|e| e.into_inner());
+        aliases.insert(alias.to_ascii_uppercase(), currency);
+    }
+}
+
+impl fmt::Display for Currency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// An error returned when parsing a `Currency` from an unknown code, with the closest known
+/// codes as suggestions.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::factor::Currency;
+///
+/// let err = "UDS".parse::<Currency>().unwrap_err();
+/// assert_eq!(err.suggestions(), [Currency::USD, Currency::UZS]);
+/// assert_eq!(err.to_string(), "unknown currency code `UDS`, did you mean USD or UZS?");
+///
+/// let err = serde_json::from_str::<Currency>(r#""EUO""#).unwrap_err();
+/// assert!(err.to_string().starts_with("unknown currency code `EUO`, did you mean EUR?"));
+/// assert!("XXXXXX".parse::<Currency>().unwrap_err().suggestions().is_empty());
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseCurrencyError {
+    code: String,
+    suggestions: Vec<Currency>,
+}
+
+/// Largest edit distance at which a currency is still suggested for an unknown code.
+const MAX_SUGGESTION_DISTANCE: usize = 1;
+
+impl ParseCurrencyError {
+    fn new(code: &str) -> Self {
+        let normalized = code.to_ascii_uppercase();
+        let mut suggestions: Vec<_> = Currency::all()
+            .filter_map(|currency| {
+                let distance = edit_distance(normalized.as_bytes(), currency.code().as_bytes())?;
+                Some((distance, currency))
+            })
+            .collect();
+        suggestions.sort_unstable();
+        Self {
+            code: code.to_string(),
+            suggestions: suggestions
+                .into_iter()
+                .map(|(_, currency)| currency)
+                .take(3)
+                .collect(),
+        }
+    }
+
+    /// The code that failed to parse.
+    pub fn code(&self) -> &str {
+        &self.code
+    }
+
+    /// Known currencies closest to the code, closest first, e.g. `USD` for `"UDS"` or `"usd"`.
+    pub fn suggestions(&self) -> &[Currency] {
+        &self.suggestions
+    }
+}
+
+/// The optimal string alignment distance between `a` and `b`, counting insertions, deletions,
+/// substitutions and swaps of adjacent letters, or `None` when above `MAX_SUGGESTION_DISTANCE`.
+fn edit_distance(a: &[u8], b: &[u8]) -> Option<usize> {
+    // the distance is at least the length difference, which bounds the work for long input
+    if a.len().abs_diff(b.len()) > MAX_SUGGESTION_DISTANCE {
+        return None;
+    }
+    let mut rows = vec![(0..=b.len()).collect::<Vec<_>>()];
+    for i in 1..=a.len() {
+        let mut row = vec![i; b.len() + 1];
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            row[j] = (rows[i - 1][j] + 1)
+                .min(row[j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                row[j] = row[j].min(rows[i - 2][j - 2] + 1);
+            }
+        }
+        rows.push(row);
+    }
+    Some(rows[a.len()][b.len()]).filter(|&distance| distance <= MAX_SUGGESTION_DISTANCE)
+}
+
+impl fmt::Display for ParseCurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown currency code `{}`", self.code)?;
+        if self.suggestions.is_empty() {
+            return Ok(());
+        }
+        f.write_str(", did you mean ")?;
+        let last = self.suggestions.len() - 1;
+        for (i, currency) in self.suggestions.iter().enumerate() {
+            let separator = match i {
+                0 => "",
+                _ if i == last => " or ",
+                _ => ", ",
+            };
+            write!(f, "{separator}{currency}")?;
+        }
+        f.write_str("?")
+    }
+}
+
+impl std::error::Error for ParseCurrencyError {}
+
+impl serde::Serialize for Currency {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Currency {
+    /// Deserializes an uppercase ISO 4217 code, suggesting close codes for unknown ones.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CodeVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for CodeVisitor {
+            type Value = Currency;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("an ISO 4217 currency code")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, code: &str) -> Result<Currency, E> {
+                code.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(CodeVisitor)
+    }
+}
+
+impl FromStr for Currency {
+    type Err = ParseCurrencyError;
+
+    /// Parses an uppercase ISO 4217 alphabetic code, e.g. `"USD"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "AED" => Ok(AED),
+            "ALL" => Ok(ALL),
+            "AMD" => Ok(AMD),
+            "ANG" => Ok(ANG),
+            "ARS" => Ok(ARS),
+            "AUD" => Ok(AUD),
+            "AWG" => Ok(AWG),
+            "AZN" => Ok(AZN),
+            "BBD" => Ok(BBD),
+            "BDT" => Ok(BDT),
+            "BHD" => Ok(BHD),
+            "BIF" => Ok(BIF),
+            "BMD" => Ok(BMD),
+            "BND" => Ok(BND),
+            "BOB" => Ok(BOB),
+            "BRL" => Ok(BRL),
+            "BSD" => Ok(BSD),
+            "BWP" => Ok(BWP),
+            "BZD" => Ok(BZD),
+            "CAD" => Ok(CAD),
+            "CHF" => Ok(CHF),
+            "CLP" => Ok(CLP),
+            "CNY" => Ok(CNY),
+            "COP" => Ok(COP),
+            "CRC" => Ok(CRC),
+            "CUP" => Ok(CUP),
+            "CZK" => Ok(CZK),
+            "DJF" => Ok(DJF),
+            "DKK" => Ok(DKK),
+            "DOP" => Ok(DOP),
+            "DZD" => Ok(DZD),
+            "EGP" => Ok(EGP),
+            "ETB" => Ok(ETB),
+            "EUR" => Ok(EUR),
+            "FJD" => Ok(FJD),
+            "GBP" => Ok(GBP),
+            "GHS" => Ok(GHS),
+            "GIP" => Ok(GIP),
+            "GMD" => Ok(GMD),
+            "GNF" => Ok(GNF),
+            "GTQ" => Ok(GTQ),
+            "GYD" => Ok(GYD),
+            "HKD" => Ok(HKD),
+            "HNL" => Ok(HNL),
+            "HRK" => Ok(HRK),
+            "HTG" => Ok(HTG),
+            "HUF" => Ok(HUF),
+            "IDR" => Ok(IDR),
+            "ILS" => Ok(ILS),
+            "INR" => Ok(INR),
+            "JMD" => Ok(JMD),
+            "JOD" => Ok(JOD),
+            "JPY" => Ok(JPY),
+            "KES" => Ok(KES),
+            "KGS" => Ok(KGS),
+            "KHR" => Ok(KHR),
+            "KMF" => Ok(KMF),
+            "KRW" => Ok(KRW),
+            "KWD" => Ok(KWD),
+            "KYD" => Ok(KYD),
+            "KZT" => Ok(KZT),
+            "LAK" => Ok(LAK),
+            "LBP" => Ok(LBP),
+            "LKR" => Ok(LKR),
+            "LRD" => Ok(LRD),
+            "LSL" => Ok(LSL),
+            "MAD" => Ok(MAD),
+            "MDL" => Ok(MDL),
+            "MGA" => Ok(MGA),
+            "MKD" => Ok(MKD),
+            "MMK" => Ok(MMK),
+            "MNT" => Ok(MNT),
+            "MOP" => Ok(MOP),
+            "MUR" => Ok(MUR),
+            "MVR" => Ok(MVR),
+            "MWK" => Ok(MWK),
+            "MXN" => Ok(MXN),
+            "MYR" => Ok(MYR),
+            "NAD" => Ok(NAD),
+            "NGN" => Ok(NGN),
+            "NIO" => Ok(NIO),
+            "NOK" => Ok(NOK),
+            "NPR" => Ok(NPR),
+            "NZD" => Ok(NZD),
+            "OMR" => Ok(OMR),
+            "PEN" => Ok(PEN),
+            "PGK" => Ok(PGK),
+            "PHP" => Ok(PHP),
+            "PKR" => Ok(PKR),
+            "PLN" => Ok(PLN),
+            "PYG" => Ok(PYG),
+            "QAR" => Ok(QAR),
+            "RUB" => Ok(RUB),
+            "RWF" => Ok(RWF),
+            "SAR" => Ok(SAR),
+            "SCR" => Ok(SCR),
+            "SEK" => Ok(SEK),
+            "SGD" => Ok(SGD),
+            "SLL" => Ok(SLL),
+            "SOS" => Ok(SOS),
+            "SSP" => Ok(SSP),
+            "SVC" => Ok(SVC),
+            "SZL" => Ok(SZL),
+            "THB" => Ok(THB),
+            "TND" => Ok(TND),
+            "TTD" => Ok(TTD),
+            "TWD" => Ok(TWD),
+            "TZS" => Ok(TZS),
+            "UGX" => Ok(UGX),
+            "USD" => Ok(USD),
+            "UYU" => Ok(UYU),
+            "UZS" => Ok(UZS),
+            "VND" => Ok(VND),
+            "VUV" => Ok(VUV),
+            "XAF" => Ok(XAF),
+            "XOF" => Ok(XOF),
+            "XPF" => Ok(XPF),
+            "YER" => Ok(YER),
+            "ZAR" => Ok(ZAR),
+            _ => Err(ParseCurrencyError::new(s)),
+        }
+    }
+}
+
+impl FromCurrency for Currency {
+    fn currency(&self) -> Currency {
+        *self
+    }
+}
+
+impl TryFromCurrency for Currency {
+    fn try_from_currency(currency: Currency) -> Option<Self> {
+        Some(currency)
+    }
+}
+
+/// Subunit exponents that replace the ISO ones for some currencies, built at compile time by
+/// [`subunit_overrides!`](crate::subunit_overrides).
+///
+/// A lookup is a plain array read, so overrides need no registry setup at startup. Currencies
+/// are attached to the table with [`SubunitOverrides::apply`], or a custom currency type can
+/// return [`SubunitOverrides::get`] from its `exponent_override`.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::factor::{Currency::{self, HUF, USD}, SubunitOverrides};
+///
+/// /// The exponents of a gateway that has no minor units for HUF and TWD.
+/// static GATEWAY: SubunitOverrides = amount_conversion::subunit_overrides! { HUF => 0, TWD => 0 };
+///
+/// type Money = MoneyInner<LowestSubunit, amount_conversion::factor::OverriddenCurrency>;
+///
+/// assert_eq!(GATEWAY.get(HUF), Some(0));
+/// assert_eq!(GATEWAY.exponent(USD), 2);
+/// assert_eq!(Money::from_str_exact("1500", &GATEWAY.apply(HUF))?.amount(), 1500);
+/// assert!(Money::from_str_exact("1500.50", &GATEWAY.apply(HUF)).is_err());
+/// assert_eq!(Money::from_str_exact("15.50", &GATEWAY.apply(USD))?.amount(), 1550);
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<amount_conversion::factor::OverriddenCurrency>>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SubunitOverrides {
+    exponents: [Option<u32>; Currency::COUNT],
+}
+
+impl SubunitOverrides {
+    /// A table setting the exponent of each listed currency, panicking, and so failing
+    /// compilation in a constant, when one is listed twice or above [`MAX_EXPONENT`].
+    pub const fn new(overrides: &[(Currency, u32)]) -> Self {
+        let mut exponents = [None; Currency::COUNT];
+        let mut i = 0;
+        while i < overrides.len() {
+            let (currency, exponent) = overrides[i];
+            if exponent > MAX_EXPONENT {
+                panic!("subunit override above the largest supported exponent");
+            }
+            if exponents[currency.index()].is_some() {
+                panic!("currency listed with two subunit overrides");
+            }
+            exponents[currency.index()] = Some(exponent);
+            i += 1;
+        }
+        Self { exponents }
+    }
+
+    /// The overridden exponent of `currency`, `None` when it keeps the ISO one.
+    pub fn get(&self, currency: Currency) -> Option<u32> {
+        self.exponents[currency.index()]
+    }
+
+    /// The exponent of `currency` under this table.
+    pub fn exponent(&self, currency: Currency) -> u32 {
+        self.get(currency).unwrap_or_else(|| currency.exponent())
+    }
+
+    /// `currency` with the table's exponents, for use as the currency of `MoneyInner`.
+    pub fn apply(&'static self, currency: Currency) -> OverriddenCurrency {
+        OverriddenCurrency {
+            currency,
+            overrides: self,
+        }
+    }
+}
+
+/// A currency paired with a static [`SubunitOverrides`] table, as returned by
+/// [`SubunitOverrides::apply`].
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OverriddenCurrency {
+    currency: Currency,
+    overrides: &'static SubunitOverrides,
+}
+
+impl FromCurrency for OverriddenCurrency {
+    fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn exponent_override(&self) -> Option<u32> {
+        self.overrides.get(self.currency)
+    }
+}
+
+/// Private three-letter codes registered with `CurrencyCode::register`.
+static PRIVATE_CODES: Lazy<RwLock<HashMap<[u8; 3], CurrencyCode>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// An ISO currency or a private alpha-3 code, such as `"PTS"` for loyalty points, usable as the
+/// currency of `MoneyInner` wherever codes are only known at runtime.
+///
+/// Private codes are registered process-wide with the ISO currency they are valued in and the
+/// number of decimals they are counted in, which replace the ISO ones as with
+/// `exponent_override`. A code carries its registration, so conversions do not look it up.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::factor::{Currency, CurrencyCode, FromCurrency, RegisterCurrencyError};
+///
+/// type Money = MoneyInner<LowestSubunit, CurrencyCode>;
+///
+/// let points = CurrencyCode::register("PTS", Currency::USD, 0).unwrap();
+/// assert_eq!("PTS".parse(), Ok(points));
+/// assert_eq!(points.currency(), Currency::USD);
+/// assert_eq!(points.currency_code(), "PTS");
+/// assert_eq!(
+///     CurrencyCode::register("pts", Currency::USD, 0),
+///     Err(RegisterCurrencyError::InvalidCode("pts".into()))
+/// );
+/// assert_eq!(
+///     CurrencyCode::register("EUR", Currency::USD, 0),
+///     Err(RegisterCurrencyError::IsoCode(Currency::EUR))
+/// );
+/// assert_eq!(Money::from_str_exact("1250", &points)?.amount(), 1_250);
+///
+/// let eur: CurrencyCode = "EUR".parse().unwrap();
+/// assert_eq!(eur.iso(), Some(Currency::EUR));
+/// assert_eq!(Money::from_str_exact("12.50", &eur)?.amount(), 1_250);
+/// assert!("QQQ".parse::<CurrencyCode>().is_err());
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<CurrencyCode>>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CurrencyCode {
+    code: [u8; 3],
+    currency: Currency,
+    exponent: Option<u32>,
+}
+
+impl CurrencyCode {
+    /// Registers the private `code`, valued in `currency` and counted with `exponent` decimals,
+    /// replacing any earlier registration of the same code.
+    ///
+    /// Fails when `code` is not three uppercase ASCII letters, is an ISO code, or `exponent` is
+    /// above [`MAX_EXPONENT`].
+    pub fn register(
+        code: &str,
+        currency: Currency,
+        exponent: u32,
+    ) -> Result<Self, RegisterCurrencyError> {
+        let bytes: [u8; 3] = match code.as_bytes().try_into() {
+            Ok(bytes) if code.bytes().all(|b| b.is_ascii_uppercase()) => bytes,
+            _ => return Err(RegisterCurrencyError::InvalidCode(code.to_string())),
+        };
+        if let Ok(iso) = code.parse::<Currency>() {
+            return Err(RegisterCurrencyError::IsoCode(iso));
+        }
+        if exponent > MAX_EXPONENT {
+            return Err(RegisterCurrencyError::InvalidExponent(exponent));
+        }
+        let registered = Self {
+            code: bytes,
+            currency,
+            exponent: Some(exponent),
+        };
+        let mut codes = PRIVATE_CODES.write().unwrap_or_else(|e| e.into_inner());
+        codes.insert(bytes, registered);
+        Ok(registered)
+    }
+
+    /// The three-letter code, e.g. `"PTS"`.
+    pub fn code(&self) -> &str {
+        std::str::from_utf8(&self.code).expect("currency codes are ASCII")
+    }
+
+    /// The ISO currency, `None` for a private code.
+    pub fn iso(&self) -> Option<Currency> {
+        self.exponent.is_none().then_some(self.currency)
+    }
+}
+
+impl From<Currency> for CurrencyCode {
+    fn from(currency: Currency) -> Self {
+        let code = currency.code().as_bytes();
+        Self {
+            code: [code[0], code[1], code[2]],
+            currency,
+            exponent: None,
+        }
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = ParseCurrencyError;
+
+    /// Parses an uppercase ISO code or a registered private code.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = match s.parse::<Currency>() {
+            Ok(currency) => return Ok(currency.into()),
+            Err(err) => err,
+        };
+        let code: [u8; 3] = s.as_bytes().try_into().map_err(|_| err.clone())?;
+        let codes = PRIVATE_CODES.read().unwrap_or_else(|e| e.into_inner());
+        codes.get(&code).copied().ok_or(err)
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+impl serde::Serialize for CurrencyCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.code())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for CurrencyCode {
+    /// Deserializes an ISO code or a private code registered before deserializing.
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let code = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        code.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl FromCurrency for CurrencyCode {
+    fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    fn exponent_override(&self) -> Option<u32> {
+        self.exponent
+    }
+
+    fn currency_code(&self) -> &str {
+        self.code()
+    }
+}
+
+/// Why [`CurrencyCode::register`] refused a private code.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RegisterCurrencyError {
+    /// The code is not three uppercase ASCII letters.
+    InvalidCode(String),
+    /// The code belongs to this ISO currency.
+    IsoCode(Currency),
+    /// The exponent is above [`MAX_EXPONENT`].
+    InvalidExponent(u32),
+}
+
+impl fmt::Display for RegisterCurrencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCode(code) => write!(
+                f,
+                "private currency code `{code}` is not three uppercase ASCII letters"
+            ),
+            Self::IsoCode(currency) => write!(f, "{currency} is an ISO currency code"),
+            Self::InvalidExponent(exponent) => write!(
+                f,
+                "subunit exponent {exponent} is above the largest supported exponent {MAX_EXPONENT}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for RegisterCurrencyError {}
+
 pub(crate) fn get_factor<T, Cur: FromCurrency>(
     amount: &amount::MoneyInner<T, Cur>,
 ) -> Result<f64, amount::MoneyConversionError<Cur>> {
-    Ok(*SUBUNIT
-        .get(&amount.currency.currency())
-        .ok_or(amount::MoneyConversionError::CurrencyNotFoundInSubunitMap(amount.currency))?
-        as f64)
+    Ok(factor_of(get_exponent(amount)?))
+}
+
+/// The subunit factor for `exponent`, which must be at most `MAX_EXPONENT`.
+pub(crate) fn factor_of(exponent: u32) -> f64 {
+    POWERS_OF_TEN[exponent as usize]
+}
+
+/// The subunit factor for `exponent` together with its reciprocal, for [`to_major`].
+pub(crate) fn factors_of(exponent: u32) -> (f64, f64) {
+    (
+        POWERS_OF_TEN[exponent as usize],
+        RECIPROCALS[exponent as usize],
+    )
+}
+
+/// Divides `subunits` by a factor from [`factors_of`], multiplying by the reciprocal instead.
+///
+/// `10^-exponent` is inexact, so the bare product misses the quotient in the last bit for many
+/// amounts; one fused correction step on the remainder rounds it exactly like the quotient.
+pub(crate) fn to_major(subunits: f64, (factor, reciprocal): (f64, f64)) -> f64 {
+    let major = subunits * reciprocal;
+    (-major)
+        .mul_add(factor, subunits)
+        .mul_add(reciprocal, major)
+}
+
+/// Number of decimal places of the currency's subunit, honouring `exponent_override`.
+pub(crate) fn get_exponent<T, Cur: FromCurrency>(
+    amount: &amount::MoneyInner<T, Cur>,
+) -> Result<u32, amount::MoneyConversionError<Cur>> {
+    match amount.currency.exponent_override() {
+        Some(exponent) if exponent > MAX_EXPONENT => {
+            Err(amount::MoneyConversionError::InvalidFactor(exponent))
+        }
+        Some(exponent) => Ok(exponent),
+        None => Ok(amount.currency.currency().exponent()),
+    }
 }
@@ -0,0 +1,344 @@
+//! C bindings, enabled with the `ffi` feature.
+//!
+//! Money values are opaque heap handles created by the `amount_conversion_money_*` constructors
+//! and released with [`amount_conversion_money_free`]; strings returned by the library are
+//! released with [`amount_conversion_string_free`]. Every fallible function returns an
+//! [`AmountConversionStatus`] and writes its result through an out pointer.
+//!
+//! The C header lives in `include/amount_conversion.h` and can be regenerated with
+//! `cbindgen --config cbindgen.toml --output include/amount_conversion.h`.
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+
+use crate::amount::{HighestUnit, LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::Currency;
+
+/// Opaque money handle holding an amount in the currency's lowest subunit.
+#[derive(Debug)]
+pub struct AmountConversionMoney(MoneyInner<LowestSubunit, Currency>);
+
+/// Outcome of an FFI call.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AmountConversionStatus {
+    Ok = 0,
+    NullPointer = 1,
+    InvalidUtf8 = 2,
+    UnknownCurrency = 3,
+    Overflow = 4,
+    InvalidDecimalString = 5,
+    TooManyDecimals = 6,
+    CurrencyMismatch = 7,
+    InvalidExchangeRate = 8,
+    SubunitMismatch = 9,
+    MinorUnitsOutOfRange = 10,
+    InvalidRate = 11,
+    NegativeAdjustment = 12,
+    DivisionByZero = 13,
+    InvalidFactor = 14,
+    InvalidDenomination = 15,
+    NoBreakdown = 16,
+    ValueDateMismatch = 17,
+    RedenominationCycle = 18,
+    EmptySum = 19,
+    FractionalAmountNotAllowed = 20,
+    AmountOutOfBounds = 21,
+}
+
+impl From<MoneyConversionError<Currency>> for AmountConversionStatus {
+    fn from(err: MoneyConversionError<Currency>) -> Self {
+        match err {
+            MoneyConversionError::CurrencyNotFoundInSubunitMap(_) => Self::UnknownCurrency,
+            MoneyConversionError::F64ToI32ConversionFailed { .. }
+            | MoneyConversionError::I128ToI32ConversionFailed { .. }
+            | MoneyConversionError::AmountOverflow => Self::Overflow,
+            MoneyConversionError::InvalidDecimalString => Self::InvalidDecimalString,
+            MoneyConversionError::TooManyDecimals(_) => Self::TooManyDecimals,
+            MoneyConversionError::CurrencyMismatch(..) => Self::CurrencyMismatch,
+            MoneyConversionError::InvalidExchangeRate => Self::InvalidExchangeRate,
+            MoneyConversionError::SubunitMismatch(..) => Self::SubunitMismatch,
+            MoneyConversionError::MinorUnitsOutOfRange(_) => Self::MinorUnitsOutOfRange,
+            MoneyConversionError::InvalidRate(_) => Self::InvalidRate,
+            MoneyConversionError::NegativeAdjustment => Self::NegativeAdjustment,
+            MoneyConversionError::DivisionByZero => Self::DivisionByZero,
+            MoneyConversionError::InvalidFactor(_) => Self::InvalidFactor,
+            MoneyConversionError::InvalidDenomination(_) => Self::InvalidDenomination,
+            MoneyConversionError::NoBreakdown => Self::NoBreakdown,
+            MoneyConversionError::ValueDateMismatch => Self::ValueDateMismatch,
+            MoneyConversionError::RedenominationCycle(_) => Self::RedenominationCycle,
+            MoneyConversionError::EmptySum => Self::EmptySum,
+            MoneyConversionError::FractionalAmountNotAllowed(_) => Self::FractionalAmountNotAllowed,
+            MoneyConversionError::AmountOutOfBounds { .. } => Self::AmountOutOfBounds,
+        }
+    }
+}
+
+unsafe fn read_str<'a>(s: *const c_char) -> Result<&'a str, AmountConversionStatus> {
+    if s.is_null() {
+        return Err(AmountConversionStatus::NullPointer);
+    }
+    CStr::from_ptr(s)
+        .to_str()
+        .map_err(|_| AmountConversionStatus::InvalidUtf8)
+}
+
+unsafe fn read_currency(code: *const c_char) -> Result<Currency, AmountConversionStatus> {
+    read_str(code)?
+        .parse()
+        .map_err(|_| AmountConversionStatus::UnknownCurrency)
+}
+
+unsafe fn write_out<T>(
+    out: *mut T,
+    value: Result<T, AmountConversionStatus>,
+) -> AmountConversionStatus {
+    if out.is_null() {
+        return AmountConversionStatus::NullPointer;
+    }
+    match value {
+        Ok(value) => {
+            out.write(value);
+            AmountConversionStatus::Ok
+        }
+        Err(status) => status,
+    }
+}
+
+unsafe fn write_money(
+    out: *mut *mut AmountConversionMoney,
+    money: Result<MoneyInner<LowestSubunit, Currency>, AmountConversionStatus>,
+) -> AmountConversionStatus {
+    // checked before boxing, which would leak the money
+    if out.is_null() {
+        return AmountConversionStatus::NullPointer;
+    }
+    write_out(
+        out,
+        money.map(|money| Box::into_raw(Box::new(AmountConversionMoney(money)))),
+    )
+}
+
+/// Creates a money handle from an amount in subunits and an ISO 4217 code like `"USD"`.
+///
+/// # Safety
+///
+/// `currency` must be null or a valid NUL-terminated string, `out` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn amount_conversion_money_new(
+    amount: i32,
+    currency: *const c_char,
+    out: *mut *mut AmountConversionMoney,
+) -> AmountConversionStatus {
+    let money = read_currency(currency)
+        .map(|currency| MoneyInner::<LowestSubunit, _>::new(amount, &currency));
+    write_money(out, money)
+}
+
+/// Creates a money handle by converting a major unit amount, e.g. `10.5` USD, into subunits.
+///
+/// # Safety
+///
+/// `currency` must be null or a valid NUL-terminated string, `out` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn amount_conversion_money_from_major(
+    amount: f64,
+    currency: *const c_char,
+    out: *mut *mut AmountConversionMoney,
+) -> AmountConversionStatus {
+    let money = read_currency(currency)
+        .and_then(|currency| Ok(MoneyInner::<HighestUnit, _>::new(amount, &currency).convert()?));
+    write_money(out, money)
+}
+
+/// Creates a money handle by parsing a major unit decimal string like `"10.50"` exactly.
+///
+/// # Safety
+///
+/// `amount` and `currency` must be null or valid NUL-terminated strings, `out` must be null or
+/// writable.
+#[no_mangle]
+pub unsafe extern "C" fn amount_conversion_money_parse(
+    amount: *const c_char,
+    currency: *const c_char,
+    out: *mut *mut AmountConversionMoney,
+) -> AmountConversionStatus {
+    let money = read_currency(currency)
+        .and_then(|currency| Ok(MoneyInner::from_str_exact(read_str(amount)?, &currency)?));
+    write_money(out, money)
+}
+
+/// Writes the amount in subunits to `out`.
+///
+/// # Safety
+///
+/// `money` must be null or a handle returned by this library, `out` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn amount_conversion_money_amount(
+    money: *const AmountConversionMoney,
+    out: *mut i32,
+) -> AmountConversionStatus {
+    let amount = money
+        .as_ref()
+        .map(|money| money.0.amount)
+        .ok_or(AmountConversionStatus::NullPointer);
+    write_out(out, amount)
+}
+
+/// Writes the amount converted to major units to `out`.
+///
+/// # Safety
+///
+/// `money` must be null or a handle returned by this library, `out` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn amount_conversion_money_to_major(
+    money: *const AmountConversionMoney,
+    out: *mut f64,
+) -> AmountConversionStatus {
+    let amount = money
+        .as_ref()
+        .ok_or(AmountConversionStatus::NullPointer)
+        .and_then(|money| Ok(money.0.convert()?.amount()));
+    write_out(out, amount)
+}
+
+/// Writes the amount in major units with exactly the currency's decimals, e.g. `"10.50"`.
+///
+/// The string must be released with [`amount_conversion_string_free`].
+///
+/// # Safety
+///
+/// `money` must be null or a handle returned by this library, `out` must be null or writable.
+#[no_mangle]
+pub unsafe extern "C" fn amount_conversion_money_format(
+    money: *const AmountConversionMoney,
+    out: *mut *mut c_char,
+) -> AmountConversionStatus {
+    let formatted = money
+        .as_ref()
+        .ok_or(AmountConversionStatus::NullPointer)
+        .and_then(|money| Ok(money.0.format_decimal()?))
+        .map(|formatted| {
+            CString::new(formatted)
+                .map(CString::into_raw)
+                .unwrap_or(ptr::null_mut())
+        });
+    write_out(out, formatted)
+}
+
+/// Releases a money handle. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `money` must be null or a handle returned by this library that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn amount_conversion_money_free(money: *mut AmountConversionMoney) {
+    if !money.is_null() {
+        drop(Box::from_raw(money));
+    }
+}
+
+/// Releases a string returned by this library. Passing null is a no-op.
+///
+/// # Safety
+///
+/// `s` must be null or a string returned by this library that was not freed yet.
+#[no_mangle]
+pub unsafe extern "C" fn amount_conversion_string_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_convert_format_free() {
+        let usd = CString::new("USD").unwrap();
+        unsafe {
+            let mut money = ptr::null_mut();
+            let status = amount_conversion_money_new(1050, usd.as_ptr(), &mut money);
+            assert_eq!(status, AmountConversionStatus::Ok);
+
+            let mut major = 0.0;
+            amount_conversion_money_to_major(money, &mut major);
+            assert_eq!(major, 10.5);
+
+            let mut formatted = ptr::null_mut();
+            amount_conversion_money_format(money, &mut formatted);
+            assert_eq!(CStr::from_ptr(formatted).to_str(), Ok("10.50"));
+
+            amount_conversion_string_free(formatted);
+            amount_conversion_money_free(money);
+        }
+    }
+
+    #[test]
+    fn parse_and_from_major() {
+        let (bhd, jpy) = (CString::new("BHD").unwrap(), CString::new("JPY").unwrap());
+        let amount_str = CString::new("12.345").unwrap();
+        unsafe {
+            let mut money = ptr::null_mut();
+            let mut amount = 0;
+            amount_conversion_money_parse(amount_str.as_ptr(), bhd.as_ptr(), &mut money);
+            amount_conversion_money_amount(money, &mut amount);
+            assert_eq!(amount, 12345);
+            amount_conversion_money_free(money);
+
+            amount_conversion_money_from_major(12.0, jpy.as_ptr(), &mut money);
+            amount_conversion_money_amount(money, &mut amount);
+            assert_eq!(amount, 12);
+            amount_conversion_money_free(money);
+        }
+    }
+
+    #[test]
+    fn errors() {
+        let (usd, jpy) = (CString::new("USD").unwrap(), CString::new("JPY").unwrap());
+        let (unknown, amount_str) = (CString::new("XXX").unwrap(), CString::new("1.5").unwrap());
+        unsafe {
+            let mut money = ptr::null_mut();
+            assert_eq!(
+                amount_conversion_money_new(1, unknown.as_ptr(), &mut money),
+                AmountConversionStatus::UnknownCurrency
+            );
+            assert_eq!(
+                amount_conversion_money_new(1, ptr::null(), &mut money),
+                AmountConversionStatus::NullPointer
+            );
+            assert_eq!(
+                amount_conversion_money_parse(amount_str.as_ptr(), jpy.as_ptr(), &mut money),
+                AmountConversionStatus::TooManyDecimals
+            );
+            assert_eq!(
+                amount_conversion_money_from_major(f64::MAX, usd.as_ptr(), &mut money),
+                AmountConversionStatus::Overflow
+            );
+            assert!(money.is_null());
+            assert_eq!(
+                amount_conversion_money_new(1, usd.as_ptr(), ptr::null_mut()),
+                AmountConversionStatus::NullPointer
+            );
+        }
+    }
+
+    #[test]
+    fn header_is_current() {
+        let root = env!("CARGO_MANIFEST_DIR");
+        let config = cbindgen::Config::from_file(format!("{root}/cbindgen.toml")).unwrap();
+        let mut header = Vec::new();
+        cbindgen::Builder::new()
+            .with_crate(root)
+            .with_config(config)
+            .generate()
+            .unwrap()
+            .write(&mut header);
+        assert_eq!(
+            String::from_utf8(header).unwrap(),
+            include_str!("../include/amount_conversion.h"),
+            "include/amount_conversion.h is stale, regenerate it with \
+             `cbindgen --config cbindgen.toml --output include/amount_conversion.h`"
+        );
+    }
+}
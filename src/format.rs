@@ -0,0 +1,239 @@
+use crate::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::backend::AmountBackend;
+use crate::decimal::format_subunits;
+use crate::factor::{get_exponent, get_factor, symbol_of, FromCurrency};
+use crate::rounding::{div_round, RoundingMode};
+
+/// The named powers of ten used by [`MoneyInner::format_compact`].
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Scale {
+    /// Thousands, millions, billions and trillions: `K`, `M`, `B`, `T`.
+    #[default]
+    Western,
+    /// Thousands, lakhs and crores: `K`, `L`, `Cr`.
+    Indian,
+}
+
+impl Scale {
+    /// Units in major units, largest first.
+    fn units(self) -> &'static [(i128, &'static str)] {
+        match self {
+            Self::Western => &[
+                (1_000_000_000_000, "T"),
+                (1_000_000_000, "B"),
+                (1_000_000, "M"),
+                (1_000, "K"),
+            ],
+            Self::Indian => &[(10_000_000, "Cr"), (100_000, "L"), (1_000, "K")],
+        }
+    }
+}
+
+/// The sizes of the integer digit groups, counted from the decimal point, as used by
+/// [`LocaleConventions`](crate::locale::LocaleConventions).
+///
+/// `first` is the size of the group next to the decimal point and `rest` the size of every
+/// group before it; a size of zero stops grouping.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DigitGrouping {
+    first: usize,
+    rest: usize,
+}
+
+impl DigitGrouping {
+    /// Groups of three, as in `1,234,567`.
+    pub const THOUSANDS: Self = Self::new(3, 3);
+    /// Three then two, as in `12,34,567`.
+    pub const INDIAN: Self = Self::new(3, 2);
+    /// No separators, as in `1234567`.
+    pub const NONE: Self = Self::new(0, 0);
+
+    pub const fn new(first: usize, rest: usize) -> Self {
+        Self { first, rest }
+    }
+
+    pub fn first(&self) -> usize {
+        self.first
+    }
+
+    pub fn rest(&self) -> usize {
+        self.rest
+    }
+
+    /// Joins the digit groups of `digits` with `separator`.
+    pub(crate) fn apply(&self, digits: &str, separator: char) -> String {
+        let mut groups = Vec::new();
+        let mut rest = digits;
+        let mut size = self.first;
+        while !rest.is_empty() {
+            if size == 0 {
+                groups.push(rest);
+                break;
+            }
+            let (head, tail) = rest.split_at(rest.len().saturating_sub(size));
+            groups.push(tail);
+            rest = head;
+            size = self.rest;
+        }
+        groups.reverse();
+        groups.join(separator.encode_utf8(&mut [0; 4]))
+    }
+}
+
+/// How [`MoneyInner::format_spreadsheet`] marks the cell type for spreadsheet imports.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum CellStyle {
+    /// A bare number such as `1234567.80`, imported as a numeric cell.
+    #[default]
+    Number,
+    /// A number with a leading apostrophe such as `'1234567.80`, imported as text so no digits
+    /// or trailing zeros are lost.
+    Text,
+}
+
+/// How negative amounts are marked, shown here for `-10.50 USD` with a prefix symbol.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum NegativeStyle {
+    /// `-$10.50`
+    #[default]
+    LeadingMinus,
+    /// `($10.50)`, as in accounting exports.
+    Parentheses,
+    /// `$10.50-`, the minus directly after the number.
+    TrailingMinus,
+    /// `$-10.50`, the minus directly after the symbol.
+    SignAfterSymbol,
+}
+
+impl<B: AmountBackend, Cur: FromCurrency> MoneyInner<B, Cur> {
+    /// Renders the amount in major units for CSV or TSV exports opened in Excel or Sheets.
+    ///
+    /// The output always has exactly the currency's number of decimals and is built from the
+    /// integer subunits, so it never uses scientific notation or grouping separators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, JPY, USD};
+    /// use amount_conversion::format::CellStyle;
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// assert_eq!(Money::new(2_000_000_000, &USD).format_spreadsheet(CellStyle::Number)?, "20000000.00");
+    /// assert_eq!(Money::new(-1_050, &USD).format_spreadsheet(CellStyle::Text)?, "'-10.50");
+    /// assert_eq!(Money::new(1_050, &JPY).format_spreadsheet(CellStyle::Number)?, "1050");
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn format_spreadsheet(
+        &self,
+        style: CellStyle,
+    ) -> Result<String, MoneyConversionError<Cur>> {
+        let number = format_subunits(self.amount.to_i128(), get_exponent(self)?);
+        Ok(match style {
+            CellStyle::Number => number,
+            CellStyle::Text => format!("'{number}"),
+        })
+    }
+}
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// Renders the amount with at most one decimal in the largest unit of `scale` it reaches,
+    /// e.g. `"$3.4M"` or `"₹1.2L"`, for dashboards showing large aggregates.
+    ///
+    /// Amounts that round to under a thousand major units are rendered in full, e.g. `"$949.49"`.
+    /// Currencies without a distinctive symbol are prefixed with their code, e.g. `"CHF 1.2M"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, INR, USD};
+    /// use amount_conversion::format::Scale;
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// assert_eq!(Money::new(340_000_000, &USD).format_compact(Scale::Western).unwrap(), "$3.4M");
+    /// assert_eq!(Money::new(12_000_000, &INR).format_compact(Scale::Indian).unwrap(), "₹1.2L");
+    /// ```
+    pub fn format_compact(&self, scale: Scale) -> Result<String, MoneyConversionError<Cur>> {
+        let prefix = match symbol_of(&self.currency) {
+            Some(symbol) => symbol.to_string(),
+            None => format!("{} ", self.currency.currency_code()),
+        };
+        let sign = if self.amount < 0 { "-" } else { "" };
+        let amount = i128::from(self.amount).abs();
+        let factor = get_factor(self)? as i128;
+
+        for &(unit, suffix) in scale.units() {
+            let tenths = div_round(amount * 10, unit * factor, RoundingMode::HalfEven);
+            if tenths >= 10 {
+                let fraction = match tenths % 10 {
+                    0 => String::new(),
+                    digit => format!(".{digit}"),
+                };
+                return Ok(format!("{sign}{prefix}{}{fraction}{suffix}", tenths / 10));
+            }
+        }
+        let full = format_subunits(amount, get_exponent(self)?);
+        Ok(format!("{sign}{prefix}{full}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+    use crate::factor::CurrencyCode;
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    fn compact(amount: i32, currency: Currency, scale: Scale) -> String {
+        Money::new(amount, &currency).format_compact(scale).unwrap()
+    }
+
+    #[test]
+    fn spreadsheet() {
+        let cell = |amount, currency, style| {
+            Money::new(amount, &currency)
+                .format_spreadsheet(style)
+                .unwrap()
+        };
+        assert_eq!(cell(i32::MAX, USD, CellStyle::Number), "21474836.47");
+        assert_eq!(cell(i32::MIN, USD, CellStyle::Number), "-21474836.48");
+        assert_eq!(cell(100, EUR, CellStyle::Number), "1.00");
+        assert_eq!(cell(5, KWD, CellStyle::Text), "'0.005");
+        assert_eq!(cell(0, JPY, CellStyle::Text), "'0");
+    }
+
+    #[test]
+    fn western() {
+        assert_eq!(compact(94_949, USD, Scale::Western), "$949.49");
+        assert_eq!(compact(100_000, USD, Scale::Western), "$1K");
+        assert_eq!(compact(123_456, USD, Scale::Western), "$1.2K");
+        assert_eq!(compact(-340_000_000, USD, Scale::Western), "-$3.4M");
+        assert_eq!(compact(2_100_000_000, JPY, Scale::Western), "¥2.1B");
+        assert_eq!(compact(120_000_000, CHF, Scale::Western), "CHF 1.2M");
+    }
+
+    #[test]
+    fn rounds_up_into_next_unit() {
+        assert_eq!(compact(99_999_999, USD, Scale::Western), "$1M");
+        assert_eq!(compact(99_960, EUR, Scale::Western), "€1K");
+    }
+
+    #[test]
+    fn indian() {
+        assert_eq!(compact(12_000_000, INR, Scale::Indian), "₹1.2L");
+        assert_eq!(compact(9_999_000, INR, Scale::Indian), "₹1L");
+        assert_eq!(compact(1_500_000_000, INR, Scale::Indian), "₹1.5Cr");
+        assert_eq!(compact(4_550_000, INR, Scale::Indian), "₹45.5K");
+    }
+
+    #[test]
+    fn private_codes() {
+        let points = CurrencyCode::register("FMP", USD, 0).unwrap();
+        let money = MoneyInner::<LowestSubunit, _>::new(1_300, &points);
+        assert_eq!(money.format_compact(Scale::Western).unwrap(), "FMP 1.3K");
+    }
+}
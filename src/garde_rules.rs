@@ -0,0 +1,102 @@
+//! Rules for the `garde` crate, enabled with the `garde` feature.
+//!
+//! The same checks as the `validator` helpers, shaped for `#[garde(custom(..))]`. They work with
+//! any validation context.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{LowestSubunit, MoneyInner};
+//! use amount_conversion::factor::Currency::{self, EUR, INR, USD};
+//! use amount_conversion::garde_rules::{currency_allowed, money_positive, money_range};
+//! use garde::Validate;
+//!
+//! type Money = MoneyInner<LowestSubunit, Currency>;
+//!
+//! #[derive(Validate)]
+//! struct Payment {
+//!     #[garde(custom(money_positive), custom(money_range(100, 1_000_000)))]
+//!     #[garde(custom(currency_allowed(&[USD, EUR])))]
+//!     amount: Money,
+//! }
+//!
+//! assert!(Payment { amount: Money::new(5_000, &USD) }.validate().is_ok());
+//! assert!(Payment { amount: Money::new(50, &USD) }.validate().is_err());
+//! assert!(Payment { amount: Money::new(5_000, &INR) }.validate().is_err());
+//! ```
+use crate::amount::{LowestSubunit, MoneyInner};
+use crate::factor::FromCurrency;
+
+/// Checks that the amount is greater than zero.
+pub fn money_positive<Cur: FromCurrency, Ctx>(
+    money: &MoneyInner<LowestSubunit, Cur>,
+    _context: &Ctx,
+) -> garde::Result {
+    if money.amount > 0 {
+        return Ok(());
+    }
+    Err(garde::Error::new(format!(
+        "amount {} must be greater than zero",
+        money.amount
+    )))
+}
+
+/// Checks that the amount is within `min..=max` subunits.
+pub fn money_range<Cur: FromCurrency, Ctx>(
+    min: LowestSubunit,
+    max: LowestSubunit,
+) -> impl FnOnce(&MoneyInner<LowestSubunit, Cur>, &Ctx) -> garde::Result {
+    move |money, _| {
+        if (min..=max).contains(&money.amount) {
+            return Ok(());
+        }
+        Err(garde::Error::new(format!(
+            "amount {} must be between {min} and {max}",
+            money.amount
+        )))
+    }
+}
+
+/// Checks that the currency is one of `allowed`.
+pub fn currency_allowed<Cur: FromCurrency, Ctx>(
+    allowed: &[Cur],
+) -> impl FnOnce(&MoneyInner<LowestSubunit, Cur>, &Ctx) -> garde::Result + '_ {
+    move |money, _| {
+        if allowed.contains(&money.currency) {
+            return Ok(());
+        }
+        Err(garde::Error::new(format!(
+            "currency {} is not allowed",
+            money.currency.currency_code()
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn positive() {
+        assert!(money_positive(&Money::new(1, &USD), &()).is_ok());
+        let error = money_positive(&Money::new(-1, &USD), &()).unwrap_err();
+        assert_eq!(error.message(), "amount -1 must be greater than zero");
+    }
+
+    #[test]
+    fn range() {
+        assert!(money_range(0, 10)(&Money::new(10, &USD), &()).is_ok());
+        let error = money_range(0, 10)(&Money::new(11, &USD), &()).unwrap_err();
+        assert_eq!(error.message(), "amount 11 must be between 0 and 10");
+    }
+
+    #[test]
+    fn allowed() {
+        assert!(currency_allowed(&[USD, INR])(&Money::new(1, &INR), &()).is_ok());
+        let error = currency_allowed(&[USD, INR])(&Money::new(1, &EUR), &()).unwrap_err();
+        assert_eq!(error.message(), "currency EUR is not allowed");
+    }
+}
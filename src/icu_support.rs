@@ -0,0 +1,177 @@
+//! Locale-aware formatting backed by ICU4X and its compiled CLDR data, enabled with the `icu`
+//! feature.
+//!
+//! Unlike [`MoneyInner::format_locale`], which covers the common conventions of a few dozen
+//! languages, the decimal patterns, grouping, numbering systems and currency symbols and
+//! display names come from CLDR for every locale it has data for.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{LowestSubunit, MoneyInner};
+//! use amount_conversion::factor::Currency;
+//! use amount_conversion::icu_support::{CurrencyDisplay, IcuFormatter};
+//!
+//! let money = MoneyInner::<LowestSubunit, _>::new(123_456_780, &Currency::INR);
+//! let formatter: IcuFormatter = "en-IN".parse().unwrap();
+//! assert_eq!(money.format_icu(&formatter).unwrap(), "₹12,34,567.80");
+//!
+//! let formatter = formatter.with_display(CurrencyDisplay::Name);
+//! assert_eq!(money.format_icu(&formatter).unwrap(), "12,34,567.80 Indian rupees");
+//! ```
+use std::fmt;
+use std::str::FromStr;
+
+use fixed_decimal::Decimal;
+use icu_experimental::dimension::currency::formatter::CurrencyFormatter;
+use icu_experimental::dimension::currency::CurrencyType;
+use icu_locale_core::{Locale, ParseError};
+use icu_provider::DataError;
+
+use crate::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::{get_exponent, FromCurrency};
+
+/// How [`MoneyInner::format_icu`] shows the currency.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum CurrencyDisplay {
+    /// The locale's symbol, e.g. `€`, or the code where the locale has none.
+    #[default]
+    Symbol,
+    /// The ISO code, e.g. `EUR`.
+    Code,
+    /// The locale's plural-aware display name, e.g. `euros`.
+    Name,
+}
+
+/// A locale and currency display for [`MoneyInner::format_icu`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct IcuFormatter {
+    locale: Locale,
+    display: CurrencyDisplay,
+}
+
+impl IcuFormatter {
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            locale,
+            display: CurrencyDisplay::default(),
+        }
+    }
+
+    pub fn with_display(self, display: CurrencyDisplay) -> Self {
+        Self { display, ..self }
+    }
+
+    pub fn locale(&self) -> &Locale {
+        &self.locale
+    }
+
+    pub fn display(&self) -> CurrencyDisplay {
+        self.display
+    }
+}
+
+/// Parses a BCP 47 locale such as `"de-CH"` or `"th-u-nu-thai"`.
+impl FromStr for IcuFormatter {
+    type Err = ParseError;
+
+    fn from_str(locale: &str) -> Result<Self, Self::Err> {
+        locale.parse().map(Self::new)
+    }
+}
+
+/// Why [`MoneyInner::format_icu`] failed.
+#[derive(Debug)]
+pub enum IcuError<Cur> {
+    /// The compiled data has no formatting data for the locale and currency.
+    Data(DataError),
+    /// The currency is not in the subunit map.
+    Money(MoneyConversionError<Cur>),
+}
+
+impl<Cur> From<DataError> for IcuError<Cur> {
+    fn from(error: DataError) -> Self {
+        Self::Data(error)
+    }
+}
+
+impl<Cur> From<MoneyConversionError<Cur>> for IcuError<Cur> {
+    fn from(error: MoneyConversionError<Cur>) -> Self {
+        Self::Money(error)
+    }
+}
+
+impl<Cur: fmt::Debug> fmt::Display for IcuError<Cur> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Data(error) => write!(f, "{error}"),
+            Self::Money(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl<Cur: fmt::Debug> std::error::Error for IcuError<Cur> {}
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// Renders the amount with the CLDR conventions of the formatter's locale, e.g.
+    /// `"CHF 1'234.50"` for `de-CH` or `"1 234,56 €"` for `fr-FR`, spaces being the no-break
+    /// spaces CLDR specifies.
+    pub fn format_icu(&self, formatter: &IcuFormatter) -> Result<String, IcuError<Cur>> {
+        let code = CurrencyType::try_from_str(self.currency.currency_code())
+            .expect("currency codes are three ASCII letters");
+        let mut value = Decimal::from(self.amount);
+        value.multiply_pow10(-i16::try_from(get_exponent(self)?).expect("exponent fits an i16"));
+
+        let prefs = (&formatter.locale).into();
+        let currency_formatter = match formatter.display {
+            CurrencyDisplay::Symbol => {
+                CurrencyFormatter::try_new_symbol(prefs, code, Default::default())
+                    .or_else(|_| CurrencyFormatter::try_new_code(prefs, code, Default::default()))?
+            }
+            CurrencyDisplay::Code => {
+                CurrencyFormatter::try_new_code(prefs, code, Default::default())?
+            }
+            CurrencyDisplay::Name => CurrencyFormatter::try_new_name(prefs, code)?,
+        };
+        let formatted = currency_formatter.format_fixed_decimal(&value).to_string();
+        Ok(formatted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    fn format(amount: i32, currency: Currency, locale: &str, display: CurrencyDisplay) -> String {
+        let formatter = locale.parse::<IcuFormatter>().unwrap();
+        Money::new(amount, &currency)
+            .format_icu(&formatter.with_display(display))
+            .unwrap()
+    }
+
+    #[test]
+    fn formats_with_cldr_data() {
+        use CurrencyDisplay::*;
+        assert_eq!(format(-123_450, EUR, "de-DE", Symbol), "-1.234,50\u{a0}€");
+        assert_eq!(
+            format(123_456, EUR, "fr-FR", Code),
+            "1\u{202f}234,56\u{a0}EUR"
+        );
+        assert_eq!(format(1_000, JPY, "ja-JP", Name), "1,000 円");
+        assert_eq!(
+            format(100_000, THB, "th-u-nu-thai", Code),
+            "THB\u{a0}๑,๐๐๐.๐๐"
+        );
+        // no symbol data for these, so the code stands in
+        assert_eq!(format(123_450, CHF, "de-CH", Symbol), "CHF\u{a0}1'234.50");
+        assert_eq!(format(5, KWD, "en-US", Symbol), "KWD\u{a0}0.005");
+    }
+
+    #[test]
+    fn rejects_invalid_locales() {
+        assert!("not a locale".parse::<IcuFormatter>().is_err());
+    }
+}
@@ -0,0 +1,273 @@
+use std::num::NonZeroU32;
+
+use crate::amount::{i128_to_i32, LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::FromCurrency;
+use crate::rate::PeriodicRate;
+use crate::rounding::RoundingMode;
+
+/// One period of an amortization schedule.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Installment<Cur: FromCurrency> {
+    /// The period number, starting at 1.
+    pub period: u32,
+    /// The total paid this period, `principal + interest`.
+    pub payment: MoneyInner<LowestSubunit, Cur>,
+    /// The part of the payment that repays principal.
+    pub principal: MoneyInner<LowestSubunit, Cur>,
+    /// The interest charged on the opening balance this period.
+    pub interest: MoneyInner<LowestSubunit, Cur>,
+    /// The principal still outstanding after this period.
+    pub balance: MoneyInner<LowestSubunit, Cur>,
+}
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// Simple interest on the amount at `rate` per period over `periods` periods.
+    ///
+    /// The interest is computed exactly and rounded once with `rounding`; add it to the principal
+    /// for the final balance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    /// use amount_conversion::rounding::RoundingMode;
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// // 1.5% a month on 1000.00 USD for a year
+    /// let interest = Money::new(100_000, &USD).simple_interest(150, 12, RoundingMode::HalfEven);
+    /// assert_eq!(interest, Ok(Money::new(18_000, &USD)));
+    /// ```
+    pub fn simple_interest(
+        self,
+        rate: impl Into<PeriodicRate>,
+        periods: u32,
+        rounding: RoundingMode,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        let principal = i128::from(self.amount) * i128::from(periods);
+        let interest = rate.into().of(principal, rounding);
+        Ok(Self::new(
+            i128_to_i32(interest, self.currency)?,
+            &self.currency,
+        ))
+    }
+
+    /// Compound interest on the amount at `rate` per period over `periods` periods.
+    ///
+    /// Each period's interest is rounded to whole subunits with `rounding` before it is added to
+    /// the balance, the way a ledger posting interest every period would. Returns the total
+    /// interest accrued; add it to the principal for the final balance.
+    pub fn compound_interest(
+        self,
+        rate: impl Into<PeriodicRate>,
+        periods: u32,
+        rounding: RoundingMode,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        let rate = rate.into();
+        let mut balance = self.amount;
+        for _ in 0..periods {
+            let interest = i128_to_i32(rate.of(i128::from(balance), rounding), self.currency)?;
+            balance = balance
+                .checked_add(interest)
+                .ok_or(MoneyConversionError::AmountOverflow)?;
+        }
+        let interest = i128::from(balance) - i128::from(self.amount);
+        Ok(Self::new(
+            i128_to_i32(interest, self.currency)?,
+            &self.currency,
+        ))
+    }
+
+    /// A level-payment repayment schedule for the amount as principal, at `rate` per period.
+    ///
+    /// Each period's interest is rounded with `rounding`. The level payment is rounded to whole
+    /// subunits, so the final installment absorbs the difference: the principal parts always sum
+    /// to exactly the original amount and the final balance is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroU32;
+    ///
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    /// use amount_conversion::rounding::RoundingMode;
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// let term = NonZeroU32::new(12).unwrap();
+    /// let schedule = Money::new(100_000, &USD).amortize(100, term, RoundingMode::HalfEven)?;
+    /// assert_eq!(schedule.len(), 12);
+    /// assert_eq!(schedule[0].payment, Money::new(8_885, &USD));
+    /// assert_eq!(schedule[11].balance, Money::new(0, &USD));
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn amortize(
+        self,
+        rate: impl Into<PeriodicRate>,
+        periods: NonZeroU32,
+        rounding: RoundingMode,
+    ) -> Result<Vec<Installment<Cur>>, MoneyConversionError<Cur>> {
+        let rate = rate.into();
+        if rate.is_negative() {
+            return Err(MoneyConversionError::InvalidRate(
+                rate.to_basis_points(RoundingMode::Floor).get(),
+            ));
+        }
+        let payment = level_payment(self.amount, rate, periods.get());
+        let money = |amount: i128| {
+            Ok(Self::new(
+                i128_to_i32(amount, self.currency)?,
+                &self.currency,
+            ))
+        };
+
+        let mut balance = i128::from(self.amount);
+        let mut schedule = Vec::with_capacity(periods.get() as usize);
+        for period in 1..=periods.get() {
+            let interest = rate.of(balance, rounding);
+            let mut principal = payment - interest;
+            if period == periods.get() || principal.abs() > balance.abs() {
+                principal = balance;
+            }
+            balance -= principal;
+            schedule.push(Installment {
+                period,
+                payment: money(principal + interest)?,
+                principal: money(principal)?,
+                interest: money(interest)?,
+                balance: money(balance)?,
+            });
+        }
+        Ok(schedule)
+    }
+}
+
+/// The annuity payment `P * r / (1 - (1 + r)^-n)`, rounded to whole subunits.
+fn level_payment(principal: LowestSubunit, rate: PeriodicRate, periods: u32) -> i128 {
+    let principal = f64::from(principal);
+    let periods = f64::from(periods);
+    let rate = rate.as_f64();
+    let payment = if rate == 0.0 {
+        principal / periods
+    } else {
+        principal * rate / (1.0 - (1.0 + rate).powf(-periods))
+    };
+    payment.round() as i128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+    use crate::percent::BasisPoints;
+    use crate::rate::AnnualRate;
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn simple_interest() -> Result<(), MoneyConversionError<Currency>> {
+        let principal = Money::new(100_000, &INR);
+        assert_eq!(
+            principal.simple_interest(BasisPoints::from_percent(10), 3, RoundingMode::HalfEven)?,
+            Money::new(30_000, &INR)
+        );
+        assert_eq!(
+            principal.simple_interest(500, 0, RoundingMode::HalfEven)?,
+            Money::new(0, &INR)
+        );
+        // 10% a year for 3 months is 2.5%
+        let monthly = AnnualRate::from_percent(10).per_period(NonZeroU32::new(12).unwrap());
+        assert_eq!(
+            principal.simple_interest(monthly, 3, RoundingMode::HalfEven)?,
+            Money::new(2_500, &INR)
+        );
+        // 0.125 subunits in total, rounded only once
+        assert_eq!(
+            Money::new(5, &INR).simple_interest(125, 2, RoundingMode::Ceil)?,
+            Money::new(1, &INR)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn compound_interest() -> Result<(), MoneyConversionError<Currency>> {
+        let principal = Money::new(100_000, &USD);
+        // 10000 + 11000 + 12100
+        assert_eq!(
+            principal.compound_interest(
+                BasisPoints::from_percent(10),
+                3,
+                RoundingMode::HalfEven
+            )?,
+            Money::new(33_100, &USD)
+        );
+        // each period rounds 0.5 subunits separately
+        assert_eq!(
+            Money::new(50, &USD).compound_interest(100, 2, RoundingMode::Ceil)?,
+            Money::new(2, &USD)
+        );
+        assert_eq!(
+            Money::new(50, &USD).compound_interest(100, 2, RoundingMode::Floor)?,
+            Money::new(0, &USD)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn amortize_conserves_principal() -> Result<(), MoneyConversionError<Currency>> {
+        for (principal, rate, periods) in
+            [(100_000, 100, 12), (5, 0, 8), (1_000, 0, 3), (-999, 250, 7)]
+        {
+            let principal = Money::new(principal, &USD);
+            let periods = NonZeroU32::new(periods).unwrap();
+            let schedule = principal.amortize(rate, periods, RoundingMode::HalfEven)?;
+            assert_eq!(schedule.len() as u32, periods.get());
+
+            let repaid = schedule
+                .iter()
+                .map(|row| row.principal.amount())
+                .sum::<i32>();
+            assert_eq!(repaid, principal.amount());
+            assert_eq!(schedule.last().unwrap().balance, Money::new(0, &USD));
+            for row in &schedule {
+                assert_eq!(row.principal.checked_add(row.interest)?, row.payment);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn amortize_rows() -> Result<(), MoneyConversionError<Currency>> {
+        let term = NonZeroU32::new(3).unwrap();
+        let schedule = Money::new(1_000, &INR).amortize(0, term, RoundingMode::HalfEven)?;
+        let rows: Vec<_> = schedule
+            .iter()
+            .map(|row| (row.period, row.payment.amount(), row.balance.amount()))
+            .collect();
+        assert_eq!(rows, [(1, 333, 667), (2, 333, 334), (3, 334, 0)]);
+
+        let schedule = Money::new(100_000, &INR).amortize(1_000, term, RoundingMode::HalfEven)?;
+        assert_eq!(schedule[0].interest, Money::new(10_000, &INR));
+        assert_eq!(schedule[0].payment, Money::new(40_211, &INR));
+
+        assert_eq!(
+            Money::new(1_000, &INR).amortize(-1, term, RoundingMode::HalfEven),
+            Err(MoneyConversionError::InvalidRate(-1))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn interest_overflow() {
+        assert_eq!(
+            Money::new(i32::MAX / 2, &USD).compound_interest(
+                BasisPoints::from_percent(100),
+                2,
+                RoundingMode::HalfEven
+            ),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+    }
+}
@@ -0,0 +1,79 @@
+//! `iso_currency` integration, enabled with the `iso-currency` feature.
+//!
+//! `Currency` converts to and from `iso_currency::Currency` by alphabetic code with `TryFrom`.
+//! Each crate knows codes the other does not, e.g. funds such as `BOV` only in `iso_currency`,
+//! so both directions fail with [`UnknownCurrency`] carrying the code.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{LowestSubunit, MoneyInner};
+//! use amount_conversion::factor::Currency;
+//! use amount_conversion::iso_currency_support::UnknownCurrency;
+//!
+//! let chf = Currency::try_from(iso_currency::Currency::CHF)?;
+//! let money = MoneyInner::<LowestSubunit, _>::from_str_exact("12.50", &chf).unwrap();
+//! assert_eq!(money.amount(), 1_250);
+//!
+//! let theirs = iso_currency::Currency::try_from(money.currency())?;
+//! assert_eq!(theirs.name(), "Swiss franc");
+//! assert_eq!(
+//!     Currency::try_from(iso_currency::Currency::BOV),
+//!     Err(UnknownCurrency("BOV"))
+//! );
+//! # Ok::<(), UnknownCurrency>(())
+//! ```
+use std::fmt;
+
+use crate::factor::Currency;
+
+/// A currency code known to only one of the crates.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct UnknownCurrency(pub &'static str);
+
+impl fmt::Display for UnknownCurrency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "currency {} is not supported", self.0)
+    }
+}
+
+impl std::error::Error for UnknownCurrency {}
+
+impl TryFrom<Currency> for iso_currency::Currency {
+    type Error = UnknownCurrency;
+
+    fn try_from(currency: Currency) -> Result<Self, Self::Error> {
+        iso_currency::Currency::from_code(currency.code()).ok_or(UnknownCurrency(currency.code()))
+    }
+}
+
+impl TryFrom<iso_currency::Currency> for Currency {
+    type Error = UnknownCurrency;
+
+    fn try_from(currency: iso_currency::Currency) -> Result<Self, Self::Error> {
+        Currency::try_from_code(currency.code()).ok_or(UnknownCurrency(currency.code()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_by_code() {
+        for currency in [Currency::USD, Currency::KWD, Currency::JPY, Currency::XPF] {
+            let theirs = iso_currency::Currency::try_from(currency).unwrap();
+            assert_eq!(theirs.exponent(), Some(currency.exponent() as u16));
+            assert_eq!(theirs.numeric(), currency.numeric_code());
+            assert_eq!(Currency::try_from(theirs), Ok(currency));
+        }
+        assert_eq!(
+            Currency::try_from(iso_currency::Currency::XAU),
+            Err(UnknownCurrency("XAU"))
+        );
+        assert_eq!(
+            UnknownCurrency("XAU").to_string(),
+            "currency XAU is not supported"
+        );
+    }
+}
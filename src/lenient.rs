@@ -0,0 +1,157 @@
+use crate::amount::{
+    f64_to_i32, to_major_units, to_whole_subunits, HighestUnit, LowestSubunit,
+    MoneyConversionError, MoneyInner,
+};
+use crate::factor::{factor_of, get_exponent, FromCurrency, MAX_EXPONENT};
+use crate::observer::{observe, ConversionKind};
+
+/// The result of a lenient conversion, recording whether a fallback exponent was used.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Lenient<T> {
+    pub value: T,
+    /// The fallback exponent, when the currency's own exponent could not be used.
+    pub fallback_exponent: Option<u32>,
+}
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// Converts to major units like `convert`, but assumes `fallback_exponent` decimals instead of
+    /// failing when the exponent lookup of the currency fails, e.g. an `exponent_override` above
+    /// `MAX_EXPONENT` from a misconfigured code, for pipelines that must not halt on it.
+    ///
+    /// Fails with `InvalidFactor` when the fallback is needed and above `MAX_EXPONENT`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    ///
+    /// let converted = MoneyInner::<LowestSubunit, Currency>::new(1050, &USD).convert_lenient(2)?;
+    /// assert_eq!(converted.value.amount(), 10.5);
+    /// assert_eq!(converted.fallback_exponent, None);
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn convert_lenient(
+        self,
+        fallback_exponent: u32,
+    ) -> Result<Lenient<MoneyInner<HighestUnit, Cur>>, MoneyConversionError<Cur>> {
+        let converted = match get_exponent(&self) {
+            Ok(_) => to_major_units(self).map(exact),
+            Err(_) => fallback_factor(fallback_exponent).map(|factor| Lenient {
+                value: MoneyInner::<HighestUnit, _>::new(
+                    f64::from(self.amount) / factor,
+                    &self.currency,
+                ),
+                fallback_exponent: Some(fallback_exponent),
+            }),
+        };
+        observe(ConversionKind::ToMajorUnits, &self.currency, converted)
+    }
+}
+
+impl<Cur: FromCurrency> MoneyInner<HighestUnit, Cur> {
+    /// Converts to subunits like `convert`, but assumes `fallback_exponent` decimals instead of
+    /// failing when the exponent lookup of the currency fails.
+    ///
+    /// Fails with `InvalidFactor` when the fallback is needed and above `MAX_EXPONENT`.
+    pub fn convert_lenient(
+        self,
+        fallback_exponent: u32,
+    ) -> Result<Lenient<MoneyInner<LowestSubunit, Cur>>, MoneyConversionError<Cur>> {
+        let converted = match get_exponent(&self) {
+            Ok(_) => to_whole_subunits(self).map(exact),
+            Err(_) => fallback_factor(fallback_exponent).and_then(|factor| {
+                Ok(Lenient {
+                    value: MoneyInner::<LowestSubunit, _>::new(
+                        f64_to_i32(self.amount * factor, self.currency)?,
+                        &self.currency,
+                    ),
+                    fallback_exponent: Some(fallback_exponent),
+                })
+            }),
+        };
+        observe(ConversionKind::ToSubunits, &self.currency, converted)
+    }
+}
+
+fn exact<T>(value: T) -> Lenient<T> {
+    Lenient {
+        value,
+        fallback_exponent: None,
+    }
+}
+
+fn fallback_factor<T>(exponent: u32) -> Result<f64, MoneyConversionError<T>> {
+    if exponent > MAX_EXPONENT {
+        return Err(MoneyConversionError::InvalidFactor(exponent));
+    }
+    Ok(factor_of(exponent))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    /// A currency whose exponent override may be unusable.
+    #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+    struct Token(u32);
+
+    impl FromCurrency for Token {
+        fn currency(&self) -> Currency {
+            USD
+        }
+
+        fn exponent_override(&self) -> Option<u32> {
+            Some(self.0)
+        }
+    }
+
+    #[test]
+    fn known_currencies_ignore_fallback() -> Result<(), MoneyConversionError<Currency>> {
+        let major = Money::new(1_234, &KWD).convert_lenient(2)?;
+        assert_eq!(major, exact(MoneyInner::<HighestUnit, _>::new(1.234, &KWD)));
+        let minor = major.value.convert_lenient(MAX_EXPONENT + 1)?;
+        assert_eq!(minor, exact(Money::new(1_234, &KWD)));
+        assert_eq!(
+            MoneyInner::<HighestUnit, _>::new(1e12, &USD).convert_lenient(2),
+            Err(MoneyConversionError::F64ToI32ConversionFailed {
+                value: 1e14,
+                bound: i32::MAX,
+                currency: USD
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unusable_exponents_fall_back() -> Result<(), MoneyConversionError<Token>> {
+        let token = Token(MAX_EXPONENT + 1);
+        let major = MoneyInner::<LowestSubunit, _>::new(1_050, &token).convert_lenient(2)?;
+        assert_eq!(major.value, MoneyInner::<HighestUnit, _>::new(10.5, &token));
+        assert_eq!(major.fallback_exponent, Some(2));
+        let minor = major.value.convert_lenient(2)?;
+        assert_eq!(
+            minor.value,
+            MoneyInner::<LowestSubunit, _>::new(1_050, &token)
+        );
+        assert_eq!(minor.fallback_exponent, Some(2));
+        assert_eq!(
+            MoneyInner::<LowestSubunit, _>::new(1_050, &token).convert_lenient(MAX_EXPONENT + 1),
+            Err(MoneyConversionError::InvalidFactor(MAX_EXPONENT + 1))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn fallback_factors() {
+        assert_eq!(fallback_factor::<()>(0), Ok(1.0));
+        assert_eq!(fallback_factor::<()>(MAX_EXPONENT), Ok(1e18));
+        assert_eq!(
+            fallback_factor::<()>(MAX_EXPONENT + 1),
+            Err(MoneyConversionError::InvalidFactor(19))
+        );
+    }
+}
@@ -1,3 +1,70 @@
+#[cfg(feature = "actix")]
+pub mod actix_support;
+pub mod adjustment;
+pub mod allocation;
 /// Amount conversion from lower subunit to higher unit and vice-versa
 pub mod amount;
+#[cfg(feature = "axum")]
+pub mod axum_support;
+pub mod backend;
+pub mod bag;
+pub mod builder;
+#[cfg(feature = "simd")]
+pub mod bulk;
+pub mod cash;
+#[cfg(feature = "cli")]
+#[doc(hidden)]
+pub mod cli;
+pub mod conversion;
+pub mod country;
+#[cfg(feature = "csv")]
+pub mod csv_support;
+#[cfg(feature = "chrono")]
+pub mod dated;
+mod decimal;
+pub mod exchange;
 pub mod factor;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod format;
+#[cfg(feature = "garde")]
+pub mod garde_rules;
+#[cfg(feature = "icu")]
+pub mod icu_support;
+pub mod interest;
+#[cfg(feature = "iso-currency")]
+pub mod iso_currency_support;
+pub mod lenient;
+pub mod limits;
+pub mod locale;
+mod macros;
+#[cfg(feature = "num-traits")]
+pub mod num_traits_support;
+pub mod observer;
+pub mod percent;
+pub mod policy;
+pub mod problem;
+pub mod provider;
+#[cfg(feature = "python")]
+pub mod python;
+pub mod rate;
+pub mod rate_table;
+pub mod redact;
+pub mod redenomination;
+pub mod region;
+#[cfg(any(feature = "actix", feature = "axum"))]
+mod response;
+pub mod rounding;
+#[cfg(feature = "rusty-money")]
+pub mod rusty_money_support;
+pub mod serde_helpers;
+#[cfg(feature = "chrono")]
+pub mod series;
+#[cfg(any(feature = "json", feature = "toml"))]
+pub mod snapshot;
+pub mod tax;
+pub mod tracked;
+#[cfg(feature = "validator")]
+pub mod validation;
+#[cfg(feature = "wasm")]
+pub mod wasm;
@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::amount::{LowestSubunit, MoneyInner};
+use crate::factor::FromCurrency;
+
+/// Minimum and maximum transaction amounts registered per currency.
+///
+/// Currencies without registered limits accept any amount.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::factor::Currency::{self, INR, USD};
+/// use amount_conversion::limits::{LimitViolation, Limits};
+///
+/// type Money = MoneyInner<LowestSubunit, Currency>;
+///
+/// let limits = Limits::new()
+///     .with_min(Money::new(100, &INR))
+///     .with_max(Money::new(10_000_000, &INR));
+/// assert_eq!(limits.validate(&Money::new(5_000, &INR)), Ok(()));
+/// assert_eq!(
+///     limits.validate(&Money::new(50, &INR)),
+///     Err(LimitViolation::BelowMinimum {
+///         amount: Money::new(50, &INR),
+///         min: Money::new(100, &INR),
+///     })
+/// );
+/// assert_eq!(limits.validate(&Money::new(1, &USD)), Ok(()));
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Limits<Cur: FromCurrency> {
+    ranges: HashMap<Cur, Range>,
+}
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+struct Range {
+    min: Option<LowestSubunit>,
+    max: Option<LowestSubunit>,
+}
+
+/// Why an amount was rejected by [`Limits::validate`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum LimitViolation<Cur: FromCurrency> {
+    /// The amount is less than the minimum registered for its currency.
+    BelowMinimum {
+        amount: MoneyInner<LowestSubunit, Cur>,
+        min: MoneyInner<LowestSubunit, Cur>,
+    },
+    /// The amount is more than the maximum registered for its currency.
+    AboveMaximum {
+        amount: MoneyInner<LowestSubunit, Cur>,
+        max: MoneyInner<LowestSubunit, Cur>,
+    },
+}
+
+impl<Cur: FromCurrency> Limits<Cur> {
+    pub fn new() -> Self {
+        Self {
+            ranges: HashMap::new(),
+        }
+    }
+
+    /// Registers the smallest amount allowed in `min`'s currency, replacing any earlier minimum.
+    pub fn with_min(mut self, min: MoneyInner<LowestSubunit, Cur>) -> Self {
+        self.ranges.entry(min.currency).or_default().min = Some(min.amount);
+        self
+    }
+
+    /// Registers the largest amount allowed in `max`'s currency, replacing any earlier maximum.
+    pub fn with_max(mut self, max: MoneyInner<LowestSubunit, Cur>) -> Self {
+        self.ranges.entry(max.currency).or_default().max = Some(max.amount);
+        self
+    }
+
+    /// The minimum registered for `currency`, if any.
+    pub fn min(&self, currency: &Cur) -> Option<MoneyInner<LowestSubunit, Cur>> {
+        let min = self.ranges.get(currency)?.min?;
+        Some(MoneyInner::<LowestSubunit, _>::new(min, currency))
+    }
+
+    /// The maximum registered for `currency`, if any.
+    pub fn max(&self, currency: &Cur) -> Option<MoneyInner<LowestSubunit, Cur>> {
+        let max = self.ranges.get(currency)?.max?;
+        Some(MoneyInner::<LowestSubunit, _>::new(max, currency))
+    }
+
+    /// Checks `money` against the limits of its currency, both bounds inclusive.
+    pub fn validate(
+        &self,
+        money: &MoneyInner<LowestSubunit, Cur>,
+    ) -> Result<(), LimitViolation<Cur>> {
+        if let Some(min) = self.min(&money.currency) {
+            if money.amount < min.amount {
+                return Err(LimitViolation::BelowMinimum {
+                    amount: *money,
+                    min,
+                });
+            }
+        }
+        if let Some(max) = self.max(&money.currency) {
+            if money.amount > max.amount {
+                return Err(LimitViolation::AboveMaximum {
+                    amount: *money,
+                    max,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<Cur: FromCurrency> LimitViolation<Cur> {
+    /// A stable snake_case identifier of the violation, `"below_minimum"` or `"above_maximum"`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::BelowMinimum { .. } => "below_minimum",
+            Self::AboveMaximum { .. } => "above_maximum",
+        }
+    }
+}
+
+impl<Cur: FromCurrency> Default for Limits<Cur> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Cur: FromCurrency> fmt::Display for LimitViolation<Cur> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BelowMinimum { amount, min } => write!(
+                f,
+                "amount of {} {} is below the minimum of {}",
+                amount.amount,
+                amount.currency.currency_code(),
+                min.amount
+            ),
+            Self::AboveMaximum { amount, max } => write!(
+                f,
+                "amount of {} {} is above the maximum of {}",
+                amount.amount,
+                amount.currency.currency_code(),
+                max.amount
+            ),
+        }
+    }
+}
+
+impl<Cur: FromCurrency + fmt::Debug> std::error::Error for LimitViolation<Cur> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+    use crate::factor::CurrencyCode;
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn validate_bounds() {
+        let limits = Limits::new()
+            .with_min(Money::new(100, &USD))
+            .with_max(Money::new(1_000, &USD))
+            .with_max(Money::new(5_000, &EUR));
+        for amount in [100, 500, 1_000] {
+            assert_eq!(limits.validate(&Money::new(amount, &USD)), Ok(()));
+        }
+        assert_eq!(
+            limits.validate(&Money::new(1_001, &USD)),
+            Err(LimitViolation::AboveMaximum {
+                amount: Money::new(1_001, &USD),
+                max: Money::new(1_000, &USD),
+            })
+        );
+        assert_eq!(limits.validate(&Money::new(-10, &EUR)), Ok(()));
+        assert_eq!(limits.validate(&Money::new(i32::MAX, &INR)), Ok(()));
+    }
+
+    #[test]
+    fn later_registration_replaces() {
+        let limits = Limits::new()
+            .with_min(Money::new(100, &USD))
+            .with_min(Money::new(50, &USD));
+        assert_eq!(limits.min(&USD), Some(Money::new(50, &USD)));
+        assert_eq!(limits.max(&USD), None);
+        assert_eq!(limits.min(&EUR), None);
+    }
+
+    #[test]
+    fn violation_message() {
+        let violation = LimitViolation::BelowMinimum {
+            amount: Money::new(50, &INR),
+            min: Money::new(100, &INR),
+        };
+        assert_eq!(
+            violation.to_string(),
+            "amount of 50 INR is below the minimum of 100"
+        );
+        assert_eq!(violation.code(), "below_minimum");
+
+        let points = CurrencyCode::register("LMP", USD, 0).unwrap();
+        let points = |amount| MoneyInner::<LowestSubunit, _>::new(amount, &points);
+        let violation = LimitViolation::AboveMaximum {
+            amount: points(1_250),
+            max: points(1_000),
+        };
+        assert_eq!(
+            violation.to_string(),
+            "amount of 1250 LMP is above the maximum of 1000"
+        );
+    }
+}
@@ -0,0 +1,564 @@
+//! Default currencies and number formatting conventions from BCP 47 locale tags such as
+//! `en-IN`, e.g. to bootstrap a currency from an `Accept-Language` header.
+//!
+//! The conventions cover the common cases of each language rather than the full CLDR data:
+//! decimal and grouping separators, the symbol position and spacing, Indian digit grouping and
+//! directional isolation for right-to-left languages.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{LowestSubunit, MoneyInner};
+//! use amount_conversion::factor::Currency;
+//! use amount_conversion::locale::LocaleConventions;
+//!
+//! let india = LocaleConventions::from_locale("en-IN").unwrap();
+//! assert_eq!(india.currency(), Currency::INR);
+//! let money = MoneyInner::<LowestSubunit, _>::new(123_456_780, &india.currency());
+//! assert_eq!(money.format_locale(&india)?, "₹12,34,567.80");
+//!
+//! let germany = LocaleConventions::from_accept_language("de-DE,de;q=0.9,en;q=0.8").unwrap();
+//! let money = MoneyInner::<LowestSubunit, _>::new(123_450, &germany.currency());
+//! assert_eq!(money.format_locale(&germany)?, "1.234,50\u{a0}€");
+//! # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+//! ```
+use crate::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::decimal::format_subunits;
+use crate::factor::{get_exponent, symbol_of, Currency, FromCurrency};
+use crate::format::{DigitGrouping, NegativeStyle, Scale};
+
+const NO_BREAK_SPACE: char = '\u{a0}';
+const NARROW_NO_BREAK_SPACE: char = '\u{202f}';
+const LEFT_TO_RIGHT_ISOLATE: char = '\u{2066}';
+const POP_DIRECTIONAL_ISOLATE: char = '\u{2069}';
+
+/// Languages written right to left, whose amounts are isolated by default.
+const RTL_LANGUAGES: [&str; 4] = ["ar", "fa", "he", "ur"];
+
+/// Where the symbol goes and whether a space separates it from the number.
+type SymbolLayout = (SymbolPlacement, bool);
+
+const BEFORE: SymbolLayout = (SymbolPlacement::Prefix, false);
+const BEFORE_SPACED: SymbolLayout = (SymbolPlacement::Prefix, true);
+const AFTER: SymbolLayout = (SymbolPlacement::Suffix, true);
+
+/// Currencies whose symbol is also the local symbol of other currencies, e.g. `$` of every
+/// dollar and peso and `¥` of the yuan.
+const AMBIGUOUS_SYMBOLS: [Currency; 2] = [Currency::USD, Currency::JPY];
+
+/// Decimal separator, grouping separator and symbol layout, for languages that differ from
+/// English.
+static LANGUAGES: [(&str, char, char, SymbolLayout); 22] = [
+    ("cs", ',', NO_BREAK_SPACE, AFTER),
+    ("da", ',', '.', AFTER),
+    ("de", ',', '.', AFTER),
+    ("el", ',', '.', AFTER),
+    ("es", ',', '.', AFTER),
+    ("fi", ',', NO_BREAK_SPACE, AFTER),
+    ("fr", ',', NARROW_NO_BREAK_SPACE, AFTER),
+    ("hr", ',', '.', AFTER),
+    ("hu", ',', NO_BREAK_SPACE, AFTER),
+    ("id", ',', '.', BEFORE),
+    ("it", ',', '.', AFTER),
+    ("nb", ',', NO_BREAK_SPACE, AFTER),
+    ("nl", ',', '.', BEFORE_SPACED),
+    ("pl", ',', NO_BREAK_SPACE, AFTER),
+    ("pt", ',', '.', BEFORE_SPACED),
+    ("ro", ',', '.', AFTER),
+    ("ru", ',', NO_BREAK_SPACE, AFTER),
+    ("sk", ',', NO_BREAK_SPACE, AFTER),
+    ("sl", ',', '.', AFTER),
+    ("sv", ',', NO_BREAK_SPACE, AFTER),
+    ("tr", ',', '.', BEFORE),
+    ("vi", ',', '.', AFTER),
+];
+
+/// Regions whose conventions differ from their language's.
+static REGIONS: [(&str, &str, char, char, SymbolLayout); 5] = [
+    ("de", "CH", '.', '\u{2019}', BEFORE),
+    ("de", "LI", '.', '\u{2019}', BEFORE),
+    ("es", "MX", '.', ',', BEFORE),
+    ("es", "US", '.', ',', BEFORE),
+    ("pt", "PT", ',', '.', AFTER),
+];
+
+/// The country a bare language tag such as `ja` stands for, where it is unambiguous.
+static LANGUAGE_COUNTRIES: [(&str, &str); 13] = [
+    ("cs", "CZ"),
+    ("da", "DK"),
+    ("he", "IL"),
+    ("hi", "IN"),
+    ("hu", "HU"),
+    ("ja", "JP"),
+    ("ko", "KR"),
+    ("nb", "NO"),
+    ("pl", "PL"),
+    ("ru", "RU"),
+    ("sv", "SE"),
+    ("th", "TH"),
+    ("vi", "VN"),
+];
+
+/// Whether the symbol goes before or after the number.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SymbolPlacement {
+    /// `$1,234.50`
+    Prefix,
+    /// `1.234,50 €`
+    Suffix,
+}
+
+/// Whether amounts show the currency's symbol or its code.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum SymbolDisplay {
+    /// The symbol, or the code for currencies without one.
+    Symbol,
+    /// Always the code, e.g. for multi-currency tables.
+    Code,
+    /// The symbol, except the code for a symbol shared with other currencies, such as `$` for
+    /// USD, when it is not the locale's own currency.
+    #[default]
+    CodeIfAmbiguous,
+}
+
+/// The default currency and number formatting conventions of a locale.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LocaleConventions {
+    currency: Currency,
+    decimal_separator: char,
+    group_separator: char,
+    symbol_placement: SymbolPlacement,
+    symbol_spacing: bool,
+    symbol_display: SymbolDisplay,
+    directional_isolates: bool,
+    negative_style: NegativeStyle,
+    grouping: DigitGrouping,
+}
+
+impl LocaleConventions {
+    /// English conventions for `currency`, as in `$1,234.50`, to be adjusted with the `with_*`
+    /// methods when the output format is known up front rather than taken from a locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, INR};
+    /// use amount_conversion::format::DigitGrouping;
+    /// use amount_conversion::locale::LocaleConventions;
+    ///
+    /// let money = MoneyInner::<LowestSubunit, Currency>::new(123_456_780, &INR);
+    /// let plain = LocaleConventions::new(INR).with_digit_grouping(DigitGrouping::NONE);
+    /// assert_eq!(money.format_locale(&plain)?, "₹1234567.80");
+    /// let indian = LocaleConventions::new(INR)
+    ///     .with_digit_grouping(DigitGrouping::INDIAN)
+    ///     .with_group_separator(' ');
+    /// assert_eq!(money.format_locale(&indian)?, "₹12 34 567.80");
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn new(currency: Currency) -> Self {
+        Self {
+            currency,
+            decimal_separator: '.',
+            group_separator: ',',
+            symbol_placement: BEFORE.0,
+            symbol_spacing: BEFORE.1,
+            symbol_display: SymbolDisplay::default(),
+            directional_isolates: false,
+            negative_style: NegativeStyle::default(),
+            grouping: DigitGrouping::THOUSANDS,
+        }
+    }
+
+    /// Resolves a BCP 47 tag such as `"en-IN"`, `"pt_BR"` or `"zh-Hant-TW"`, ignoring case.
+    ///
+    /// The currency comes from the region, or for a bare language such as `"ja"` from the one
+    /// country it stands for. Returns `None` when no supported currency follows from the tag.
+    pub fn from_locale(tag: &str) -> Option<Self> {
+        let mut subtags = tag.split(['-', '_']);
+        let language = subtags.next()?.to_ascii_lowercase();
+        if !(2..=3).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic())
+        {
+            return None;
+        }
+        // the region follows the language and an optional four-letter script
+        let region = subtags
+            .take(2)
+            .find(|subtag| subtag.len() == 2 && subtag.bytes().all(|b| b.is_ascii_alphabetic()))
+            .map(str::to_ascii_uppercase)
+            .or_else(|| {
+                LANGUAGE_COUNTRIES
+                    .iter()
+                    .find(|(lang, _)| *lang == language)
+                    .map(|(_, country)| country.to_string())
+            })?;
+        let currency = Currency::for_country(&region)?;
+
+        let (decimal_separator, group_separator, (symbol_placement, symbol_spacing)) = REGIONS
+            .iter()
+            .find(|(lang, country, ..)| *lang == language && *country == region)
+            .map(|&(_, _, decimal, group, layout)| (decimal, group, layout))
+            .or_else(|| {
+                LANGUAGES
+                    .iter()
+                    .find(|(lang, ..)| *lang == language)
+                    .map(|&(_, decimal, group, layout)| (decimal, group, layout))
+            })
+            .unwrap_or(('.', ',', BEFORE));
+        let grouping = if region == "IN" {
+            DigitGrouping::INDIAN
+        } else {
+            DigitGrouping::THOUSANDS
+        };
+        Some(Self {
+            decimal_separator,
+            group_separator,
+            symbol_placement,
+            symbol_spacing,
+            directional_isolates: RTL_LANGUAGES.contains(&language.as_str()),
+            grouping,
+            ..Self::new(currency)
+        })
+    }
+
+    /// Resolves the most preferred locale of an `Accept-Language` header value that yields a
+    /// currency, e.g. `"fr-CH, fr;q=0.9, en;q=0.8"`.
+    pub fn from_accept_language(header: &str) -> Option<Self> {
+        let mut ranges: Vec<(&str, f32)> = header
+            .split(',')
+            .filter_map(|range| {
+                let mut parts = range.split(';').map(str::trim);
+                let tag = parts.next().filter(|tag| !tag.is_empty() && *tag != "*")?;
+                let quality = parts
+                    .find_map(|param| param.strip_prefix("q="))
+                    .map_or(Some(1.0), |q| q.parse().ok())?;
+                (quality > 0.0).then_some((tag, quality))
+            })
+            .collect();
+        // stable, so equally preferred ranges keep the header's order
+        ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+        ranges
+            .into_iter()
+            .find_map(|(tag, _)| Self::from_locale(tag))
+    }
+
+    /// The locale's default currency.
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    pub fn decimal_separator(&self) -> char {
+        self.decimal_separator
+    }
+
+    pub fn group_separator(&self) -> char {
+        self.group_separator
+    }
+
+    /// Whether the symbol follows the amount, e.g. `1.234,50 €`.
+    pub fn symbol_after(&self) -> bool {
+        self.symbol_placement == SymbolPlacement::Suffix
+    }
+
+    pub fn symbol_placement(&self) -> SymbolPlacement {
+        self.symbol_placement
+    }
+
+    /// Whether a space separates the symbol from the number. Codes are always separated.
+    pub fn symbol_spacing(&self) -> bool {
+        self.symbol_spacing
+    }
+
+    pub fn symbol_display(&self) -> SymbolDisplay {
+        self.symbol_display
+    }
+
+    pub fn with_symbol_placement(mut self, symbol_placement: SymbolPlacement) -> Self {
+        self.symbol_placement = symbol_placement;
+        self
+    }
+
+    pub fn with_symbol_spacing(mut self, symbol_spacing: bool) -> Self {
+        self.symbol_spacing = symbol_spacing;
+        self
+    }
+
+    /// Sets whether amounts show symbols or codes, `CodeIfAmbiguous` by default.
+    pub fn with_symbol_display(mut self, symbol_display: SymbolDisplay) -> Self {
+        self.symbol_display = symbol_display;
+        self
+    }
+
+    /// Whether formatted amounts are wrapped in Unicode left-to-right isolates, so they keep
+    /// their order when embedded in right-to-left text.
+    pub fn directional_isolates(&self) -> bool {
+        self.directional_isolates
+    }
+
+    /// Sets whether to emit directional isolates, on by default for right-to-left languages
+    /// such as Arabic and Hebrew.
+    pub fn with_directional_isolates(mut self, directional_isolates: bool) -> Self {
+        self.directional_isolates = directional_isolates;
+        self
+    }
+
+    pub fn negative_style(&self) -> NegativeStyle {
+        self.negative_style
+    }
+
+    /// Sets how negative amounts are marked, a leading minus by default.
+    pub fn with_negative_style(mut self, negative_style: NegativeStyle) -> Self {
+        self.negative_style = negative_style;
+        self
+    }
+
+    /// The compact notation scale matching the grouping: `Indian` for Indian grouping as in
+    /// `12,34,567`, `Western` otherwise.
+    pub fn grouping(&self) -> Scale {
+        if self.grouping == DigitGrouping::INDIAN {
+            Scale::Indian
+        } else {
+            Scale::Western
+        }
+    }
+
+    pub fn digit_grouping(&self) -> DigitGrouping {
+        self.grouping
+    }
+
+    pub fn with_digit_grouping(mut self, grouping: DigitGrouping) -> Self {
+        self.grouping = grouping;
+        self
+    }
+
+    pub fn with_group_separator(mut self, group_separator: char) -> Self {
+        self.group_separator = group_separator;
+        self
+    }
+
+    pub fn with_decimal_separator(mut self, decimal_separator: char) -> Self {
+        self.decimal_separator = decimal_separator;
+        self
+    }
+
+    /// Inserts group separators into a string of integer digits.
+    fn group(&self, digits: &str) -> String {
+        self.grouping.apply(digits, self.group_separator)
+    }
+}
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// Renders the amount with the separators, grouping and symbol position of `conventions`,
+    /// e.g. `"₹12,34,567.80"` or `"1.234,50 €"`.
+    ///
+    /// The symbol is the money's own currency's, chosen by the conventions' `SymbolDisplay`, or
+    /// its code when it has none, e.g. `"CHF 1’234.50"`. Spaces are no-break spaces, and with
+    /// `directional_isolates` the result is wrapped in U+2066 and U+2069.
+    pub fn format_locale(
+        &self,
+        conventions: &LocaleConventions,
+    ) -> Result<String, MoneyConversionError<Cur>> {
+        let currency = self.currency.currency();
+        let symbol = match conventions.symbol_display {
+            SymbolDisplay::Code => None,
+            SymbolDisplay::CodeIfAmbiguous
+                if currency != conventions.currency && AMBIGUOUS_SYMBOLS.contains(&currency) =>
+            {
+                None
+            }
+            _ => symbol_of(&self.currency),
+        };
+        let space = if conventions.symbol_spacing || symbol.is_none() {
+            NO_BREAK_SPACE.to_string()
+        } else {
+            String::new()
+        };
+        let symbol = symbol.unwrap_or_else(|| self.currency.currency_code());
+        let negative = self.amount < 0;
+        let digits = format_subunits(i128::from(self.amount).abs(), get_exponent(self)?);
+        let number = match digits.split_once('.') {
+            Some((major, minor)) => format!(
+                "{}{}{minor}",
+                conventions.group(major),
+                conventions.decimal_separator
+            ),
+            None => conventions.group(&digits),
+        };
+        let style = conventions.negative_style;
+        let minus = |marked: bool| if negative && marked { "-" } else { "" };
+        let after_number = minus(style == NegativeStyle::TrailingMinus);
+        let after_symbol = minus(style == NegativeStyle::SignAfterSymbol);
+        let body = match conventions.symbol_placement {
+            SymbolPlacement::Prefix => {
+                format!("{symbol}{space}{after_symbol}{number}{after_number}")
+            }
+            SymbolPlacement::Suffix => {
+                format!("{number}{after_number}{space}{symbol}{after_symbol}")
+            }
+        };
+        let formatted = match style {
+            NegativeStyle::LeadingMinus if negative => format!("-{body}"),
+            NegativeStyle::Parentheses if negative => format!("({body})"),
+            _ => body,
+        };
+        Ok(if conventions.directional_isolates {
+            format!("{LEFT_TO_RIGHT_ISOLATE}{formatted}{POP_DIRECTIONAL_ISOLATE}")
+        } else {
+            formatted
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::*;
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    fn format(amount: i32, currency: Currency, tag: &str) -> String {
+        let conventions = LocaleConventions::from_locale(tag).unwrap();
+        Money::new(amount, &currency)
+            .format_locale(&conventions)
+            .unwrap()
+    }
+
+    #[test]
+    fn resolves_currencies() {
+        let currency = |tag| LocaleConventions::from_locale(tag).map(|c| c.currency());
+        assert_eq!(currency("en-IN"), Some(INR));
+        assert_eq!(currency("pt_br"), Some(BRL));
+        assert_eq!(currency("zh-Hant-TW"), Some(TWD));
+        assert_eq!(currency("ja"), Some(JPY));
+        assert_eq!(currency("es-419"), None);
+        assert_eq!(currency("en"), None);
+        assert_eq!(currency("english-US"), None);
+        assert_eq!(currency(""), None);
+    }
+
+    #[test]
+    fn accept_language() {
+        let currency =
+            |header| LocaleConventions::from_accept_language(header).map(|c| c.currency());
+        assert_eq!(currency("en;q=0.5, fr-CH;q=0.9, de-DE;q=0.7"), Some(CHF));
+        assert_eq!(currency("en, ja;q=0.1"), Some(JPY));
+        assert_eq!(currency("sv-SE;q=0, *, en-GB;q=0.3"), Some(GBP));
+        assert_eq!(currency("de-AT;q=bad, de-CH"), Some(CHF));
+        assert_eq!(currency(""), None);
+    }
+
+    #[test]
+    fn formats() {
+        assert_eq!(format(-123_456_780, INR, "hi-IN"), "-₹12,34,567.80");
+        assert_eq!(format(99_999, INR, "en-IN"), "₹999.99");
+        assert_eq!(format(123_456_789, USD, "en-US"), "$1,234,567.89");
+        assert_eq!(format(123_456, EUR, "fr-FR"), "1\u{202f}234,56\u{a0}€");
+        assert_eq!(format(123_450, CHF, "de-CH"), "CHF\u{a0}1\u{2019}234.50");
+        assert_eq!(format(1_000_000, JPY, "ja-JP"), "¥1,000,000");
+        assert_eq!(format(5, KWD, "en-US"), "KWD\u{a0}0.005");
+        assert_eq!(format(123_450, EUR, "nl-NL"), "€\u{a0}1.234,50");
+    }
+
+    #[test]
+    fn symbol_rules() {
+        let canada = LocaleConventions::from_locale("en-CA").unwrap();
+        let format = |amount, currency, conventions: &LocaleConventions| {
+            Money::new(amount, &currency)
+                .format_locale(conventions)
+                .unwrap()
+        };
+        assert_eq!(format(1_050, CAD, &canada), "CA$10.50");
+        assert_eq!(format(1_050, USD, &canada), "USD\u{a0}10.50");
+        let symbols = canada.with_symbol_display(SymbolDisplay::Symbol);
+        assert_eq!(format(1_050, USD, &symbols), "$10.50");
+        let codes = canada
+            .with_symbol_display(SymbolDisplay::Code)
+            .with_symbol_placement(SymbolPlacement::Suffix);
+        assert_eq!(format(1_050, CAD, &codes), "10.50\u{a0}CAD");
+        let germany = LocaleConventions::from_locale("de-DE")
+            .unwrap()
+            .with_symbol_spacing(false);
+        assert!(germany.symbol_after());
+        assert_eq!(format(1_050, EUR, &germany), "10,50€");
+    }
+
+    #[test]
+    fn negative_styles() {
+        let styled = |tag, style| {
+            let conventions = LocaleConventions::from_locale(tag)
+                .unwrap()
+                .with_negative_style(style);
+            let currency = conventions.currency();
+            Money::new(-1_050, &currency)
+                .format_locale(&conventions)
+                .unwrap()
+        };
+        assert_eq!(styled("en-US", NegativeStyle::Parentheses), "($10.50)");
+        assert_eq!(styled("en-US", NegativeStyle::TrailingMinus), "$10.50-");
+        assert_eq!(styled("en-US", NegativeStyle::SignAfterSymbol), "$-10.50");
+        assert_eq!(
+            styled("de-CH", NegativeStyle::SignAfterSymbol),
+            "CHF\u{a0}-10.50"
+        );
+        assert_eq!(
+            styled("de-DE", NegativeStyle::TrailingMinus),
+            "10,50-\u{a0}€"
+        );
+        assert_eq!(
+            styled("de-DE", NegativeStyle::SignAfterSymbol),
+            "10,50\u{a0}€-"
+        );
+        assert_eq!(
+            Money::new(1_050, &USD)
+                .format_locale(
+                    &LocaleConventions::from_locale("en-US")
+                        .unwrap()
+                        .with_negative_style(NegativeStyle::Parentheses)
+                )
+                .unwrap(),
+            "$10.50"
+        );
+    }
+
+    #[test]
+    fn explicit_grouping() {
+        let money = Money::new(-123_456_789, &USD);
+        let format = |grouping| {
+            money
+                .format_locale(&LocaleConventions::new(USD).with_digit_grouping(grouping))
+                .unwrap()
+        };
+        assert_eq!(format(DigitGrouping::THOUSANDS), "-$1,234,567.89");
+        assert_eq!(format(DigitGrouping::INDIAN), "-$12,34,567.89");
+        assert_eq!(format(DigitGrouping::NONE), "-$1234567.89");
+        assert_eq!(format(DigitGrouping::new(4, 4)), "-$123,4567.89");
+        assert_eq!(format(DigitGrouping::new(3, 0)), "-$1234,567.89");
+        let swiss = LocaleConventions::new(CHF)
+            .with_group_separator('\u{2019}')
+            .with_decimal_separator(',');
+        assert_eq!(
+            Money::new(123_450, &CHF).format_locale(&swiss).unwrap(),
+            "CHF\u{a0}1\u{2019}234,50"
+        );
+    }
+
+    #[test]
+    fn isolates_right_to_left() {
+        let israel = LocaleConventions::from_locale("he-IL").unwrap();
+        assert!(israel.directional_isolates());
+        let money = Money::new(-1_050, &ILS);
+        assert_eq!(
+            money.format_locale(&israel).unwrap(),
+            "\u{2066}-₪10.50\u{2069}"
+        );
+        assert_eq!(
+            money
+                .format_locale(&israel.with_directional_isolates(false))
+                .unwrap(),
+            "-₪10.50"
+        );
+        assert!(!LocaleConventions::from_locale("en-IL")
+            .unwrap()
+            .directional_isolates());
+    }
+}
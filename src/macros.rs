@@ -0,0 +1,199 @@
+/// Declares a currency enum for [`MoneyInner`](crate::amount::MoneyInner) together with the ISO
+/// currency each variant maps to and, optionally, its own number of decimals, generating the
+/// [`FromCurrency`](crate::factor::FromCurrency) impl.
+///
+/// Assets without an ISO code, such as loyalty points or internal settlement units, map to the
+/// ISO currency they are valued in and set the decimals they are counted in, which replace the
+/// ISO ones everywhere as with `exponent_override`.
+///
+/// The enum derives `Copy`, `Clone`, `Debug`, `Eq`, `Hash` and `PartialEq`, and gets an `ALL`
+/// constant listing the variants in declaration order, `code` returning the variant name and
+/// `from_code` looking a variant up by it.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::factor::{Currency, FromCurrency};
+///
+/// amount_conversion::define_currencies! {
+///     /// Everything a wallet can hold.
+///     pub enum Asset {
+///         Usd => USD,
+///         Inr => INR,
+///         /// Loyalty points worth a cent each, never fractional.
+///         Points => USD (exponent = 0),
+///         /// Internal settlement units in millionths of a dollar.
+///         Settlement => USD (exponent = 6),
+///     }
+/// }
+///
+/// type Money = MoneyInner<LowestSubunit, Asset>;
+///
+/// assert_eq!(Asset::Points.currency(), Currency::USD);
+/// assert_eq!(Money::from_str_exact("12.50", &Asset::Usd)?.amount(), 1_250);
+/// assert_eq!(Money::from_str_exact("1250", &Asset::Points)?.amount(), 1_250);
+/// assert_eq!(Money::from_str_exact("0.000125", &Asset::Settlement)?.amount(), 125);
+/// assert_eq!(Asset::ALL.len(), 4);
+/// assert_eq!(Asset::from_code("Points"), Some(Asset::Points));
+/// assert_eq!(Asset::Settlement.code(), "Settlement");
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<Asset>>(())
+/// ```
+#[macro_export]
+macro_rules! define_currencies {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $(
+                $(#[$variant_meta:meta])*
+                $variant:ident => $iso:ident $((exponent = $exponent:expr))?
+            ),+ $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+        $vis enum $name {
+            $(
+                $(#[$variant_meta])*
+                $variant,
+            )+
+        }
+
+        impl $name {
+            /// Every variant, in declaration order.
+            pub const ALL: &'static [Self] = &[$(Self::$variant),+];
+
+            /// The variant's name.
+            pub fn code(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => stringify!($variant),)+
+                }
+            }
+
+            /// The variant named `code`, matching case sensitively.
+            pub fn from_code(code: &str) -> ::core::option::Option<Self> {
+                Self::ALL.iter().copied().find(|currency| currency.code() == code)
+            }
+        }
+
+        impl $crate::factor::FromCurrency for $name {
+            fn currency(&self) -> $crate::factor::Currency {
+                match self {
+                    $(Self::$variant => $crate::factor::Currency::$iso,)+
+                }
+            }
+
+            fn exponent_override(&self) -> ::core::option::Option<u32> {
+                match self {
+                    $(Self::$variant => $crate::define_currencies!(@exponent $($exponent)?),)+
+                }
+            }
+        }
+    };
+    (@exponent $exponent:expr) => {
+        ::core::option::Option::Some($exponent)
+    };
+    (@exponent) => {
+        ::core::option::Option::None
+    };
+}
+
+/// Builds a [`SubunitOverrides`](crate::factor::SubunitOverrides) table from
+/// `CURRENCY => exponent` pairs at compile time, so a currency listed twice or an exponent above
+/// [`MAX_EXPONENT`](crate::factor::MAX_EXPONENT) fails the build.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::factor::{Currency, SubunitOverrides};
+///
+/// static GATEWAY: SubunitOverrides = amount_conversion::subunit_overrides! { HUF => 0, TWD => 0 };
+///
+/// assert_eq!(GATEWAY.exponent(Currency::TWD), 0);
+/// assert_eq!(GATEWAY.exponent(Currency::EUR), 2);
+/// ```
+///
+/// ```compile_fail
+/// static GATEWAY: amount_conversion::factor::SubunitOverrides =
+///     amount_conversion::subunit_overrides! { HUF => 0, HUF => 2 };
+/// ```
+#[macro_export]
+macro_rules! subunit_overrides {
+    ($($currency:ident => $exponent:expr),* $(,)?) => {{
+        const OVERRIDES: $crate::factor::SubunitOverrides = $crate::factor::SubunitOverrides::new(&[
+            $(($crate::factor::Currency::$currency, $exponent)),*
+        ]);
+        OVERRIDES
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::amount::{HighestUnit, LowestSubunit, MoneyConversionError, MoneyInner};
+    use crate::factor::{Currency, FromCurrency, SubunitOverrides, MAX_EXPONENT};
+
+    crate::define_currencies! {
+        enum Ledger {
+            Eur => EUR,
+            Miles => EUR (exponent = 0),
+            Broken => EUR (exponent = MAX_EXPONENT + 1)
+        }
+    }
+
+    #[test]
+    fn generated_impls() {
+        assert_eq!(Ledger::ALL, [Ledger::Eur, Ledger::Miles, Ledger::Broken]);
+        assert_eq!(Ledger::Miles.currency(), Currency::EUR);
+        assert_eq!(Ledger::Eur.exponent_override(), None);
+        assert_eq!(Ledger::from_code("miles"), None);
+        assert_eq!(
+            MoneyInner::<HighestUnit, _>::new(12.0, &Ledger::Miles).convert(),
+            Ok(MoneyInner::<LowestSubunit, _>::new(12, &Ledger::Miles))
+        );
+        assert_eq!(
+            MoneyInner::<LowestSubunit, _>::new(1, &Ledger::Broken).convert(),
+            Err(MoneyConversionError::InvalidFactor(MAX_EXPONENT + 1))
+        );
+    }
+
+    #[test]
+    fn recast_checks_exponents() {
+        let euros = MoneyInner::<LowestSubunit, _>::new(1_250, &Currency::EUR);
+        assert_eq!(
+            euros.recast(&Ledger::Eur),
+            Ok(MoneyInner::<LowestSubunit, _>::new(1_250, &Ledger::Eur))
+        );
+        assert_eq!(
+            euros.recast(&Ledger::Miles),
+            Err(MoneyConversionError::SubunitMismatch(
+                Currency::EUR,
+                Currency::EUR
+            ))
+        );
+        assert_eq!(
+            MoneyInner::<LowestSubunit, _>::new(1_250, &Ledger::Miles).recast(&Currency::EUR),
+            Err(MoneyConversionError::SubunitMismatch(
+                Currency::EUR,
+                Currency::EUR
+            ))
+        );
+        assert_eq!(
+            euros.recast(&Ledger::Broken),
+            Err(MoneyConversionError::InvalidFactor(MAX_EXPONENT + 1))
+        );
+    }
+
+    #[test]
+    fn static_subunit_overrides() {
+        static OVERRIDES: SubunitOverrides = crate::subunit_overrides! { KWD => 2, JPY => 2 };
+
+        assert_eq!(SubunitOverrides::new(&[]).get(Currency::KWD), None);
+        assert_eq!(OVERRIDES.exponent(Currency::KWD), 2);
+        assert_eq!(OVERRIDES.get(Currency::BHD), None);
+        assert_eq!(
+            MoneyInner::<LowestSubunit, _>::new(1_250, &OVERRIDES.apply(Currency::JPY))
+                .format_decimal(),
+            Ok("12.50".to_string())
+        );
+    }
+}
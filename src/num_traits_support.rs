@@ -0,0 +1,109 @@
+//! `num-traits` implementations, enabled with the `num-traits` feature.
+//!
+//! `CheckedAdd` and `CheckedSub` return `None` on a currency mismatch as well as on overflow.
+//! `Zero` and `Bounded` take no currency, so they are only implemented when the currency type
+//! has a `Default`, which they are expressed in; `Zero::is_zero` holds for zero of any currency.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{LowestSubunit, MoneyInner};
+//! use amount_conversion::factor::{Currency, FromCurrency};
+//! use num_traits::{CheckedAdd, Zero};
+//!
+//! #[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+//! struct Usd;
+//!
+//! impl FromCurrency for Usd {
+//!     fn currency(&self) -> Currency {
+//!         Currency::USD
+//!     }
+//! }
+//!
+//! type Money = MoneyInner<LowestSubunit, Usd>;
+//!
+//! fn total<T: Zero + CheckedAdd>(values: &[T]) -> Option<T> {
+//!     values.iter().try_fold(T::zero(), |total, value| total.checked_add(value))
+//! }
+//!
+//! let payments = [Money::new(1_050, &Usd), Money::new(250, &Usd)];
+//! assert_eq!(total(&payments), Some(Money::new(1_300, &Usd)));
+//! assert_eq!(total(&[Money::new(i32::MAX, &Usd); 2]), None);
+//! ```
+use std::fmt;
+
+use num_traits::{Bounded, CheckedAdd, CheckedSub, Zero};
+
+use crate::amount::MoneyInner;
+use crate::backend::AmountBackend;
+use crate::factor::FromCurrency;
+
+impl<B: AmountBackend, Cur: FromCurrency + fmt::Debug> CheckedAdd for MoneyInner<B, Cur> {
+    fn checked_add(&self, other: &Self) -> Option<Self> {
+        MoneyInner::checked_add(*self, *other).ok()
+    }
+}
+
+impl<B: AmountBackend, Cur: FromCurrency + fmt::Debug> CheckedSub for MoneyInner<B, Cur> {
+    fn checked_sub(&self, other: &Self) -> Option<Self> {
+        MoneyInner::checked_sub(*self, *other).ok()
+    }
+}
+
+impl<B: AmountBackend, Cur: FromCurrency + fmt::Debug + Default> Zero for MoneyInner<B, Cur> {
+    fn zero() -> Self {
+        Self::new(B::ZERO, &Cur::default())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.amount == B::ZERO
+    }
+}
+
+impl<B: AmountBackend + Bounded, Cur: FromCurrency + Default> Bounded for MoneyInner<B, Cur> {
+    fn min_value() -> Self {
+        Self::new(B::min_value(), &Cur::default())
+    }
+
+    fn max_value() -> Self {
+        Self::new(B::max_value(), &Cur::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amount::{LowestSubunit, WideMoney};
+    use crate::factor::Currency;
+
+    #[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+    struct Yen;
+
+    impl FromCurrency for Yen {
+        fn currency(&self) -> Currency {
+            Currency::JPY
+        }
+    }
+
+    type Money = MoneyInner<LowestSubunit, Yen>;
+
+    #[test]
+    fn traits_follow_checked_arithmetic() {
+        let (usd, eur) = (
+            MoneyInner::<LowestSubunit, _>::new(1, &Currency::USD),
+            MoneyInner::<LowestSubunit, _>::new(1, &Currency::EUR),
+        );
+        assert_eq!(CheckedAdd::checked_add(&usd, &eur), None);
+        assert_eq!(CheckedSub::checked_sub(&usd, &usd), Some(usd - usd));
+        assert_eq!(
+            CheckedSub::checked_sub(&Money::min_value(), &Money::new(1, &Yen)),
+            None
+        );
+        assert_eq!(Money::max_value().amount(), i32::MAX);
+        assert!(Zero::is_zero(&<Money as Zero>::zero()));
+        assert_eq!(
+            <WideMoney<Yen> as Zero>::zero() + WideMoney::new(5, &Yen),
+            WideMoney::new(5, &Yen)
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! A process-wide hook notified of every conversion, for metrics such as overflow or
+//! unknown-currency rates.
+//!
+//! # Examples
+//!
+//! ```
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! use amount_conversion::amount::{HighestUnit, LowestSubunit, MoneyInner};
+//! use amount_conversion::factor::Currency::{self, USD};
+//! use amount_conversion::observer::{self, ConversionEvent};
+//!
+//! static OVERFLOWS: AtomicUsize = AtomicUsize::new(0);
+//!
+//! observer::set_observer(|event: &ConversionEvent| {
+//!     if event.error == Some("f64_to_i32_conversion_failed") {
+//!         OVERFLOWS.fetch_add(1, Ordering::Relaxed);
+//!     }
+//! });
+//! assert!(MoneyInner::<HighestUnit, Currency>::new(1e12, &USD).convert::<LowestSubunit>().is_err());
+//! assert_eq!(OVERFLOWS.load(Ordering::Relaxed), 1);
+//! observer::clear_observer();
+//! ```
+use std::sync::{Arc, RwLock};
+
+use crate::amount::MoneyConversionError;
+use crate::factor::{Currency, FromCurrency};
+
+/// Which conversion a [`ConversionEvent`] reports.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ConversionKind {
+    /// Subunits to major units, e.g. `MoneyInner::<LowestSubunit, _>::convert`.
+    ToMajorUnits,
+    /// Major units to subunits, e.g. `MoneyInner::<HighestUnit, _>::convert` or `convert_floor`.
+    ToSubunits,
+    /// An exchange into another currency with `ExchangeRate::convert` or `convert_with`.
+    Exchange,
+}
+
+/// The outcome of one conversion.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ConversionEvent<'a> {
+    pub kind: ConversionKind,
+    /// The currency converted from.
+    pub currency: Currency,
+    /// The [`FromCurrency::currency_code`] of the currency converted from, which differs from
+    /// the ISO code of `currency` for a private code.
+    pub code: &'a str,
+    /// The [`MoneyConversionError::code`] of a failed conversion, `None` on success.
+    pub error: Option<&'static str>,
+}
+
+/// Receives an event after every conversion, successful or not.
+///
+/// Implemented for closures taking `&ConversionEvent`. Observers run on the converting thread, so
+/// they should be cheap, e.g. incrementing a counter.
+pub trait ConversionObserver: Send + Sync {
+    fn on_conversion(&self, event: &ConversionEvent<'_>);
+}
+
+impl<F: Fn(&ConversionEvent<'_>) + Send + Sync> ConversionObserver for F {
+    fn on_conversion(&self, event: &ConversionEvent<'_>) {
+        self(event)
+    }
+}
+
+static OBSERVER: RwLock<Option<Arc<dyn ConversionObserver>>> = RwLock::new(None);
+
+/// Installs `observer` for the whole process, replacing any earlier one.
+pub fn set_observer(observer: impl ConversionObserver + 'static) {
+    *OBSERVER.write().unwrap_or_else(|e| e.into_inner()) = Some(Arc::new(observer));
+}
+
+/// Removes the installed observer, if any.
+pub fn clear_observer() {
+    *OBSERVER.write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Reports `result` to the installed observer and passes it through.
+pub(crate) fn observe<T, Cur: FromCurrency>(
+    kind: ConversionKind,
+    currency: &Cur,
+    result: Result<T, MoneyConversionError<Cur>>,
+) -> Result<T, MoneyConversionError<Cur>> {
+    let observer = OBSERVER.read().unwrap_or_else(|e| e.into_inner()).clone();
+    if let Some(observer) = observer {
+        observer.on_conversion(&ConversionEvent {
+            kind,
+            currency: currency.currency(),
+            code: currency.currency_code(),
+            error: result.as_ref().err().map(MoneyConversionError::code),
+        });
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+    use std::thread::{self, ThreadId};
+
+    use super::*;
+    use crate::amount::{HighestUnit, LowestSubunit, MoneyInner};
+    use crate::exchange::ExchangeRate;
+    use crate::factor::Currency::*;
+    use crate::factor::CurrencyCode;
+    use crate::rounding::RoundingMode;
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn reports_each_conversion_once() {
+        // other tests convert concurrently, so only events from this thread are kept
+        type Recorded = (ConversionKind, Currency, String, Option<&'static str>);
+        let events: Arc<Mutex<Vec<(ThreadId, Recorded)>>> = Arc::default();
+        let recorded = events.clone();
+        set_observer(move |event: &ConversionEvent| {
+            let event = (
+                event.kind,
+                event.currency,
+                event.code.to_string(),
+                event.error,
+            );
+            recorded
+                .lock()
+                .unwrap()
+                .push((thread::current().id(), event));
+        });
+
+        let rate = ExchangeRate::new(USD, INR, 83.0).unwrap();
+        let _ = Money::new(1_999, &USD).convert();
+        let _ =
+            MoneyInner::<HighestUnit, Currency>::new(1e12, &JPY).convert_floor::<LowestSubunit>();
+        let _ = rate.convert(Money::new(100, &USD));
+        let _ = rate.convert_with(Money::new(100, &EUR), RoundingMode::HalfUp);
+        let miles = CurrencyCode::register("OBM", USD, 0).unwrap();
+        let _ = MoneyInner::<LowestSubunit, _>::new(1_250, &miles).convert();
+        clear_observer();
+        let _ = Money::new(1, &USD).convert();
+
+        let this = thread::current().id();
+        let events: Vec<_> = events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(thread, _)| *thread == this)
+            .map(|(_, event)| event.clone())
+            .collect();
+        let event = |kind, currency, code: &str, error| (kind, currency, code.to_string(), error);
+        assert_eq!(
+            events,
+            [
+                event(ConversionKind::ToMajorUnits, USD, "USD", None),
+                event(
+                    ConversionKind::ToSubunits,
+                    JPY,
+                    "JPY",
+                    Some("f64_to_i32_conversion_failed")
+                ),
+                event(ConversionKind::Exchange, USD, "USD", None),
+                event(
+                    ConversionKind::Exchange,
+                    EUR,
+                    "EUR",
+                    Some("currency_mismatch")
+                ),
+                event(ConversionKind::ToMajorUnits, USD, "OBM", None),
+            ]
+        );
+    }
+}
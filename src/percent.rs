@@ -0,0 +1,147 @@
+use crate::amount::{i128_to_i32, LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::FromCurrency;
+use crate::rounding::{div_round, RoundingMode};
+
+/// Basis points in 100%.
+pub(crate) const BPS_PER_UNIT: i128 = 10_000;
+
+/// An exact percentage expressed in basis points, where 1 bps is 0.01% and 10 000 bps is 100%.
+///
+/// Used instead of f64 percentages so surcharges and commissions never drift.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BasisPoints(i32);
+
+impl BasisPoints {
+    pub const fn new(bps: i32) -> Self {
+        Self(bps)
+    }
+
+    /// A whole percentage, e.g. `from_percent(18)` is 1800 bps.
+    ///
+    /// # Panics
+    ///
+    /// When `percent` is beyond ±21 474 836, whose basis points do not fit an `i32`; see
+    /// [`checked_from_percent`](Self::checked_from_percent).
+    pub const fn from_percent(percent: i32) -> Self {
+        match Self::checked_from_percent(percent) {
+            Some(bps) => bps,
+            None => panic!("percentage out of the basis point range"),
+        }
+    }
+
+    /// A whole percentage like [`from_percent`](Self::from_percent), or `None` when its basis
+    /// points do not fit an `i32`.
+    pub const fn checked_from_percent(percent: i32) -> Option<Self> {
+        match percent.checked_mul(100) {
+            Some(bps) => Some(Self(bps)),
+            None => None,
+        }
+    }
+
+    pub const fn get(self) -> i32 {
+        self.0
+    }
+
+    /// The share of `amount` subunits covered by this rate, before rounding to whole subunits.
+    pub(crate) fn of(self, amount: i128, rounding: RoundingMode) -> i128 {
+        div_round(amount * i128::from(self.0), BPS_PER_UNIT, rounding)
+    }
+}
+
+impl From<i32> for BasisPoints {
+    fn from(bps: i32) -> Self {
+        Self(bps)
+    }
+}
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// The `bps` share of the amount, rounded half-even to whole subunits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// // 2.5% commission on 19.99 USD
+    /// assert_eq!(Money::new(1999, &USD).apply_bps(250), Ok(Money::new(50, &USD)));
+    /// ```
+    pub fn apply_bps(self, bps: impl Into<BasisPoints>) -> Result<Self, MoneyConversionError<Cur>> {
+        self.apply_bps_with(bps, RoundingMode::HalfEven)
+    }
+
+    /// The `bps` share of the amount, rounded to whole subunits with `rounding`.
+    pub fn apply_bps_with(
+        self,
+        bps: impl Into<BasisPoints>,
+        rounding: RoundingMode,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        let share = bps.into().of(i128::from(self.amount), rounding);
+        Ok(Self::new(
+            i128_to_i32(share, self.currency)?,
+            &self.currency,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn apply_bps() -> Result<(), MoneyConversionError<Currency>> {
+        let amount = Money::new(1000, &USD);
+        assert_eq!(amount.apply_bps(250)?, Money::new(25, &USD));
+        assert_eq!(amount.apply_bps(BasisPoints::from_percent(100))?, amount);
+        assert_eq!(amount.apply_bps(-250)?, Money::new(-25, &USD));
+
+        // 0.5 subunit ties
+        assert_eq!(Money::new(50, &USD).apply_bps(100)?, Money::new(0, &USD));
+        assert_eq!(Money::new(150, &USD).apply_bps(100)?, Money::new(2, &USD));
+        assert_eq!(
+            Money::new(50, &USD).apply_bps_with(100, RoundingMode::Ceil)?,
+            Money::new(1, &USD)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn percent_range() {
+        assert_eq!(
+            BasisPoints::checked_from_percent(-21_474_836),
+            Some(BasisPoints::new(-2_147_483_600))
+        );
+        assert_eq!(BasisPoints::checked_from_percent(21_474_837), None);
+        assert_eq!(BasisPoints::checked_from_percent(i32::MIN), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "percentage out of the basis point range")]
+    fn from_percent_overflow() {
+        BasisPoints::from_percent(21_474_837);
+    }
+
+    #[test]
+    fn apply_bps_overflow() {
+        assert_eq!(
+            Money::new(i32::MAX, &USD).apply_bps(BasisPoints::from_percent(200)),
+            Err(MoneyConversionError::I128ToI32ConversionFailed {
+                value: 4_294_967_294,
+                bound: i32::MAX,
+                currency: USD
+            })
+        );
+        assert_eq!(
+            Money::new(i32::MIN, &USD)
+                .apply_bps(BasisPoints::from_percent(200))
+                .unwrap_err()
+                .to_string(),
+            "-4294967296 subunits of USD exceed the i32 minimum -2147483648"
+        );
+    }
+}
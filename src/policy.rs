@@ -0,0 +1,439 @@
+use crate::amount::{HighestUnit, LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::exchange::ExchangeRate;
+use crate::factor::{factor_of, factors_of, get_exponent, to_major, FromCurrency, MAX_EXPONENT};
+use crate::lenient::Lenient;
+use crate::observer::{observe, ConversionKind};
+use crate::rounding::{round_f64, snap_to_whole, RoundingMode};
+
+/// What to do when a result does not fit the subunit type.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum OnOverflow {
+    /// Fail with `F64ToI32ConversionFailed`.
+    #[default]
+    Error,
+    /// Clamp to `i32::MIN` or `i32::MAX`.
+    Saturate,
+}
+
+/// What to do when the exponent lookup of a currency fails, e.g. for an `exponent_override`
+/// above `MAX_EXPONENT`.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum OnUnknownCurrency {
+    /// Fail with the lookup error, `InvalidFactor`.
+    #[default]
+    Error,
+    /// Assume this many decimals and record it in the result.
+    Fallback(u32),
+}
+
+/// What to do when a result falls between two subunits.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum OnPrecisionLoss {
+    /// Fail with `TooManyDecimals`.
+    #[default]
+    Error,
+    /// Round with the policy's rounding mode.
+    Round,
+}
+
+/// How conversions round, overflow and handle currencies without a usable exponent, in one place.
+///
+/// The default is strict: every overflow, failed exponent lookup and fractional subunit is an error.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{HighestUnit, MoneyConversionError, MoneyInner};
+/// use amount_conversion::factor::Currency::{self, USD};
+/// use amount_conversion::policy::{ConversionPolicy, OnOverflow, OnPrecisionLoss};
+/// use amount_conversion::rounding::RoundingMode;
+///
+/// type Major = MoneyInner<HighestUnit, Currency>;
+///
+/// let strict = ConversionPolicy::default();
+/// assert_eq!(
+///     Major::new(10.125, &USD).convert_with_policy(&strict),
+///     Err(MoneyConversionError::TooManyDecimals(2))
+/// );
+///
+/// let reporting = ConversionPolicy::new()
+///     .with_rounding(RoundingMode::HalfUp)
+///     .with_precision_loss(OnPrecisionLoss::Round)
+///     .with_overflow(OnOverflow::Saturate);
+/// let converted = Major::new(10.125, &USD).convert_with_policy(&reporting)?;
+/// assert_eq!(converted.value.amount(), 1013);
+/// assert_eq!(Major::new(1e12, &USD).convert_with_policy(&reporting)?.value.amount(), i32::MAX);
+/// # Ok::<(), MoneyConversionError<Currency>>(())
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct ConversionPolicy {
+    rounding: RoundingMode,
+    overflow: OnOverflow,
+    unknown_currency: OnUnknownCurrency,
+    precision_loss: OnPrecisionLoss,
+    bounds: Option<(LowestSubunit, LowestSubunit)>,
+}
+
+impl ConversionPolicy {
+    /// The strict policy, same as `default()`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A policy that never fails on data issues: it rounds, saturates and assumes
+    /// `fallback_exponent` decimals for currencies whose exponent lookup fails.
+    pub fn lenient(fallback_exponent: u32) -> Self {
+        Self::new()
+            .with_overflow(OnOverflow::Saturate)
+            .with_unknown_currency(OnUnknownCurrency::Fallback(fallback_exponent))
+            .with_precision_loss(OnPrecisionLoss::Round)
+    }
+
+    /// Sets the rounding mode used when precision loss is allowed, `HalfEven` by default.
+    pub fn with_rounding(mut self, rounding: RoundingMode) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    pub fn with_overflow(mut self, overflow: OnOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn with_unknown_currency(mut self, unknown_currency: OnUnknownCurrency) -> Self {
+        self.unknown_currency = unknown_currency;
+        self
+    }
+
+    pub fn with_precision_loss(mut self, precision_loss: OnPrecisionLoss) -> Self {
+        self.precision_loss = precision_loss;
+        self
+    }
+
+    /// Limits amounts to `min..=max` subunits, tighter than the `i32` range, e.g. a maximum
+    /// single transaction. Amounts outside fail with `AmountOutOfBounds` whatever the overflow
+    /// rule, since they are valid values the application does not accept.
+    ///
+    /// # Panics
+    ///
+    /// Panics when `min` is greater than `max`.
+    pub fn with_bounds(mut self, min: LowestSubunit, max: LowestSubunit) -> Self {
+        assert!(min <= max, "bounds {min}..={max} are empty");
+        self.bounds = Some((min, max));
+        self
+    }
+
+    pub fn rounding(&self) -> RoundingMode {
+        self.rounding
+    }
+
+    pub fn overflow(&self) -> OnOverflow {
+        self.overflow
+    }
+
+    pub fn unknown_currency(&self) -> OnUnknownCurrency {
+        self.unknown_currency
+    }
+
+    pub fn precision_loss(&self) -> OnPrecisionLoss {
+        self.precision_loss
+    }
+
+    /// The inclusive subunit bounds, if any.
+    pub fn bounds(&self) -> Option<(LowestSubunit, LowestSubunit)> {
+        self.bounds
+    }
+
+    /// The exponent of `money`'s currency, with the fallback when one was used.
+    fn exponent<Amt, Cur: FromCurrency>(
+        &self,
+        money: &MoneyInner<Amt, Cur>,
+    ) -> Result<(u32, Option<u32>), MoneyConversionError<Cur>> {
+        match (get_exponent(money), self.unknown_currency) {
+            (Err(_), OnUnknownCurrency::Fallback(exponent)) => {
+                if exponent > MAX_EXPONENT {
+                    return Err(MoneyConversionError::InvalidFactor(exponent));
+                }
+                Ok((exponent, Some(exponent)))
+            }
+            (exponent, _) => Ok((exponent?, None)),
+        }
+    }
+
+    /// Narrows a subunit value of `currency` to `i32` following the precision-loss and
+    /// overflow rules.
+    fn narrow<Cur: Copy>(
+        &self,
+        subunits: f64,
+        exponent: u32,
+        currency: Cur,
+    ) -> Result<LowestSubunit, MoneyConversionError<Cur>> {
+        let subunits = snap_to_whole(subunits);
+        if subunits.fract() != 0.0 && self.precision_loss == OnPrecisionLoss::Error {
+            return Err(MoneyConversionError::TooManyDecimals(exponent));
+        }
+        let subunits = round_f64(subunits, self.rounding);
+        self.check_bounds(subunits, currency)?;
+        let in_range = (f64::from(i32::MIN)..=f64::from(i32::MAX)).contains(&subunits);
+        match self.overflow {
+            _ if in_range => Ok(subunits as i32),
+            OnOverflow::Saturate if !subunits.is_nan() => Ok(subunits as i32),
+            _ => Err(MoneyConversionError::F64ToI32ConversionFailed {
+                value: subunits,
+                bound: if subunits < 0.0 { i32::MIN } else { i32::MAX },
+                currency,
+            }),
+        }
+    }
+
+    /// Fails with `AmountOutOfBounds` when `subunits` is outside the configured bounds. Values
+    /// are checked before saturation, so the error reports the actual amount.
+    fn check_bounds<Cur>(
+        &self,
+        subunits: f64,
+        currency: Cur,
+    ) -> Result<(), MoneyConversionError<Cur>> {
+        match self.bounds {
+            Some((min, _)) if subunits < f64::from(min) => {
+                Err(MoneyConversionError::AmountOutOfBounds {
+                    value: subunits,
+                    limit: min,
+                    currency,
+                })
+            }
+            Some((_, max)) if subunits > f64::from(max) => {
+                Err(MoneyConversionError::AmountOutOfBounds {
+                    value: subunits,
+                    limit: max,
+                    currency,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// Converts to major units, applying the unknown-currency and bounds rules of `policy`.
+    pub fn convert_with_policy(
+        self,
+        policy: &ConversionPolicy,
+    ) -> Result<Lenient<MoneyInner<HighestUnit, Cur>>, MoneyConversionError<Cur>> {
+        let converted = policy
+            .check_bounds(f64::from(self.amount), self.currency)
+            .and_then(|()| {
+                let (exponent, fallback_exponent) = policy.exponent(&self)?;
+                Ok(Lenient {
+                    value: MoneyInner::<HighestUnit, _>::new(
+                        to_major(f64::from(self.amount), factors_of(exponent)),
+                        &self.currency,
+                    ),
+                    fallback_exponent,
+                })
+            });
+        observe(ConversionKind::ToMajorUnits, &self.currency, converted)
+    }
+}
+
+impl<Cur: FromCurrency> MoneyInner<HighestUnit, Cur> {
+    /// Converts to subunits, applying every rule of `policy`.
+    pub fn convert_with_policy(
+        self,
+        policy: &ConversionPolicy,
+    ) -> Result<Lenient<MoneyInner<LowestSubunit, Cur>>, MoneyConversionError<Cur>> {
+        let converted = policy
+            .exponent(&self)
+            .and_then(|(exponent, fallback_exponent)| {
+                let factor = factor_of(exponent);
+                let subunits = policy.narrow(self.amount * factor, exponent, self.currency)?;
+                Ok(Lenient {
+                    value: MoneyInner::<LowestSubunit, _>::new(subunits, &self.currency),
+                    fallback_exponent,
+                })
+            });
+        observe(ConversionKind::ToSubunits, &self.currency, converted)
+    }
+}
+
+impl<Cur: FromCurrency> ExchangeRate<Cur> {
+    /// Converts `money` into the target currency, applying the rounding, precision-loss,
+    /// overflow and bounds rules of `policy`. Both currencies must be in the subunit map.
+    pub fn convert_with_policy(
+        &self,
+        money: MoneyInner<LowestSubunit, Cur>,
+        policy: &ConversionPolicy,
+    ) -> Result<MoneyInner<LowestSubunit, Cur>, MoneyConversionError<Cur>> {
+        let target = MoneyInner::<LowestSubunit, _>::zero(&self.to());
+        let converted = self.subunit_rate(&money).and_then(|rate| {
+            let exponent = get_exponent(&target)?;
+            let subunits = policy.narrow(f64::from(money.amount) * rate, exponent, self.to())?;
+            Ok(MoneyInner::<LowestSubunit, _>::new(subunits, &self.to()))
+        });
+        observe(ConversionKind::Exchange, &money.currency, converted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+    type Major = MoneyInner<HighestUnit, Currency>;
+
+    #[test]
+    fn strict_by_default() {
+        let strict = ConversionPolicy::default();
+        assert_eq!(
+            Major::new(0.29, &USD).convert_with_policy(&strict),
+            Ok(Lenient {
+                value: Money::new(29, &USD),
+                fallback_exponent: None
+            })
+        );
+        assert_eq!(
+            Major::new(-1e12, &USD).convert_with_policy(&strict),
+            Err(MoneyConversionError::F64ToI32ConversionFailed {
+                value: -1e14,
+                bound: i32::MIN,
+                currency: USD
+            })
+        );
+        assert_eq!(
+            Major::new(1.5, &JPY).convert_with_policy(&strict),
+            Err(MoneyConversionError::TooManyDecimals(0))
+        );
+    }
+
+    #[test]
+    fn lenient_rounds_and_saturates() {
+        let lenient = ConversionPolicy::lenient(2).with_rounding(RoundingMode::Floor);
+        let convert = |amount: f64| {
+            Major::new(amount, &USD)
+                .convert_with_policy(&lenient)
+                .map(|converted| converted.value.amount())
+        };
+        assert_eq!(convert(10.129), Ok(1012));
+        assert_eq!(convert(-1e12), Ok(i32::MIN));
+        assert!(matches!(
+            convert(f64::NAN),
+            Err(MoneyConversionError::F64ToI32ConversionFailed { value, .. }) if value.is_nan()
+        ));
+        assert_eq!(
+            Money::new(1_050, &USD)
+                .convert_with_policy(&lenient)
+                .map(|converted| converted.value.amount()),
+            Ok(10.5)
+        );
+    }
+
+    #[test]
+    fn bounds() {
+        // At most 1,000,000.00 per transaction, and no refunds.
+        let policy = ConversionPolicy::lenient(2).with_bounds(0, 100_000_000);
+        assert_eq!(
+            Major::new(1_000_000.0, &USD)
+                .convert_with_policy(&policy)
+                .map(|converted| converted.value.amount()),
+            Ok(100_000_000)
+        );
+        assert_eq!(
+            Major::new(1e12, &USD).convert_with_policy(&policy),
+            Err(MoneyConversionError::AmountOutOfBounds {
+                value: 1e14,
+                limit: 100_000_000,
+                currency: USD
+            })
+        );
+        // Bounds apply before the overflow rule, which only sees values within them.
+        assert_eq!(
+            Major::new(-1e12, &USD)
+                .convert_with_policy(&policy.with_overflow(OnOverflow::Error))
+                .unwrap_err()
+                .to_string(),
+            "-100000000000000 subunits of USD exceed the configured minimum 0"
+        );
+        let refund = Money::new(-1, &USD)
+            .convert_with_policy(&policy)
+            .unwrap_err();
+        assert_eq!(refund.code(), "amount_out_of_bounds");
+        assert_eq!(
+            refund.to_string(),
+            "-1 subunits of USD exceed the configured minimum 0"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "bounds 10..=0 are empty")]
+    fn empty_bounds() {
+        let _ = ConversionPolicy::new().with_bounds(10, 0);
+    }
+
+    #[test]
+    fn exchange() -> Result<(), MoneyConversionError<Currency>> {
+        let rate = ExchangeRate::new(USD, EUR, 0.92)?;
+        assert_eq!(
+            rate.convert_with_policy(Money::new(1_999, &USD), &ConversionPolicy::new()),
+            Err(MoneyConversionError::TooManyDecimals(2))
+        );
+        let rounding = ConversionPolicy::new()
+            .with_precision_loss(OnPrecisionLoss::Round)
+            .with_rounding(RoundingMode::Ceil);
+        assert_eq!(
+            rate.convert_with_policy(Money::new(1_999, &USD), &rounding)?,
+            Money::new(1_840, &EUR)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_currency_fallback() {
+        #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+        struct Token(u32);
+
+        impl FromCurrency for Token {
+            fn currency(&self) -> Currency {
+                USD
+            }
+
+            fn exponent_override(&self) -> Option<u32> {
+                Some(self.0)
+            }
+        }
+
+        let broken = Token(MAX_EXPONENT + 1);
+        let strict = ConversionPolicy::new();
+        assert_eq!(
+            MoneyInner::<HighestUnit, _>::new(10.5, &broken).convert_with_policy(&strict),
+            Err(MoneyConversionError::InvalidFactor(MAX_EXPONENT + 1))
+        );
+
+        let fallback = strict.with_unknown_currency(OnUnknownCurrency::Fallback(2));
+        assert_eq!(
+            MoneyInner::<HighestUnit, _>::new(10.5, &broken).convert_with_policy(&fallback),
+            Ok(Lenient {
+                value: MoneyInner::<LowestSubunit, _>::new(1_050, &broken),
+                fallback_exponent: Some(2)
+            })
+        );
+        assert_eq!(
+            MoneyInner::<LowestSubunit, _>::new(1_050, &broken).convert_with_policy(&fallback),
+            Ok(Lenient {
+                value: MoneyInner::<HighestUnit, _>::new(10.5, &broken),
+                fallback_exponent: Some(2)
+            })
+        );
+        // a usable exponent ignores the fallback
+        assert_eq!(
+            MoneyInner::<HighestUnit, _>::new(10.5, &Token(3))
+                .convert_with_policy(&fallback)
+                .map(|converted| (converted.value.amount(), converted.fallback_exponent)),
+            Ok((10_500, None))
+        );
+        let invalid = strict.with_unknown_currency(OnUnknownCurrency::Fallback(MAX_EXPONENT + 1));
+        assert_eq!(
+            MoneyInner::<HighestUnit, _>::new(10.5, &broken).convert_with_policy(&invalid),
+            Err(MoneyConversionError::InvalidFactor(MAX_EXPONENT + 1))
+        );
+    }
+}
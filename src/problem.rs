@@ -0,0 +1,249 @@
+//! RFC 7807 problem details for the crate's errors, so API layers can answer every failure with
+//! the same `application/problem+json` shape.
+//!
+//! The `type` of a problem is [`TYPE_PREFIX`] followed by the error's stable `code`, which is
+//! also included as an extension member, e.g. `urn:amount-conversion:problem:currency_mismatch`.
+//! Invalid input answers `422 Unprocessable Entity`, a rate source that cannot be queried
+//! `503 Service Unavailable` and an unreadable rate snapshot `500 Internal Server Error`.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::MoneyConversionError;
+//! use amount_conversion::factor::Currency::{self, EUR, USD};
+//! use amount_conversion::problem::ProblemDetails;
+//!
+//! let error = MoneyConversionError::CurrencyMismatch(USD, EUR);
+//! let problem = ProblemDetails::from(&error);
+//! assert_eq!(problem.type_uri, "urn:amount-conversion:problem:currency_mismatch");
+//! assert_eq!(problem.title, "Currency mismatch");
+//! assert_eq!(problem.status, 422);
+//! assert_eq!(problem.detail, error.to_string());
+//! ```
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::amount::MoneyConversionError;
+#[cfg(feature = "csv")]
+use crate::csv_support::{CsvError, RowError};
+use crate::factor::{FromCurrency, ParseCurrencyError};
+use crate::limits::LimitViolation;
+use crate::provider::{FallbackError, RateProviderError};
+#[cfg(any(feature = "json", feature = "toml"))]
+use crate::snapshot::SnapshotError;
+
+/// The media type of a serialized [`ProblemDetails`].
+pub const CONTENT_TYPE: &str = "application/problem+json";
+
+/// The prefix of every problem `type`, followed by the error's code.
+pub const TYPE_PREFIX: &str = "urn:amount-conversion:problem:";
+
+/// An RFC 7807 problem details object, serializing `type_uri` as `type` and omitting `errors`
+/// when empty.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+pub struct ProblemDetails {
+    /// [`TYPE_PREFIX`] followed by `code`.
+    #[serde(rename = "type")]
+    pub type_uri: String,
+    /// A short summary that is the same for every problem of the type.
+    pub title: String,
+    /// The HTTP status code.
+    pub status: u16,
+    /// What went wrong in this occurrence, the error's message.
+    pub detail: String,
+    /// The stable snake_case identifier of the error kind.
+    pub code: &'static str,
+    /// The individual problems of a batch, e.g. every rejected row of a CSV upload.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ProblemDetails>,
+}
+
+impl ProblemDetails {
+    /// A problem of the type for `code`, titled after it: `"too_many_decimals"` becomes
+    /// `"Too many decimals"`.
+    pub fn new(code: &'static str, status: u16, detail: impl Into<String>) -> Self {
+        let mut title: String = code.replace('_', " ");
+        if let Some(first) = title.get_mut(..1) {
+            first.make_ascii_uppercase();
+        }
+        Self {
+            type_uri: format!("{TYPE_PREFIX}{code}"),
+            title,
+            status,
+            detail: detail.into(),
+            code,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Nests the individual problems of a batch.
+    pub fn with_errors(mut self, errors: Vec<ProblemDetails>) -> Self {
+        self.errors = errors;
+        self
+    }
+}
+
+impl<T: fmt::Debug> From<&MoneyConversionError<T>> for ProblemDetails {
+    fn from(error: &MoneyConversionError<T>) -> Self {
+        Self::new(error.code(), 422, error.to_string())
+    }
+}
+
+impl From<&ParseCurrencyError> for ProblemDetails {
+    fn from(error: &ParseCurrencyError) -> Self {
+        Self::new("unknown_currency", 422, error.to_string())
+    }
+}
+
+impl<Cur: FromCurrency> From<&LimitViolation<Cur>> for ProblemDetails {
+    fn from(violation: &LimitViolation<Cur>) -> Self {
+        Self::new(violation.code(), 422, violation.to_string())
+    }
+}
+
+impl From<&RateProviderError> for ProblemDetails {
+    fn from(error: &RateProviderError) -> Self {
+        let status = match error {
+            RateProviderError::NotFound => 422,
+            RateProviderError::Unavailable(_) => 503,
+        };
+        Self::new(error.code(), status, error.to_string())
+    }
+}
+
+/// Nests a problem for each provider that failed, its detail prefixed with the provider's name.
+impl From<&FallbackError> for ProblemDetails {
+    fn from(error: &FallbackError) -> Self {
+        let failures = error
+            .failures
+            .iter()
+            .map(|(name, failure)| {
+                let mut problem = Self::from(failure);
+                problem.detail = format!("{name}: {}", problem.detail);
+                problem
+            })
+            .collect();
+        let status = if error.code() == "rate_not_found" {
+            422
+        } else {
+            503
+        };
+        Self::new(error.code(), status, "no rate provider served the rate").with_errors(failures)
+    }
+}
+
+#[cfg(any(feature = "json", feature = "toml"))]
+impl<Cur: fmt::Debug> From<&SnapshotError<Cur>> for ProblemDetails {
+    fn from(error: &SnapshotError<Cur>) -> Self {
+        Self::new(error.code(), 500, error.to_string())
+    }
+}
+
+#[cfg(feature = "csv")]
+impl<Cur: fmt::Debug> From<&CsvError<Cur>> for ProblemDetails {
+    fn from(error: &CsvError<Cur>) -> Self {
+        Self::new(error.code(), 422, error.to_string())
+    }
+}
+
+#[cfg(feature = "csv")]
+impl<Cur: fmt::Debug> From<&RowError<Cur>> for ProblemDetails {
+    fn from(error: &RowError<Cur>) -> Self {
+        Self::new(error.error.code(), 422, error.to_string())
+    }
+}
+
+/// The errors of [`read_money`](crate::csv_support::read_money), nested in file order under an
+/// `invalid_rows` problem.
+#[cfg(feature = "csv")]
+impl<Cur: fmt::Debug> From<&[RowError<Cur>]> for ProblemDetails {
+    fn from(errors: &[RowError<Cur>]) -> Self {
+        let detail = match errors.len() {
+            1 => "1 row was rejected".to_string(),
+            n => format!("{n} rows were rejected"),
+        };
+        Self::new("invalid_rows", 422, detail).with_errors(errors.iter().map(Self::from).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency;
+
+    #[test]
+    fn serializes_rfc_7807_members() {
+        let problem = ProblemDetails::from(&MoneyConversionError::<Currency>::TooManyDecimals(2));
+        assert_eq!(
+            serde_json::to_value(&problem).unwrap(),
+            serde_json::json!({
+                "type": "urn:amount-conversion:problem:too_many_decimals",
+                "title": "Too many decimals",
+                "status": 422,
+                "detail": problem.detail,
+                "code": "too_many_decimals",
+            })
+        );
+        assert_eq!(
+            ProblemDetails::from(&"EUO".parse::<Currency>().unwrap_err()).type_uri,
+            "urn:amount-conversion:problem:unknown_currency"
+        );
+    }
+
+    #[test]
+    fn fallback_nests_failures() {
+        let error = FallbackError {
+            failures: vec![
+                (
+                    "api".to_string(),
+                    RateProviderError::Unavailable("timed out".into()),
+                ),
+                ("cache".to_string(), RateProviderError::NotFound),
+            ],
+        };
+        let problem = ProblemDetails::from(&error);
+        assert_eq!(problem.code, "rate_source_unavailable");
+        assert_eq!(problem.status, 503);
+        let nested: Vec<_> = problem
+            .errors
+            .iter()
+            .map(|p| (p.code, p.status, p.detail.as_str()))
+            .collect();
+        assert_eq!(
+            nested,
+            [
+                (
+                    "rate_source_unavailable",
+                    503,
+                    "api: rate source unavailable: timed out"
+                ),
+                (
+                    "rate_not_found",
+                    422,
+                    "cache: no rate for the currency pair"
+                ),
+            ]
+        );
+
+        let error = FallbackError {
+            failures: vec![("cache".to_string(), RateProviderError::NotFound)],
+        };
+        assert_eq!(ProblemDetails::from(&error).status, 422);
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn batch_nests_rows() {
+        use crate::csv_support::{read_money, Layout};
+
+        let upload = "amount,currency\n1.999,USD\n2,USD\n1e3,USD\n";
+        let errors = read_money::<_, Currency>(upload.as_bytes(), Layout::MajorUnits).unwrap_err();
+        let problem = ProblemDetails::from(errors.as_slice());
+        assert_eq!(problem.code, "invalid_rows");
+        assert_eq!(problem.detail, "2 rows were rejected");
+        assert_eq!(problem.errors.len(), 2);
+        assert!(problem.errors[0].detail.starts_with("line 2: "));
+        assert_eq!(problem.errors[0].code, "too_many_decimals");
+    }
+}
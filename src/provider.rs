@@ -0,0 +1,255 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::exchange::ExchangeRate;
+use crate::factor::FromCurrency;
+use crate::rate_table::RateTable;
+
+/// A source of exchange rates, such as a pricing API, a cache or a static file.
+pub trait RateProvider<Cur: FromCurrency> {
+    /// A short name for the source in logs and [`SourcedRate::source`], e.g. `"cache"`.
+    fn name(&self) -> &str;
+
+    /// The current rate from `from` to `to`.
+    fn rate(&self, from: Cur, to: Cur) -> Result<ExchangeRate<Cur>, RateProviderError>;
+}
+
+/// Why a [`RateProvider`] has no rate.
+#[derive(Debug)]
+pub enum RateProviderError {
+    /// The source has no rate for the pair.
+    NotFound,
+    /// The source could not be queried, e.g. a timeout or an unreadable file.
+    Unavailable(Box<dyn Error + Send + Sync>),
+}
+
+impl RateProviderError {
+    /// A stable snake_case identifier of the error kind, like [`MoneyConversionError::code`].
+    ///
+    /// [`MoneyConversionError::code`]: crate::amount::MoneyConversionError::code
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::NotFound => "rate_not_found",
+            Self::Unavailable(_) => "rate_source_unavailable",
+        }
+    }
+}
+
+impl fmt::Display for RateProviderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotFound => f.write_str("no rate for the currency pair"),
+            Self::Unavailable(error) => write!(f, "rate source unavailable: {error}"),
+        }
+    }
+}
+
+impl Error for RateProviderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::NotFound => None,
+            Self::Unavailable(error) => Some(error.as_ref()),
+        }
+    }
+}
+
+/// Serves the rates in the table, direct quotes or their inverses.
+impl<Cur: FromCurrency> RateProvider<Cur> for RateTable<Cur> {
+    fn name(&self) -> &str {
+        "table"
+    }
+
+    fn rate(&self, from: Cur, to: Cur) -> Result<ExchangeRate<Cur>, RateProviderError> {
+        self.get(from, to).ok_or(RateProviderError::NotFound)
+    }
+}
+
+/// A rate and the name of the provider that served it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourcedRate<Cur: FromCurrency> {
+    pub rate: ExchangeRate<Cur>,
+    pub source: String,
+}
+
+/// Every provider of a [`FallbackProvider`] failed; the errors are in query order.
+#[derive(Debug)]
+pub struct FallbackError {
+    pub failures: Vec<(String, RateProviderError)>,
+}
+
+impl FallbackError {
+    /// `"rate_not_found"` when no provider has the pair, `"rate_source_unavailable"` when any of
+    /// them could not be queried.
+    pub fn code(&self) -> &'static str {
+        if self
+            .failures
+            .iter()
+            .all(|(_, error)| matches!(error, RateProviderError::NotFound))
+        {
+            "rate_not_found"
+        } else {
+            "rate_source_unavailable"
+        }
+    }
+}
+
+impl fmt::Display for FallbackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no rate provider served the rate")?;
+        for (i, (name, error)) in self.failures.iter().enumerate() {
+            write!(f, "{} {name}: {error}", if i == 0 { ":" } else { ";" })?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for FallbackError {}
+
+/// Queries providers in order, e.g. a pricing API, then a cache, then a static file, until
+/// one serves the rate.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::exchange::ExchangeRate;
+/// use amount_conversion::factor::Currency::{self, *};
+/// use amount_conversion::provider::FallbackProvider;
+/// use amount_conversion::rate_table::RateTable;
+///
+/// let mut live = RateTable::new();
+/// live.insert(ExchangeRate::new(USD, INR, 83.1)?);
+/// let mut fallback = RateTable::new();
+/// fallback.insert(ExchangeRate::new(USD, EUR, 0.92)?);
+///
+/// let provider = FallbackProvider::new().with(live).with(fallback);
+/// let served = provider.rate_with_source(EUR, USD).unwrap();
+/// assert_eq!(served.source, "table");
+/// assert_eq!(served.rate.to(), USD);
+/// assert!(provider.rate_with_source(EUR, INR).is_err());
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+/// ```
+pub struct FallbackProvider<Cur: FromCurrency> {
+    providers: Vec<Box<dyn RateProvider<Cur> + Send + Sync>>,
+}
+
+impl<Cur: FromCurrency> FallbackProvider<Cur> {
+    pub fn new() -> Self {
+        Self {
+            providers: Vec::new(),
+        }
+    }
+
+    /// Adds `provider` after the ones already added.
+    pub fn with(mut self, provider: impl RateProvider<Cur> + Send + Sync + 'static) -> Self {
+        self.providers.push(Box::new(provider));
+        self
+    }
+
+    /// The rate from the first provider that serves it, with that provider's name.
+    pub fn rate_with_source(&self, from: Cur, to: Cur) -> Result<SourcedRate<Cur>, FallbackError> {
+        let mut failures = Vec::new();
+        for provider in &self.providers {
+            match provider.rate(from, to) {
+                Ok(rate) => {
+                    return Ok(SourcedRate {
+                        rate,
+                        source: provider.name().to_string(),
+                    })
+                }
+                Err(error) => failures.push((provider.name().to_string(), error)),
+            }
+        }
+        Err(FallbackError { failures })
+    }
+}
+
+impl<Cur: FromCurrency> Default for FallbackProvider<Cur> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Cur: FromCurrency> fmt::Debug for FallbackProvider<Cur> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names: Vec<&str> = self.providers.iter().map(|p| p.name()).collect();
+        f.debug_struct("FallbackProvider")
+            .field("providers", &names)
+            .finish()
+    }
+}
+
+/// Fails with `NotFound` when no provider has the pair, or `Unavailable` with the
+/// [`FallbackError`] when any of them could not be queried.
+impl<Cur: FromCurrency> RateProvider<Cur> for FallbackProvider<Cur> {
+    fn name(&self) -> &str {
+        "fallback"
+    }
+
+    fn rate(&self, from: Cur, to: Cur) -> Result<ExchangeRate<Cur>, RateProviderError> {
+        match self.rate_with_source(from, to) {
+            Ok(served) => Ok(served.rate),
+            Err(error) if error.code() == "rate_not_found" => Err(RateProviderError::NotFound),
+            Err(error) => Err(RateProviderError::Unavailable(Box::new(error))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    struct Down;
+
+    impl RateProvider<Currency> for Down {
+        fn name(&self) -> &str {
+            "api"
+        }
+
+        fn rate(
+            &self,
+            _: Currency,
+            _: Currency,
+        ) -> Result<ExchangeRate<Currency>, RateProviderError> {
+            Err(RateProviderError::Unavailable("timed out".into()))
+        }
+    }
+
+    fn table(rate: ExchangeRate<Currency>) -> RateTable<Currency> {
+        let mut table = RateTable::new();
+        table.insert(rate);
+        table
+    }
+
+    #[test]
+    fn falls_back_in_order() {
+        let rate = ExchangeRate::new(USD, INR, 83.0).unwrap();
+        let provider = FallbackProvider::new().with(Down).with(table(rate));
+        assert_eq!(
+            provider.rate_with_source(USD, INR).unwrap(),
+            SourcedRate {
+                rate,
+                source: "table".to_string()
+            }
+        );
+
+        let error = provider.rate_with_source(USD, EUR).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no rate provider served the rate: api: rate source unavailable: timed out; \
+             table: no rate for the currency pair"
+        );
+        assert!(matches!(
+            provider.rate(USD, EUR),
+            Err(RateProviderError::Unavailable(_))
+        ));
+        assert!(matches!(
+            FallbackProvider::new().with(table(rate)).rate(USD, EUR),
+            Err(RateProviderError::NotFound)
+        ));
+        assert_eq!(
+            format!("{provider:?}"),
+            r#"FallbackProvider { providers: ["api", "table"] }"#
+        );
+    }
+}
@@ -0,0 +1,243 @@
+//! PyO3 bindings, enabled with the `python` feature.
+//!
+//! Exposes `Money` and `Currency` classes backed by the same subunit tables, conversions and
+//! checked arithmetic as the Rust API. Build the extension module with
+//! `maturin develop` (see `pyproject.toml`).
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use pyo3::exceptions::{PyOverflowError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::amount::{HighestUnit, LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::Currency;
+
+impl From<MoneyConversionError<Currency>> for PyErr {
+    fn from(err: MoneyConversionError<Currency>) -> Self {
+        match err {
+            MoneyConversionError::F64ToI32ConversionFailed { .. }
+            | MoneyConversionError::I128ToI32ConversionFailed { .. }
+            | MoneyConversionError::AmountOverflow => PyOverflowError::new_err(err.to_string()),
+            _ => PyValueError::new_err(err.to_string()),
+        }
+    }
+}
+
+/// An ISO 4217 currency, constructed from its code: `Currency("USD")`.
+#[pyclass(
+    name = "Currency",
+    module = "amount_conversion",
+    eq,
+    hash,
+    frozen,
+    from_py_object
+)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PyCurrency(Currency);
+
+#[pymethods]
+impl PyCurrency {
+    #[new]
+    fn new(code: &str) -> PyResult<Self> {
+        code.parse()
+            .map(Self)
+            .map_err(|err| PyValueError::new_err(err.to_string()))
+    }
+
+    /// The ISO 4217 alphabetic code.
+    #[getter]
+    fn code(&self) -> &'static str {
+        self.0.code()
+    }
+
+    fn __str__(&self) -> &'static str {
+        self.0.code()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Currency('{}')", self.0)
+    }
+}
+
+/// Accepts either a `Currency` instance or an ISO 4217 code string.
+#[derive(FromPyObject)]
+enum CurrencyArg {
+    Currency(PyCurrency),
+    Code(String),
+}
+
+impl CurrencyArg {
+    fn resolve(self) -> PyResult<Currency> {
+        match self {
+            Self::Currency(currency) => Ok(currency.0),
+            Self::Code(code) => PyCurrency::new(&code).map(|currency| currency.0),
+        }
+    }
+}
+
+/// Money held in the currency's lowest subunit: `Money(1050, "USD")`.
+#[pyclass(
+    name = "Money",
+    module = "amount_conversion",
+    eq,
+    frozen,
+    from_py_object
+)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct PyMoney(MoneyInner<LowestSubunit, Currency>);
+
+#[pymethods]
+impl PyMoney {
+    #[new]
+    fn new(amount: i32, currency: CurrencyArg) -> PyResult<Self> {
+        let currency = currency.resolve()?;
+        Ok(Self(MoneyInner::<LowestSubunit, _>::new(amount, &currency)))
+    }
+
+    /// Converts a major unit amount, e.g. `10.5` USD, into subunits.
+    #[staticmethod]
+    fn from_major(amount: f64, currency: CurrencyArg) -> PyResult<Self> {
+        let currency = currency.resolve()?;
+        Ok(Self(
+            MoneyInner::<HighestUnit, _>::new(amount, &currency).convert()?,
+        ))
+    }
+
+    /// Parses a major unit decimal string like `"10.50"` exactly, without going through floats.
+    #[staticmethod]
+    fn parse(amount: &str, currency: CurrencyArg) -> PyResult<Self> {
+        let currency = currency.resolve()?;
+        Ok(Self(MoneyInner::from_str_exact(amount, &currency)?))
+    }
+
+    /// The amount in subunits.
+    #[getter]
+    fn amount(&self) -> i32 {
+        self.0.amount
+    }
+
+    #[getter]
+    fn currency(&self) -> PyCurrency {
+        PyCurrency(self.0.currency)
+    }
+
+    /// The amount in major units.
+    fn to_major(&self) -> PyResult<f64> {
+        Ok(self.0.convert()?.amount())
+    }
+
+    /// The amount in major units with exactly the currency's decimals, e.g. `"10.50"`.
+    fn format(&self) -> PyResult<String> {
+        Ok(self.0.format_decimal()?)
+    }
+
+    fn __add__(&self, other: &Self) -> PyResult<Self> {
+        Ok(Self(self.0.checked_add(other.0)?))
+    }
+
+    fn __sub__(&self, other: &Self) -> PyResult<Self> {
+        Ok(Self(self.0.checked_sub(other.0)?))
+    }
+
+    fn __mul__(&self, rhs: i32) -> PyResult<Self> {
+        Ok(Self(self.0.checked_mul(rhs)?))
+    }
+
+    fn __rmul__(&self, lhs: i32) -> PyResult<Self> {
+        self.__mul__(lhs)
+    }
+
+    fn __neg__(&self) -> PyResult<Self> {
+        Ok(Self(self.0.checked_neg()?))
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.0.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn __str__(&self) -> PyResult<String> {
+        Ok(format!("{} {}", self.format()?, self.0.currency))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Money({}, '{}')", self.0.amount, self.0.currency)
+    }
+}
+
+#[pymodule]
+fn amount_conversion(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyCurrency>()?;
+    module.add_class::<PyMoney>()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usd() -> CurrencyArg {
+        CurrencyArg::Code("USD".into())
+    }
+
+    fn money(amount: i32) -> PyMoney {
+        PyMoney::new(amount, usd()).unwrap()
+    }
+
+    #[test]
+    fn constructors() -> PyResult<()> {
+        let bhd = CurrencyArg::Currency(PyCurrency::new("BHD")?);
+        assert_eq!(PyMoney::parse("12.345", bhd)?.amount(), 12_345);
+        assert_eq!(PyMoney::from_major(10.5, usd())?, money(1_050));
+        let jpy = PyMoney::new(12, CurrencyArg::Code("JPY".into()))?;
+        assert_eq!(jpy.currency(), PyCurrency::new("JPY")?);
+        assert_eq!(jpy.currency().code(), "JPY");
+        Ok(())
+    }
+
+    #[test]
+    fn arithmetic_and_formatting() -> PyResult<()> {
+        let price = money(1_050);
+        assert_eq!(price.__add__(&money(25))?, money(1_075));
+        assert_eq!(price.__sub__(&money(2_000))?, money(-950));
+        assert_eq!(price.__mul__(3)?, money(3_150));
+        assert_eq!(price.__rmul__(2)?, money(2_100));
+        assert_eq!(price.__neg__()?, money(-1_050));
+        assert_eq!(price.to_major()?, 10.5);
+        assert_eq!(price.format()?, "10.50");
+        assert_eq!(price.__str__()?, "10.50 USD");
+        assert_eq!(price.__repr__(), "Money(1050, 'USD')");
+        assert_eq!(PyCurrency::new("EUR")?.__repr__(), "Currency('EUR')");
+        Ok(())
+    }
+
+    #[test]
+    fn errors_map_to_python_exceptions() {
+        Python::initialize();
+        Python::attach(|py| {
+            let overflow = money(i32::MAX).__add__(&money(1)).unwrap_err();
+            assert!(overflow.is_instance_of::<PyOverflowError>(py));
+            let overflow = PyMoney::from_major(f64::MAX, usd()).unwrap_err();
+            assert!(overflow.is_instance_of::<PyOverflowError>(py));
+            assert!(money(i32::MIN)
+                .__neg__()
+                .unwrap_err()
+                .is_instance_of::<PyOverflowError>(py));
+
+            let mismatch = money(1)
+                .__add__(&PyMoney::new(1, CurrencyArg::Code("EUR".into())).unwrap())
+                .unwrap_err();
+            assert!(mismatch.is_instance_of::<PyValueError>(py));
+            assert!(!mismatch.is_instance_of::<PyOverflowError>(py));
+            let decimals = PyMoney::parse("1.5", CurrencyArg::Code("JPY".into())).unwrap_err();
+            assert!(decimals.is_instance_of::<PyValueError>(py));
+            assert_eq!(
+                decimals.value(py).to_string(),
+                MoneyConversionError::<Currency>::TooManyDecimals(0).to_string()
+            );
+            let unknown = PyMoney::new(1, CurrencyArg::Code("XXX".into())).unwrap_err();
+            assert!(unknown.is_instance_of::<PyValueError>(py));
+        });
+    }
+}
@@ -0,0 +1,199 @@
+use std::num::NonZeroU32;
+
+use crate::percent::{BasisPoints, BPS_PER_UNIT};
+use crate::rounding::{div_round, RoundingMode};
+
+/// A nominal interest rate per annum, in basis points.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AnnualRate(BasisPoints);
+
+/// An interest rate per period, such as a month, kept as an exact fraction of basis points so
+/// that an annual rate split over the periods of a year loses nothing.
+///
+/// Interest APIs take anything convertible into one; bare integers and [`BasisPoints`] are a
+/// rate per period.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU32;
+///
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::factor::Currency::{self, USD};
+/// use amount_conversion::rate::AnnualRate;
+/// use amount_conversion::rounding::RoundingMode;
+///
+/// type Money = MoneyInner<LowestSubunit, Currency>;
+///
+/// let monthly = AnnualRate::from_percent(5).per_period(NonZeroU32::new(12).unwrap());
+/// assert_eq!(monthly.to_basis_points(RoundingMode::HalfEven).get(), 42);
+///
+/// // 1000.00 USD at 5% a year for a month is 500/12 = 4.1666.. USD
+/// let interest = Money::new(100_000, &USD).simple_interest(monthly, 1, RoundingMode::HalfEven)?;
+/// assert_eq!(interest, Money::new(417, &USD));
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PeriodicRate {
+    bps: i32,
+    divisor: NonZeroU32,
+}
+
+impl AnnualRate {
+    pub const fn new(bps: i32) -> Self {
+        Self(BasisPoints::new(bps))
+    }
+
+    /// A whole percentage per annum, e.g. `from_percent(5)` is 500 bps, panicking out of the
+    /// range of [`BasisPoints::from_percent`].
+    pub const fn from_percent(percent: i32) -> Self {
+        Self(BasisPoints::from_percent(percent))
+    }
+
+    pub const fn basis_points(self) -> BasisPoints {
+        self.0
+    }
+
+    /// The rate per period for a year of `periods_per_year` periods, e.g. 12 for monthly.
+    pub fn per_period(self, periods_per_year: NonZeroU32) -> PeriodicRate {
+        PeriodicRate::fraction(self.0.get(), periods_per_year)
+    }
+}
+
+impl From<BasisPoints> for AnnualRate {
+    fn from(bps: BasisPoints) -> Self {
+        Self(bps)
+    }
+}
+
+impl PeriodicRate {
+    pub fn new(bps: i32) -> Self {
+        Self {
+            bps,
+            divisor: NonZeroU32::new(1).expect("one is non-zero"),
+        }
+    }
+
+    /// `bps / divisor` in lowest terms, so equal rates compare equal.
+    fn fraction(bps: i32, divisor: NonZeroU32) -> Self {
+        let gcd = gcd(bps.unsigned_abs(), divisor.get());
+        Self {
+            // dividing by a common factor keeps both in range
+            bps: (i64::from(bps) / i64::from(gcd)) as i32,
+            divisor: NonZeroU32::new(divisor.get() / gcd).expect("divisor stays non-zero"),
+        }
+    }
+
+    /// The rate in whole basis points per period, rounded with `rounding`.
+    pub fn to_basis_points(self, rounding: RoundingMode) -> BasisPoints {
+        let bps = div_round(
+            i128::from(self.bps),
+            i128::from(self.divisor.get()),
+            rounding,
+        );
+        // never larger in magnitude than `self.bps`
+        BasisPoints::new(bps as i32)
+    }
+
+    /// The nominal annual rate for a year of `periods_per_year` periods, rounded with
+    /// `rounding`; `None` when it exceeds the basis point range.
+    pub fn annualized(
+        self,
+        periods_per_year: NonZeroU32,
+        rounding: RoundingMode,
+    ) -> Option<AnnualRate> {
+        let bps = div_round(
+            i128::from(self.bps) * i128::from(periods_per_year.get()),
+            i128::from(self.divisor.get()),
+            rounding,
+        );
+        i32::try_from(bps).ok().map(AnnualRate::new)
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.bps < 0
+    }
+
+    /// The share of `amount` subunits covered by this rate, before rounding to whole subunits.
+    pub(crate) fn of(self, amount: i128, rounding: RoundingMode) -> i128 {
+        div_round(
+            amount * i128::from(self.bps),
+            BPS_PER_UNIT * i128::from(self.divisor.get()),
+            rounding,
+        )
+    }
+
+    /// The rate as a fraction of one, for float-only formulas.
+    pub(crate) fn as_f64(self) -> f64 {
+        f64::from(self.bps) / BPS_PER_UNIT as f64 / f64::from(self.divisor.get())
+    }
+}
+
+impl From<BasisPoints> for PeriodicRate {
+    fn from(bps: BasisPoints) -> Self {
+        Self::new(bps.get())
+    }
+}
+
+impl From<i32> for PeriodicRate {
+    fn from(bps: i32) -> Self {
+        Self::new(bps)
+    }
+}
+
+fn gcd(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rounding::RoundingMode::*;
+
+    fn months() -> NonZeroU32 {
+        NonZeroU32::new(12).unwrap()
+    }
+
+    #[test]
+    fn conversions_are_exact() {
+        let monthly = AnnualRate::from_percent(12).per_period(months());
+        assert_eq!(monthly, PeriodicRate::new(100));
+        assert_eq!(
+            monthly.annualized(months(), Trunc),
+            Some(AnnualRate::new(1200))
+        );
+
+        let monthly = AnnualRate::new(-500).per_period(months());
+        assert_eq!(
+            monthly,
+            AnnualRate::new(-1000).per_period(NonZeroU32::new(24).unwrap())
+        );
+        assert_eq!(monthly.to_basis_points(Floor), BasisPoints::new(-42));
+        assert_eq!(monthly.to_basis_points(Trunc), BasisPoints::new(-41));
+        assert_eq!(
+            monthly.annualized(months(), Trunc),
+            Some(AnnualRate::new(-500))
+        );
+        assert!(monthly.is_negative());
+
+        assert_eq!(
+            PeriodicRate::new(i32::MAX).annualized(months(), Trunc),
+            None
+        );
+        assert_eq!(
+            AnnualRate::new(i32::MIN).per_period(NonZeroU32::new(2).unwrap()),
+            PeriodicRate::new(i32::MIN / 2)
+        );
+    }
+
+    #[test]
+    fn applies_without_intermediate_rounding() {
+        let monthly = AnnualRate::from_percent(5).per_period(months());
+        // 100000 * 500 / 12 / 10000 = 416.67
+        assert_eq!(monthly.of(100_000, HalfEven), 417);
+        assert_eq!(monthly.of(100_000, Floor), 416);
+    }
+}
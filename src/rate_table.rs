@@ -0,0 +1,203 @@
+use std::time::SystemTime;
+
+use crate::exchange::ExchangeRate;
+use crate::factor::FromCurrency;
+
+/// A set of exchange rates, each usable in both directions, for converting between currencies
+/// without a direct quote.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::exchange::ExchangeRate;
+/// use amount_conversion::factor::Currency::{self, *};
+/// use amount_conversion::rate_table::RateTable;
+///
+/// let mut table = RateTable::new();
+/// table.insert(ExchangeRate::with_spread(USD, INR, 83.0, 83.5)?);
+/// table.insert(ExchangeRate::with_spread(EUR, USD, 1.08, 1.09)?);
+/// table.insert(ExchangeRate::with_spread(EUR, INR, 88.0, 92.0)?);
+///
+/// // through USD is cheaper than the wide direct EUR/INR quote
+/// let best = table.find_path(EUR, INR, 2).unwrap();
+/// assert_eq!(best.path, [EUR, USD, INR]);
+/// assert!(best.spread < 0.02);
+/// assert_eq!(table.find_path(EUR, INR, 1).unwrap().path, [EUR, INR]);
+/// assert!(table.find_path(EUR, JPY, 3).is_none());
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct RateTable<Cur: FromCurrency> {
+    /// In insertion order, which breaks ties between equally cheap paths.
+    rates: Vec<ExchangeRate<Cur>>,
+    timestamp: Option<SystemTime>,
+}
+
+/// A conversion found by [`RateTable::find_path`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct RatePath<Cur: FromCurrency> {
+    /// The composed rate from the first currency of the path to the last.
+    pub rate: ExchangeRate<Cur>,
+    /// Every currency converted through, both ends included.
+    pub path: Vec<Cur>,
+    /// The cumulative spread of the composed rate, `(ask - bid) / mid`.
+    pub spread: f64,
+}
+
+impl<Cur: FromCurrency> RateTable<Cur> {
+    pub fn new() -> Self {
+        Self {
+            rates: Vec::new(),
+            timestamp: None,
+        }
+    }
+
+    /// Records when the rates were taken, as written to snapshot files.
+    pub fn with_timestamp(mut self, timestamp: SystemTime) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn timestamp(&self) -> Option<SystemTime> {
+        self.timestamp
+    }
+
+    /// Every rate in the table, in insertion order.
+    pub fn rates(&self) -> &[ExchangeRate<Cur>] {
+        &self.rates
+    }
+
+    /// Adds `rate`, replacing any rate between the same two currencies in either direction.
+    pub fn insert(&mut self, rate: ExchangeRate<Cur>) {
+        let pair = |r: &ExchangeRate<Cur>| {
+            (r.from() == rate.from() && r.to() == rate.to())
+                || (r.from() == rate.to() && r.to() == rate.from())
+        };
+        match self.rates.iter_mut().find(|r| pair(r)) {
+            Some(known) => *known = rate,
+            None => self.rates.push(rate),
+        }
+    }
+
+    /// The rate from `from` to `to` if the table has one in either direction.
+    pub fn get(&self, from: Cur, to: Cur) -> Option<ExchangeRate<Cur>> {
+        self.rates_from(from).find(|rate| rate.to() == to)
+    }
+
+    /// The conversion from `from` to `to` through at most `max_hops` rates with the smallest
+    /// cumulative spread, preferring fewer hops among equally cheap paths.
+    ///
+    /// Returns `None` when no path is short enough; converting a currency into itself needs no
+    /// rate and is never found.
+    pub fn find_path(&self, from: Cur, to: Cur, max_hops: usize) -> Option<RatePath<Cur>> {
+        // the cheapest path to each currency with exactly as many hops as rounds so far, where
+        // the cost ln(ask / bid) adds up along a path
+        let mut frontier: Vec<(f64, Vec<Cur>)> = vec![(0.0, vec![from])];
+        let mut best: Option<(f64, Vec<Cur>)> = None;
+        for _ in 0..max_hops {
+            let mut next: Vec<(f64, Vec<Cur>)> = Vec::new();
+            for (cost, path) in &frontier {
+                let last = *path.last().expect("paths are never empty");
+                for rate in self.rates_from(last) {
+                    if path.contains(&rate.to()) {
+                        continue;
+                    }
+                    let cost = cost + (rate.ask() / rate.bid()).ln();
+                    let mut extended = path.clone();
+                    extended.push(rate.to());
+                    match next
+                        .iter_mut()
+                        .find(|(_, known)| known.last() == extended.last())
+                    {
+                        Some(known) if cost < known.0 => *known = (cost, extended),
+                        Some(_) => {}
+                        None => next.push((cost, extended)),
+                    }
+                }
+            }
+            if let Some(found) = next.iter().find(|(_, path)| path.last() == Some(&to)) {
+                if best.as_ref().map_or(true, |(known, _)| found.0 < *known) {
+                    best = Some(found.clone());
+                }
+            }
+            next.retain(|(_, path)| path.last() != Some(&to));
+            frontier = next;
+        }
+        let (_, path) = best?;
+        let mut legs = path.windows(2).map(|pair| self.get(pair[0], pair[1]));
+        let mut rate = legs.next()??;
+        for leg in legs {
+            rate = rate.compose(&leg?).ok()?;
+        }
+        Some(RatePath {
+            spread: (rate.ask() - rate.bid()) / rate.rate(),
+            rate,
+            path,
+        })
+    }
+
+    /// Every rate out of `currency`, inverting those quoted into it.
+    fn rates_from(&self, currency: Cur) -> impl Iterator<Item = ExchangeRate<Cur>> + '_ {
+        self.rates.iter().filter_map(move |rate| {
+            if rate.from() == currency {
+                Some(*rate)
+            } else if rate.to() == currency {
+                Some(rate.inverse())
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<Cur: FromCurrency> Default for RateTable<Cur> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    fn table(quotes: &[(Currency, Currency, f64, f64)]) -> RateTable<Currency> {
+        let mut table = RateTable::new();
+        for &(from, to, bid, ask) in quotes {
+            table.insert(ExchangeRate::with_spread(from, to, bid, ask).unwrap());
+        }
+        table
+    }
+
+    #[test]
+    fn composes_inverted_legs() {
+        let table = table(&[(USD, JPY, 150.0, 150.0), (GBP, USD, 1.25, 1.25)]);
+        let path = table.find_path(JPY, GBP, 3).unwrap();
+        assert_eq!(path.path, [JPY, USD, GBP]);
+        assert_eq!(path.rate.from(), JPY);
+        assert_eq!(path.rate.to(), GBP);
+        assert!((path.rate.rate() - 1.0 / 187.5).abs() < 1e-15);
+        assert_eq!(path.spread, 0.0);
+        assert!(table.find_path(JPY, GBP, 1).is_none());
+        assert!(table.find_path(JPY, JPY, 3).is_none());
+    }
+
+    #[test]
+    fn prefers_fewer_hops_on_ties() {
+        let table = table(&[
+            (USD, EUR, 0.9, 0.9),
+            (USD, GBP, 0.8, 0.8),
+            (GBP, EUR, 1.125, 1.125),
+        ]);
+        assert_eq!(table.find_path(USD, EUR, 3).unwrap().path, [USD, EUR]);
+    }
+
+    #[test]
+    fn insert_replaces_either_direction() {
+        let mut table = table(&[(USD, INR, 80.0, 80.0)]);
+        table.insert(ExchangeRate::new(INR, USD, 0.0125).unwrap());
+        assert_eq!(table.get(INR, USD).unwrap().rate(), 0.0125);
+        assert_eq!(table.get(USD, INR).unwrap().rate(), 80.0);
+        assert_eq!(table.rates.len(), 1);
+    }
+}
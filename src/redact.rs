@@ -0,0 +1,114 @@
+use std::fmt;
+
+use crate::amount::MoneyInner;
+use crate::factor::{get_exponent, FromCurrency};
+
+/// Wraps an amount so `Debug` and `Display` show only its currency, e.g. `USD ****.**`.
+///
+/// Use it for fields that end up in logs of PCI-scoped services. The mask has as many decimals
+/// as the currency, so `JPY ****` and `KWD ****.***`; the amount itself stays reachable through
+/// [`Redacted::into_inner`].
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::factor::Currency::{self, JPY, USD};
+///
+/// type Money = MoneyInner<LowestSubunit, Currency>;
+///
+/// let charge = Money::new(1_999, &USD).redacted();
+/// assert_eq!(charge.to_string(), "USD ****.**");
+/// assert_eq!(format!("{charge:?}"), "Redacted(USD ****.**)");
+/// assert_eq!(Money::new(500, &JPY).redacted().to_string(), "JPY ****");
+/// assert_eq!(charge.into_inner(), Money::new(1_999, &USD));
+/// ```
+#[derive(Copy, Clone, Eq, Hash, PartialEq)]
+pub struct Redacted<T>(T);
+
+impl<T> Redacted<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<Amt, Cur: FromCurrency> MoneyInner<Amt, Cur> {
+    /// Wraps the amount in [`Redacted`] for logging.
+    pub fn redacted(self) -> Redacted<Self> {
+        Redacted(self)
+    }
+}
+
+impl<Amt, Cur: FromCurrency> From<MoneyInner<Amt, Cur>> for Redacted<MoneyInner<Amt, Cur>> {
+    fn from(money: MoneyInner<Amt, Cur>) -> Self {
+        Self(money)
+    }
+}
+
+impl<Amt, Cur: FromCurrency> fmt::Display for Redacted<MoneyInner<Amt, Cur>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ****", self.0.currency.currency_code())?;
+        // currencies missing from the subunit map get no decimals rather than an error
+        match get_exponent(&self.0) {
+            Ok(0) | Err(_) => Ok(()),
+            Ok(exponent) => write!(f, ".{}", "*".repeat(exponent as usize)),
+        }
+    }
+}
+
+impl<Amt, Cur: FromCurrency> fmt::Debug for Redacted<MoneyInner<Amt, Cur>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Redacted({self})")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amount::{HighestUnit, LowestSubunit};
+    use crate::factor::Currency::{self, *};
+    use crate::factor::CurrencyCode;
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn masks_by_exponent() {
+        assert_eq!(Money::new(-5, &EUR).redacted().to_string(), "EUR ****.**");
+        assert_eq!(Money::new(5, &KWD).redacted().to_string(), "KWD ****.***");
+        assert_eq!(
+            MoneyInner::<HighestUnit, Currency>::new(12.5, &USD)
+                .redacted()
+                .to_string(),
+            "USD ****.**"
+        );
+        let points = CurrencyCode::register("RDP", USD, 0).unwrap();
+        assert_eq!(
+            MoneyInner::<LowestSubunit, _>::new(1_250, &points)
+                .redacted()
+                .to_string(),
+            "RDP ****"
+        );
+    }
+
+    #[test]
+    fn debug_hides_amount() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Charge {
+            id: u32,
+            amount: Redacted<Money>,
+        }
+        let charge = Charge {
+            id: 7,
+            amount: Money::new(123_456, &INR).into(),
+        };
+        assert_eq!(
+            format!("{charge:?}"),
+            "Charge { id: 7, amount: Redacted(INR ****.**) }"
+        );
+    }
+}
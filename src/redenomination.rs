@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::num::NonZeroU64;
+
+use crate::amount::{i128_to_i32, LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::{get_factor, FromCurrency};
+use crate::rounding::{div_round, RoundingMode};
+
+/// Redenominations of currencies, each replacing an old currency by a new one at a fixed
+/// divisor, e.g. 1,000 old leones for one new leone.
+///
+/// Archival amounts are brought forward through every redenomination that followed them with
+/// integer math, rounding only once at the end.
+///
+/// # Examples
+///
+/// ```
+/// use std::num::NonZeroU64;
+///
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::factor::{Currency, FromCurrency};
+/// use amount_conversion::redenomination::Redenominations;
+/// use amount_conversion::rounding::RoundingMode;
+///
+/// #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+/// enum Leone {
+///     Old,
+///     New,
+/// }
+///
+/// impl FromCurrency for Leone {
+///     fn currency(&self) -> Currency {
+///         // both leones have 100 subunits
+///         Currency::SLL
+///     }
+/// }
+///
+/// let redenominations =
+///     Redenominations::new().with(Leone::Old, Leone::New, NonZeroU64::new(1_000).unwrap());
+/// let archived = MoneyInner::<LowestSubunit, _>::new(1_234_567, &Leone::Old);
+/// assert_eq!(
+///     redenominations.redenominate(archived, RoundingMode::HalfUp)?,
+///     MoneyInner::<LowestSubunit, _>::new(1_235, &Leone::New)
+/// );
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<Leone>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Redenominations<Cur: FromCurrency> {
+    steps: HashMap<Cur, (Cur, NonZeroU64)>,
+}
+
+impl<Cur: FromCurrency> Redenominations<Cur> {
+    pub fn new() -> Self {
+        Self {
+            steps: HashMap::new(),
+        }
+    }
+
+    /// Registers that `divisor` major units of `from` became one major unit of `to`, replacing
+    /// any earlier redenomination of `from`.
+    pub fn with(mut self, from: Cur, to: Cur, divisor: NonZeroU64) -> Self {
+        self.steps.insert(from, (to, divisor));
+        self
+    }
+
+    /// The currency `from` was redenominated into, with the divisor, if any.
+    pub fn step(&self, from: &Cur) -> Option<(Cur, NonZeroU64)> {
+        self.steps.get(from).copied()
+    }
+
+    /// Brings `money` forward into the latest currency it was redenominated into, rounding to
+    /// whole subunits with `rounding`. Amounts in currencies without redenominations are
+    /// returned unchanged.
+    ///
+    /// Fails with `RedenominationCycle` when the redenominations lead back to a currency already
+    /// passed, with `AmountOverflow` when the chain's factors overflow, or with
+    /// `I128ToI32ConversionFailed` when the result does not fit.
+    pub fn redenominate(
+        &self,
+        money: MoneyInner<LowestSubunit, Cur>,
+        rounding: RoundingMode,
+    ) -> Result<MoneyInner<LowestSubunit, Cur>, MoneyConversionError<Cur>> {
+        let mut currency = money.currency;
+        let mut numerator = i128::from(money.amount);
+        let mut denominator = 1_i128;
+        let mut hops = 0;
+        while let Some((to, divisor)) = self.step(&currency) {
+            // an acyclic chain uses every step at most once
+            if hops == self.steps.len() {
+                return Err(MoneyConversionError::RedenominationCycle(currency));
+            }
+            numerator = numerator
+                .checked_mul(factor(&to)?)
+                .ok_or(MoneyConversionError::AmountOverflow)?;
+            denominator = denominator
+                .checked_mul(factor(&currency)?)
+                .and_then(|d| d.checked_mul(i128::from(divisor.get())))
+                .ok_or(MoneyConversionError::AmountOverflow)?;
+            currency = to;
+            hops += 1;
+        }
+        let amount = div_round(numerator, denominator, rounding);
+        let amount = i128_to_i32(amount, currency)?;
+        Ok(MoneyInner::<LowestSubunit, _>::new(amount, &currency))
+    }
+}
+
+impl<Cur: FromCurrency> Default for Redenominations<Cur> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn factor<Cur: FromCurrency>(currency: &Cur) -> Result<i128, MoneyConversionError<Cur>> {
+    let money = MoneyInner {
+        amount: (),
+        currency: *currency,
+    };
+    Ok(get_factor(&money)? as i128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    fn divisor(divisor: u64) -> NonZeroU64 {
+        NonZeroU64::new(divisor).unwrap()
+    }
+
+    #[test]
+    fn chains_round_once() -> Result<(), MoneyConversionError<Currency>> {
+        // fictional redenominations, chosen to mix exponents
+        let redenominations =
+            Redenominations::new()
+                .with(SLL, JPY, divisor(1_000))
+                .with(JPY, KWD, divisor(10));
+        assert_eq!(
+            redenominations.redenominate(Money::new(1_999_999, &SLL), RoundingMode::Floor)?,
+            Money::new(1_999, &KWD)
+        );
+        // per-step rounding would give 1 yen and then 0.100 KWD
+        assert_eq!(
+            redenominations.redenominate(Money::new(149_999, &SLL), RoundingMode::HalfUp)?,
+            Money::new(150, &KWD)
+        );
+        assert_eq!(
+            redenominations.redenominate(Money::new(-5, &USD), RoundingMode::HalfUp)?,
+            Money::new(-5, &USD)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_cycles() {
+        let redenominations =
+            Redenominations::new()
+                .with(SLL, ZAR, divisor(10))
+                .with(ZAR, SLL, divisor(10));
+        assert_eq!(
+            redenominations.redenominate(Money::new(100, &SLL), RoundingMode::HalfUp),
+            Err(MoneyConversionError::RedenominationCycle(SLL))
+        );
+        assert_eq!(redenominations.step(&ZAR), Some((SLL, divisor(10))));
+    }
+}
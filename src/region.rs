@@ -0,0 +1,108 @@
+//! Groupings of currencies for routing and compliance rules: the world region a currency is
+//! issued in, EU membership, and tags registered at runtime.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::factor::Currency::{self, *};
+//! use amount_conversion::region::Region;
+//!
+//! assert_eq!(BRL.region(), Region::Americas);
+//! assert_eq!(INR.region(), Region::Apac);
+//! assert!(PLN.is_eu_member_currency());
+//! assert!(!CHF.is_eu_member_currency());
+//!
+//! Currency::register_tag("enhanced-due-diligence", [ARS, NGN]);
+//! assert!(NGN.has_tag("enhanced-due-diligence"));
+//! assert_eq!(Currency::tagged("enhanced-due-diligence"), [ARS, NGN]);
+//! ```
+use once_cell::sync::Lazy;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::RwLock;
+
+use crate::factor::Currency::{self, *};
+
+/// Custom groupings registered with `Currency::register_tag`.
+static TAGS: Lazy<RwLock<HashMap<String, BTreeSet<Currency>>>> = Lazy::new(Default::default);
+
+/// The world region a currency is issued in, as returned by [`Currency::region`].
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Region {
+    /// North, Central and South America and the Caribbean.
+    Americas,
+    /// Europe, the Middle East and Africa.
+    Emea,
+    /// Asia and the Pacific, Central and South Asia included.
+    Apac,
+}
+
+impl Currency {
+    /// The region of the issuing country or monetary union. Currencies used across regions
+    /// belong to their issuer's, e.g. USD to the Americas and XPF, issued for the French
+    /// Pacific territories, to APAC.
+    pub fn region(&self) -> Region {
+        match self {
+            ANG | ARS | AWG | BBD | BMD | BOB | BRL | BSD | BZD | CAD | CLP | COP | CRC | CUP
+            | DOP | GTQ | GYD | HNL | HTG | JMD | KYD | MXN | NIO | PEN | PYG | SVC | TTD | USD
+            | UYU => Region::Americas,
+            AED | ALL | AMD | AZN | BHD | BIF | BWP | CHF | CZK | DJF | DKK | DZD | EGP | ETB
+            | EUR | GBP | GHS | GIP | GMD | GNF | HRK | HUF | ILS | JOD | KES | KMF | KWD | LBP
+            | LRD | LSL | MAD | MDL | MGA | MKD | MUR | MWK | NAD | NGN | NOK | OMR | PLN | QAR
+            | RUB | RWF | SAR | SCR | SEK | SLL | SOS | SSP | SZL | TND | TZS | UGX | XAF | XOF
+            | YER | ZAR => Region::Emea,
+            AUD | BDT | BND | CNY | FJD | HKD | IDR | INR | JPY | KGS | KHR | KRW | KZT | LAK
+            | LKR | MMK | MNT | MOP | MVR | MYR | NPR | NZD | PGK | PHP | PKR | SGD | THB | TWD
+            | UZS | VND | VUV | XPF => Region::Apac,
+        }
+    }
+
+    /// Whether the currency is the euro or the current currency of an EU member state, e.g.
+    /// for SEPA and EU reporting rules. HRK is not, Croatia having adopted the euro.
+    pub fn is_eu_member_currency(&self) -> bool {
+        matches!(self, EUR | CZK | DKK | HUF | PLN | SEK)
+    }
+
+    /// Adds `currencies` to the group named `tag` process-wide, keeping those added before.
+    pub fn register_tag(tag: &str, currencies: impl IntoIterator<Item = Self>) {
+        let mut tags = TAGS.write().unwrap_or_else(|e| e.into_inner());
+        tags.entry(tag.to_string()).or_default().extend(currencies);
+    }
+
+    /// Whether the currency was registered under `tag`.
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tags = TAGS.read().unwrap_or_else(|e| e.into_inner());
+        tags.get(tag)
+            .map_or(false, |currencies| currencies.contains(self))
+    }
+
+    /// The currencies registered under `tag`, in code order.
+    pub fn tagged(tag: &str) -> Vec<Self> {
+        let tags = TAGS.read().unwrap_or_else(|e| e.into_inner());
+        tags.get(tag)
+            .map_or_else(Vec::new, |currencies| currencies.iter().copied().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regions() {
+        assert_eq!(USD.region(), Region::Americas);
+        assert_eq!(XOF.region(), Region::Emea);
+        assert_eq!(KZT.region(), Region::Apac);
+        assert!(!HRK.is_eu_member_currency());
+    }
+
+    #[test]
+    fn tags_accumulate() {
+        assert_eq!(Currency::tagged("region-test"), []);
+        Currency::register_tag("region-test", [USD]);
+        Currency::register_tag("region-test", [EUR, USD]);
+        assert_eq!(Currency::tagged("region-test"), [EUR, USD]);
+        assert!(EUR.has_tag("region-test"));
+        assert!(!EUR.has_tag("Region-Test"));
+        assert!(!GBP.has_tag("region-test"));
+    }
+}
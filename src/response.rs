@@ -0,0 +1,40 @@
+use serde::Serialize;
+
+use crate::amount::MoneyConversionError;
+use crate::factor::{FromCurrency, ParseCurrencyError};
+use crate::limits::LimitViolation;
+
+/// The JSON body returned by the web framework integrations, e.g.
+/// `{"code": "currency_mismatch", "message": "..."}`.
+#[derive(Debug, Serialize)]
+pub(crate) struct ErrorBody {
+    pub(crate) code: &'static str,
+    pub(crate) message: String,
+}
+
+impl<T: std::fmt::Debug> From<&MoneyConversionError<T>> for ErrorBody {
+    fn from(err: &MoneyConversionError<T>) -> Self {
+        Self {
+            code: err.code(),
+            message: err.to_string(),
+        }
+    }
+}
+
+impl From<&ParseCurrencyError> for ErrorBody {
+    fn from(err: &ParseCurrencyError) -> Self {
+        Self {
+            code: "unknown_currency",
+            message: err.to_string(),
+        }
+    }
+}
+
+impl<Cur: FromCurrency> From<&LimitViolation<Cur>> for ErrorBody {
+    fn from(violation: &LimitViolation<Cur>) -> Self {
+        Self {
+            code: violation.code(),
+            message: violation.to_string(),
+        }
+    }
+}
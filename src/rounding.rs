@@ -0,0 +1,248 @@
+use std::sync::RwLock;
+
+use crate::amount::SUBUNIT_EPSILON;
+use crate::factor::Currency;
+
+/// How to round a result that falls between two representable subunits.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub enum RoundingMode {
+    /// Round to the nearest subunit, ties go to the even neighbour (banker's rounding).
+    #[default]
+    HalfEven,
+    /// Round to the nearest subunit, ties go away from zero.
+    HalfUp,
+    /// Round to the nearest subunit, ties go toward zero.
+    HalfDown,
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero, dropping the fraction.
+    Trunc,
+}
+
+/// Defaults registered with `Currency::register_rounding`, indexed by currency.
+static DEFAULT_ROUNDING: RwLock<[Option<CurrencyRounding>; Currency::COUNT]> =
+    RwLock::new([None; Currency::COUNT]);
+
+/// A currency's default rounding: a mode and the increment, in subunits, amounts round to.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{HighestUnit, LowestSubunit, MoneyInner};
+/// use amount_conversion::factor::Currency::{self, CHF};
+/// use amount_conversion::rounding::{CurrencyRounding, RoundingMode};
+///
+/// // Swiss francs settle in multiples of 5 centimes
+/// Currency::register_rounding(CHF, CurrencyRounding::new(RoundingMode::HalfUp).with_increment(5));
+/// let money = MoneyInner::<HighestUnit, _>::new(10.126, &CHF).convert::<LowestSubunit>()?;
+/// assert_eq!(money.amount(), 1015);
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CurrencyRounding {
+    mode: RoundingMode,
+    increment: u32,
+}
+
+impl CurrencyRounding {
+    /// Rounds to whole subunits with `mode`.
+    pub fn new(mode: RoundingMode) -> Self {
+        Self { mode, increment: 1 }
+    }
+
+    /// Rounds to multiples of `increment` subunits instead, e.g. `5` for 0.05 CHF; `0` is
+    /// taken as `1`.
+    pub fn with_increment(self, increment: u32) -> Self {
+        Self {
+            increment: increment.max(1),
+            ..self
+        }
+    }
+
+    pub fn mode(&self) -> RoundingMode {
+        self.mode
+    }
+
+    pub fn increment(&self) -> u32 {
+        self.increment
+    }
+
+    /// Rounds `subunits`, taking products that are whole up to float noise as that subunit.
+    pub(crate) fn round(self, subunits: f64) -> f64 {
+        let subunits = snap_to_whole(subunits);
+        let increment = f64::from(self.increment);
+        round_f64(subunits / increment, self.mode) * increment
+    }
+}
+
+impl From<RoundingMode> for CurrencyRounding {
+    fn from(mode: RoundingMode) -> Self {
+        Self::new(mode)
+    }
+}
+
+impl Currency {
+    /// Sets the rounding that narrowing major units to subunits of `currency` uses when no mode
+    /// is given, process-wide, replacing any earlier one.
+    ///
+    /// It applies to `MoneyInner::<HighestUnit, _>::convert`, its `TryFrom` equivalent and
+    /// `ExchangeRate::convert`; currencies without one round half to even, and registering
+    /// [`RoundingMode::Trunc`] restores the truncation toward zero of earlier versions.
+    pub fn register_rounding(currency: Self, rounding: impl Into<CurrencyRounding>) {
+        let mut defaults = DEFAULT_ROUNDING.write().unwrap_or_else(|e| e.into_inner());
+        defaults[currency.index()] = Some(rounding.into());
+    }
+
+    /// The rounding registered for the currency with [`Currency::register_rounding`].
+    pub fn default_rounding(&self) -> Option<CurrencyRounding> {
+        let defaults = DEFAULT_ROUNDING.read().unwrap_or_else(|e| e.into_inner());
+        defaults[self.index()]
+    }
+}
+
+/// Divides `numerator` by a non-zero `denominator` with exact integer math and `mode` rounding.
+pub(crate) fn div_round(numerator: i128, denominator: i128, mode: RoundingMode) -> i128 {
+    let (numerator, denominator) = if denominator < 0 {
+        (-numerator, -denominator)
+    } else {
+        (numerator, denominator)
+    };
+    let quotient = numerator / denominator;
+    let remainder = numerator % denominator;
+    if remainder == 0 {
+        return quotient;
+    }
+    let away = quotient + numerator.signum();
+    let twice_remainder = remainder.abs() * 2;
+    match mode {
+        RoundingMode::Trunc => quotient,
+        RoundingMode::Floor if numerator < 0 => away,
+        RoundingMode::Ceil if numerator > 0 => away,
+        RoundingMode::Floor | RoundingMode::Ceil => quotient,
+        _ if twice_remainder > denominator => away,
+        _ if twice_remainder < denominator => quotient,
+        RoundingMode::HalfUp => away,
+        RoundingMode::HalfDown => quotient,
+        RoundingMode::HalfEven if quotient % 2 == 0 => quotient,
+        RoundingMode::HalfEven => away,
+    }
+}
+
+/// How far `subunits` may be from a whole number and still be float noise: [`SUBUNIT_EPSILON`],
+/// or a few ULPs of `subunits` once those are larger, as for amounts above about 1e10 major
+/// units, where a product such as `10000039595.05 * 100` lands a ULP short of a whole number.
+pub(crate) fn noise_tolerance(subunits: f64) -> f64 {
+    SUBUNIT_EPSILON.max(subunits.abs() * f64::EPSILON * 4.0)
+}
+
+/// `subunits` snapped to the nearest whole number when only float noise away from it, e.g.
+/// `100.0 * 0.29`, so directional rounding does not lose or gain a subunit.
+pub(crate) fn snap_to_whole(subunits: f64) -> f64 {
+    let nearest = subunits.round();
+    if (subunits - nearest).abs() < noise_tolerance(subunits) {
+        nearest
+    } else {
+        subunits
+    }
+}
+
+/// Rounds a finite `value` to a whole number with `mode`, for results only available as f64.
+pub(crate) fn round_f64(value: f64, mode: RoundingMode) -> f64 {
+    let quotient = value.trunc();
+    let fraction = (value - quotient).abs();
+    if fraction == 0.0 {
+        return value;
+    }
+    let away = quotient + value.signum();
+    match mode {
+        RoundingMode::Trunc => quotient,
+        RoundingMode::Floor => value.floor(),
+        RoundingMode::Ceil => value.ceil(),
+        _ if fraction > 0.5 => away,
+        _ if fraction < 0.5 => quotient,
+        RoundingMode::HalfUp => away,
+        RoundingMode::HalfDown => quotient,
+        RoundingMode::HalfEven if quotient % 2.0 == 0.0 => quotient,
+        RoundingMode::HalfEven => away,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RoundingMode::*;
+    use super::*;
+
+    #[test]
+    fn div_round_modes() {
+        // 2.5, -2.5, 2.4, -2.6, 3.5
+        let cases = [(25, 10), (-25, 10), (24, 10), (-26, 10), (35, 10)];
+        let expected = [
+            (HalfEven, [2, -2, 2, -3, 4]),
+            (HalfUp, [3, -3, 2, -3, 4]),
+            (HalfDown, [2, -2, 2, -3, 3]),
+            (Floor, [2, -3, 2, -3, 3]),
+            (Ceil, [3, -2, 3, -2, 4]),
+            (Trunc, [2, -2, 2, -2, 3]),
+        ];
+        for (mode, results) in expected {
+            for ((numerator, denominator), result) in cases.into_iter().zip(results) {
+                assert_eq!(
+                    div_round(numerator, denominator, mode),
+                    result,
+                    "{numerator}/{denominator} {mode:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn round_f64_modes() {
+        let values = [2.5, -2.5, 2.4, -2.6, 3.5, 3.0];
+        let expected = [
+            (HalfEven, [2.0, -2.0, 2.0, -3.0, 4.0, 3.0]),
+            (HalfUp, [3.0, -3.0, 2.0, -3.0, 4.0, 3.0]),
+            (HalfDown, [2.0, -2.0, 2.0, -3.0, 3.0, 3.0]),
+            (Floor, [2.0, -3.0, 2.0, -3.0, 3.0, 3.0]),
+            (Ceil, [3.0, -2.0, 3.0, -2.0, 4.0, 3.0]),
+            (Trunc, [2.0, -2.0, 2.0, -2.0, 3.0, 3.0]),
+        ];
+        for (mode, results) in expected {
+            for (value, result) in values.into_iter().zip(results) {
+                assert_eq!(round_f64(value, mode), result, "{value} {mode:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn registered_defaults() {
+        use crate::amount::{HighestUnit, LowestSubunit, MoneyInner};
+        use crate::factor::Currency::SEK;
+
+        let convert = |amount| {
+            MoneyInner::<HighestUnit, _>::new(amount, &SEK)
+                .convert::<LowestSubunit>()
+                .map(|money| money.amount())
+        };
+        assert_eq!(SEK.default_rounding(), None);
+        assert_eq!(convert(12.348), Ok(1235));
+        assert_eq!(convert(10.999_999_999), Ok(1100));
+        Currency::register_rounding(SEK, CurrencyRounding::new(HalfUp).with_increment(100));
+        assert_eq!(convert(12.348), Ok(1200));
+        assert_eq!(convert(-12.5), Ok(-1300));
+        Currency::register_rounding(SEK, Ceil);
+        assert_eq!(convert(12.341), Ok(1235));
+        assert_eq!(convert(0.29), Ok(29));
+        assert_eq!(SEK.default_rounding().map(|r| r.increment()), Some(1));
+        Currency::register_rounding(SEK, Trunc);
+        assert_eq!(convert(12.348), Ok(1234));
+    }
+
+    #[test]
+    fn div_round_exact_and_negative_denominator() {
+        assert_eq!(div_round(30, 10, HalfEven), 3);
+        assert_eq!(div_round(25, -10, Floor), -3);
+        assert_eq!(div_round(-25, -10, HalfEven), 2);
+    }
+}
@@ -0,0 +1,161 @@
+//! `rusty_money` integration, enabled with the `rusty-money` feature.
+//!
+//! Money converts to `rusty_money::Money` with an ISO currency and back through `TryFrom`. Amounts
+//! go through `rust_decimal` exactly: subunits become a decimal with the currency's exponent, and
+//! a decimal with more places than the exponent fails with `TooManyDecimals` instead of rounding.
+//! Currencies missing from the other crate fail with [`RustyMoneyError::UnknownCurrency`].
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{LowestSubunit, MoneyInner};
+//! use amount_conversion::factor::Currency::{self, USD};
+//! use rusty_money::{iso, FormattableCurrency, Money};
+//!
+//! type Ours = MoneyInner<LowestSubunit, Currency>;
+//!
+//! let theirs = Money::try_from(Ours::new(1_999, &USD))?;
+//! assert_eq!(theirs, Money::from_minor(1_999, iso::USD));
+//!
+//! let ours = Ours::try_from(Money::from_str("12.34", iso::USD).unwrap())?;
+//! assert_eq!(ours, Ours::new(1_234, &USD));
+//! assert!(Ours::try_from(Money::from_str("12.345", iso::USD).unwrap()).is_err());
+//! # Ok::<(), amount_conversion::rusty_money_support::RustyMoneyError<Currency>>(())
+//! ```
+use std::fmt;
+
+use rust_decimal::Decimal;
+use rusty_money::{iso, FormattableCurrency, Money};
+
+use crate::amount::{MoneyConversionError, MoneyInner};
+use crate::backend::AmountBackend;
+use crate::factor::{get_exponent, Currency, FromCurrency};
+
+/// A failed conversion to or from `rusty_money`.
+#[derive(Debug, PartialEq)]
+pub enum RustyMoneyError<Cur> {
+    /// The currency with this code exists in only one of the crates.
+    UnknownCurrency(String),
+    /// The amount does not fit, or has more decimals than the currency allows.
+    Amount(MoneyConversionError<Cur>),
+}
+
+impl<Cur> From<MoneyConversionError<Cur>> for RustyMoneyError<Cur> {
+    fn from(error: MoneyConversionError<Cur>) -> Self {
+        Self::Amount(error)
+    }
+}
+
+impl<Cur: fmt::Debug> fmt::Display for RustyMoneyError<Cur> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCurrency(code) => write!(f, "currency {code} is not supported"),
+            Self::Amount(error) => error.fmt(f),
+        }
+    }
+}
+
+impl<Cur: fmt::Debug> std::error::Error for RustyMoneyError<Cur> {}
+
+impl TryFrom<Currency> for &'static iso::Currency {
+    type Error = RustyMoneyError<Currency>;
+
+    fn try_from(currency: Currency) -> Result<Self, Self::Error> {
+        iso::find(currency.code())
+            .ok_or_else(|| RustyMoneyError::UnknownCurrency(currency.code().to_string()))
+    }
+}
+
+impl TryFrom<&iso::Currency> for Currency {
+    type Error = RustyMoneyError<Currency>;
+
+    fn try_from(currency: &iso::Currency) -> Result<Self, Self::Error> {
+        Currency::try_from_code(currency.code())
+            .ok_or_else(|| RustyMoneyError::UnknownCurrency(currency.code().to_string()))
+    }
+}
+
+/// The amount in major units, with the exponent of the money's currency, overrides included.
+impl<B: AmountBackend, Cur: FromCurrency> TryFrom<MoneyInner<B, Cur>>
+    for Money<'static, iso::Currency>
+{
+    type Error = RustyMoneyError<Cur>;
+
+    fn try_from(money: MoneyInner<B, Cur>) -> Result<Self, Self::Error> {
+        let code = money.currency.currency_code();
+        let currency =
+            iso::find(code).ok_or_else(|| RustyMoneyError::UnknownCurrency(code.to_string()))?;
+        let amount =
+            Decimal::try_from_i128_with_scale(money.amount.to_i128(), get_exponent(&money)?)
+                .map_err(|_| MoneyConversionError::AmountOverflow)?;
+        Ok(Money::from_decimal(amount, currency))
+    }
+}
+
+impl<B: AmountBackend> TryFrom<Money<'_, iso::Currency>> for MoneyInner<B, Currency> {
+    type Error = RustyMoneyError<Currency>;
+
+    fn try_from(money: Money<'_, iso::Currency>) -> Result<Self, Self::Error> {
+        let currency = Currency::try_from(money.currency())?;
+        let exponent = currency.exponent();
+        let subunits = money
+            .amount()
+            .checked_mul(Decimal::from(currency.factor()))
+            .ok_or(MoneyConversionError::AmountOverflow)?;
+        if !subunits.fract().is_zero() {
+            return Err(MoneyConversionError::TooManyDecimals(exponent).into());
+        }
+        Ok(MoneyInner::<B, _>::new(
+            B::narrow(subunits.to_i128(), currency)?,
+            &currency,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amount::{LowestSubunit, SignedMoney};
+    use crate::factor::Currency::*;
+
+    type Ours = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn round_trips() -> Result<(), RustyMoneyError<Currency>> {
+        for money in [
+            Ours::new(-1_999, &USD),
+            Ours::new(5, &KWD),
+            Ours::new(1_050, &JPY),
+        ] {
+            assert_eq!(Ours::try_from(Money::try_from(money)?)?, money);
+        }
+        assert_eq!(<&iso::Currency>::try_from(KWD)?, iso::KWD);
+        assert_eq!(Currency::try_from(iso::INR)?, INR);
+        assert_eq!(
+            Currency::try_from(iso::BTN),
+            Err(RustyMoneyError::UnknownCurrency("BTN".into()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn amounts_stay_exact() {
+        let big = Money::from_minor(3_000_000_000, iso::USD);
+        assert_eq!(
+            SignedMoney::try_from(big),
+            Ok(SignedMoney::new(3_000_000_000, &USD))
+        );
+        assert!(matches!(
+            Ours::try_from(big),
+            Err(RustyMoneyError::Amount(
+                MoneyConversionError::I128ToI32ConversionFailed { .. }
+            ))
+        ));
+        assert_eq!(
+            Ours::try_from(Money::from_str("1.5", iso::JPY).unwrap()),
+            Err(RustyMoneyError::Amount(
+                MoneyConversionError::TooManyDecimals(0)
+            ))
+        );
+    }
+}
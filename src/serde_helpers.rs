@@ -0,0 +1,1446 @@
+//! Alternative serde representations of `MoneyInner`, for use with `#[serde(with = "...")]`.
+
+/// Serializes `MoneyInner<HighestUnit, _>` with the amount as a string carrying exactly the
+/// currency's number of decimals, e.g. `{"amount": "10.10", "currency": "Usd"}`.
+///
+/// Deserialization parses the string with integer math, rejecting more decimals than the
+/// currency allows, so `"10.10"` always comes back as the f64 closest to `10.1`.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{HighestUnit, MoneyInner};
+/// use amount_conversion::factor::{Currency, FromCurrency};
+///
+/// #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize)]
+/// enum UserCurrency {
+///     Usd,
+/// }
+///
+/// impl FromCurrency for UserCurrency {
+///     fn currency(&self) -> Currency {
+///         Currency::USD
+///     }
+/// }
+///
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Payment {
+///     #[serde(flatten, with = "amount_conversion::serde_helpers::decimal_string")]
+///     amount: MoneyInner<HighestUnit, UserCurrency>,
+/// }
+///
+/// let payment = Payment {
+///     amount: MoneyInner::<HighestUnit, _>::new(10.1, &UserCurrency::Usd),
+/// };
+/// let json = serde_json::to_string(&payment).unwrap();
+/// assert_eq!(json, r#"{"amount":"10.10","currency":"Usd"}"#);
+/// ```
+pub mod decimal_string {
+    use std::fmt;
+
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::amount::{f64_to_i32, HighestUnit, MoneyInner};
+    use crate::decimal;
+    use crate::factor::{factors_of, get_exponent, get_factor, to_major, FromCurrency};
+
+    pub fn serialize<S, Cur>(
+        money: &MoneyInner<HighestUnit, Cur>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Cur: FromCurrency + fmt::Debug + Serialize,
+    {
+        let exponent = get_exponent(money).map_err(ser::Error::custom)?;
+        let factor = get_factor(money).map_err(ser::Error::custom)?;
+        let subunits = f64_to_i32((money.amount * factor).round(), money.currency)
+            .map_err(ser::Error::custom)?;
+        MoneyInner {
+            amount: decimal::format_subunits(i128::from(subunits), exponent),
+            currency: money.currency,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, Cur>(
+        deserializer: D,
+    ) -> Result<MoneyInner<HighestUnit, Cur>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Cur: FromCurrency + fmt::Debug + Deserialize<'de>,
+    {
+        let raw = MoneyInner::<String, Cur>::deserialize(deserializer)?;
+        let exponent = get_exponent(&raw).map_err(de::Error::custom)?;
+        let subunits =
+            decimal::parse_subunits::<Cur>(&raw.amount, exponent).map_err(de::Error::custom)?;
+        Ok(MoneyInner::<HighestUnit, Cur>::new(
+            to_major(subunits as f64, factors_of(exponent)),
+            &raw.currency,
+        ))
+    }
+}
+
+/// Deserializes `MoneyInner` from an amount given either as a JSON number or as a numeric
+/// string, e.g. both `{"amount": 1050, ...}` and `{"amount": "1050", ...}`.
+///
+/// Works for both `LowestSubunit` and `HighestUnit` amounts. Subunit amounts must be whole
+/// numbers within the `i32` range. Serialization is unchanged and always emits a number.
+///
+/// Numbers also deserialize with serde_json's `arbitrary_precision` feature, which hands
+/// flattened amounts over as maps and so breaks the derived impl.
+///
+/// ```
+/// # use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// # use amount_conversion::factor::Currency;
+/// #[derive(serde::Deserialize)]
+/// struct Refund {
+///     #[serde(flatten, with = "amount_conversion::serde_helpers::string_or_number")]
+///     amount: MoneyInner<LowestSubunit, Currency>,
+/// }
+///
+/// let refund: Refund = serde_json::from_str(r#"{"amount": "1050", "currency": "USD"}"#).unwrap();
+/// assert_eq!(refund.amount, MoneyInner::<LowestSubunit, _>::new(1050, &Currency::USD));
+/// ```
+pub mod string_or_number {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::amount::{HighestUnit, LowestSubunit, MoneyInner};
+    use crate::factor::FromCurrency;
+
+    pub fn serialize<S, Amt, Cur>(
+        money: &MoneyInner<Amt, Cur>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Amt: Serialize,
+        Cur: FromCurrency + Serialize,
+    {
+        money.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, Amt, Cur>(deserializer: D) -> Result<MoneyInner<Amt, Cur>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Amt: Amount,
+        Cur: FromCurrency + Deserialize<'de>,
+    {
+        let raw = MoneyInner::<StringOrNumber<Amt>, Cur>::deserialize(deserializer)?;
+        Ok(MoneyInner {
+            amount: raw.amount.0,
+            currency: raw.currency,
+        })
+    }
+
+    /// Amount types accepted by [`deserialize`](self::deserialize).
+    pub trait Amount: Sized {
+        #[doc(hidden)]
+        fn from_i64(value: i64) -> Option<Self>;
+        #[doc(hidden)]
+        fn from_f64(value: f64) -> Option<Self>;
+        #[doc(hidden)]
+        fn from_str(value: &str) -> Option<Self>;
+    }
+
+    impl Amount for LowestSubunit {
+        fn from_i64(value: i64) -> Option<Self> {
+            i32::try_from(value).ok()
+        }
+
+        fn from_f64(value: f64) -> Option<Self> {
+            if value.fract() != 0.0 || value < f64::from(i32::MIN) || value > f64::from(i32::MAX) {
+                return None;
+            }
+            Some(value as i32)
+        }
+
+        fn from_str(value: &str) -> Option<Self> {
+            value.parse().ok()
+        }
+    }
+
+    impl Amount for HighestUnit {
+        fn from_i64(value: i64) -> Option<Self> {
+            Some(value as f64)
+        }
+
+        fn from_f64(value: f64) -> Option<Self> {
+            Some(value)
+        }
+
+        fn from_str(value: &str) -> Option<Self> {
+            value.parse::<f64>().ok().filter(|value| value.is_finite())
+        }
+    }
+
+    struct StringOrNumber<Amt>(Amt);
+
+    impl<'de, Amt: Amount> Deserialize<'de> for StringOrNumber<Amt> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(StringOrNumberVisitor(PhantomData))
+        }
+    }
+
+    struct StringOrNumberVisitor<Amt>(PhantomData<Amt>);
+
+    impl<'de, Amt: Amount> de::Visitor<'de> for StringOrNumberVisitor<Amt> {
+        type Value = StringOrNumber<Amt>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an amount as a number or a numeric string")
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            Amt::from_i64(value)
+                .map(StringOrNumber)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(value), &self))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            i64::try_from(value)
+                .ok()
+                .and_then(Amt::from_i64)
+                .map(StringOrNumber)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            Amt::from_f64(value)
+                .map(StringOrNumber)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Float(value), &self))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            Amt::from_str(value)
+                .map(StringOrNumber)
+                .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            let digits = super::number_digits(map, &self)?;
+            Amt::from_str(&digits)
+                .or_else(|| digits.parse().ok().and_then(Amt::from_f64))
+                .map(StringOrNumber)
+                .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&digits), &self))
+        }
+    }
+}
+
+/// The key under which serde_json's `arbitrary_precision` feature passes a number's digits.
+const SERDE_JSON_NUMBER: &str = "$serde_json::private::Number";
+
+/// The digits of an `arbitrary_precision` number, which arrives as a map with a single entry
+/// when deserialized through `deserialize_any`, e.g. in a flattened struct.
+fn number_digits<'de, A: serde::de::MapAccess<'de>>(
+    mut map: A,
+    expected: &dyn serde::de::Expected,
+) -> Result<String, A::Error> {
+    match map.next_key::<String>()? {
+        Some(key) if key == SERDE_JSON_NUMBER => map.next_value(),
+        _ => Err(serde::de::Error::invalid_type(
+            serde::de::Unexpected::Map,
+            expected,
+        )),
+    }
+}
+
+/// The decimal digits of a JSON number or numeric string.
+struct Digits(String);
+
+impl<'de> serde::Deserialize<'de> for Digits {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(DigitsVisitor)
+    }
+}
+
+struct DigitsVisitor;
+
+impl<'de> serde::de::Visitor<'de> for DigitsVisitor {
+    type Value = Digits;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a major unit amount as a number or a numeric string")
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Digits(value.to_string()))
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Digits(value.to_string()))
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        if !value.is_finite() {
+            return Err(E::invalid_value(serde::de::Unexpected::Float(value), &self));
+        }
+        Ok(Digits(value.to_string()))
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Digits(value.to_string()))
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        number_digits(map, &self).map(Digits)
+    }
+}
+
+/// Parses major unit `digits` into subunits, with serde errors naming the offending input for
+/// exponent notation and for more than `exponent` decimals.
+fn parse_digits<E: serde::de::Error>(digits: &str, exponent: u32) -> Result<i64, E> {
+    let unexpected = serde::de::Unexpected::Str(digits);
+    if digits.contains(['e', 'E']) {
+        return Err(E::invalid_value(
+            unexpected,
+            &"a plain decimal amount without exponent notation",
+        ));
+    }
+    crate::decimal::parse_subunits::<()>(digits, exponent).map_err(|err| match err {
+        crate::amount::MoneyConversionError::TooManyDecimals(allowed) => E::invalid_value(
+            unexpected,
+            &format!("an amount with at most {allowed} decimals").as_str(),
+        ),
+        _ => E::invalid_value(unexpected, &"a decimal amount"),
+    })
+}
+
+/// (De)serializes `MoneyInner<LowestSubunit, _>` with the amount in major units, e.g.
+/// `{"amount": 10.25, "currency": "USD"}` for 1025 cents, reading the amount's decimal digits
+/// with integer math rather than multiplying an f64.
+///
+/// With serde_json's `arbitrary_precision` feature the digits are exactly those of the payload,
+/// flattened or not; without it they are the shortest form of the parsed f64, which matches the
+/// payload for up to 15 significant digits. Numeric strings are accepted too. More decimals than
+/// the currency allows fail with `TooManyDecimals` and exponent notation is rejected.
+/// Serialization emits the amount as a major-unit number.
+///
+/// ```
+/// # use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// # use amount_conversion::factor::Currency;
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Charge {
+///     #[serde(flatten, with = "amount_conversion::serde_helpers::exact_major")]
+///     amount: MoneyInner<LowestSubunit, Currency>,
+/// }
+///
+/// let charge: Charge = serde_json::from_str(r#"{"amount": 10.25, "currency": "USD"}"#).unwrap();
+/// assert_eq!(charge.amount, MoneyInner::<LowestSubunit, _>::new(1025, &Currency::USD));
+/// assert_eq!(serde_json::to_string(&charge).unwrap(), r#"{"amount":10.25,"currency":"USD"}"#);
+/// assert!(serde_json::from_str::<Charge>(r#"{"amount": 10.255, "currency": "USD"}"#).is_err());
+/// ```
+pub mod exact_major {
+    use std::fmt;
+
+    use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::amount::{i128_to_i32, to_major_units, LowestSubunit, MoneyInner};
+    use crate::factor::{get_exponent, FromCurrency};
+
+    pub fn serialize<S, Cur>(
+        money: &MoneyInner<LowestSubunit, Cur>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Cur: FromCurrency + fmt::Debug + Serialize,
+    {
+        to_major_units(*money)
+            .map_err(ser::Error::custom)?
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, Cur>(
+        deserializer: D,
+    ) -> Result<MoneyInner<LowestSubunit, Cur>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Cur: FromCurrency + fmt::Debug + Deserialize<'de>,
+    {
+        let raw = MoneyInner::<super::Digits, Cur>::deserialize(deserializer)?;
+        let exponent = get_exponent(&raw).map_err(de::Error::custom)?;
+        let subunits = super::parse_digits(&raw.amount.0, exponent)?;
+        let subunits = i128_to_i32(subunits.into(), raw.currency).map_err(de::Error::custom)?;
+        Ok(MoneyInner::<LowestSubunit, _>::new(subunits, &raw.currency))
+    }
+
+    /// Subunits for a `serde_json::Number` in major units of `currency`, read from its digits
+    /// without going through f64 when `arbitrary_precision` is enabled.
+    ///
+    /// ```
+    /// # use amount_conversion::factor::Currency;
+    /// use amount_conversion::serde_helpers::exact_major;
+    ///
+    /// let number: serde_json::Number = serde_json::from_str("12.345").unwrap();
+    /// assert_eq!(exact_major::from_number(&number, &Currency::BHD).unwrap().amount(), 12_345);
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn from_number<Cur: FromCurrency>(
+        number: &serde_json::Number,
+        currency: &Cur,
+    ) -> Result<MoneyInner<LowestSubunit, Cur>, crate::amount::MoneyConversionError<Cur>> {
+        MoneyInner::from_str_exact(&number.to_string(), currency)
+    }
+}
+
+/// Serializes `MoneyInner<HighestUnit, _>` with the amount as a number that never uses
+/// exponent notation, which several payment APIs reject, e.g. `{"amount": 0.3, ...}` for
+/// `0.1 + 0.2` USD rather than `0.30000000000000004`.
+///
+/// The amount is rounded to whole subunits first, as `convert` does. Float writers such as
+/// serde_json's switch to exponent notation below `0.00001`, so nonzero amounts of that size,
+/// only possible with more than four decimals, are written as a plain decimal string instead.
+/// Deserialization accepts both forms like [`string_or_number`].
+///
+/// ```
+/// # use amount_conversion::amount::{HighestUnit, MoneyInner};
+/// # use amount_conversion::factor::{Currency, FromCurrency};
+/// #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, serde::Deserialize, serde::Serialize)]
+/// enum Asset {
+///     Usd,
+///     Micros,
+/// }
+///
+/// impl FromCurrency for Asset {
+///     fn currency(&self) -> Currency {
+///         Currency::USD
+///     }
+///
+///     fn exponent_override(&self) -> Option<u32> {
+///         (*self == Asset::Micros).then_some(6)
+///     }
+/// }
+///
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Payout {
+///     #[serde(flatten, with = "amount_conversion::serde_helpers::plain_number")]
+///     amount: MoneyInner<HighestUnit, Asset>,
+/// }
+///
+/// let payout = |amount, asset| Payout { amount: MoneyInner::<HighestUnit, _>::new(amount, &asset) };
+/// let json = |payout| serde_json::to_string(&payout).unwrap();
+/// assert_eq!(json(payout(0.1 + 0.2 - 0.3, Asset::Usd)), r#"{"amount":0.0,"currency":"Usd"}"#);
+/// assert_eq!(json(payout(21474836.47, Asset::Usd)), r#"{"amount":21474836.47,"currency":"Usd"}"#);
+/// assert_eq!(json(payout(1e-6, Asset::Micros)), r#"{"amount":"0.000001","currency":"Micros"}"#);
+/// ```
+pub mod plain_number {
+    use std::fmt;
+
+    use serde::{ser, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::amount::{to_major_units, to_subunits, HighestUnit, LowestSubunit, MoneyInner};
+    use crate::decimal;
+    use crate::factor::{get_exponent, FromCurrency};
+
+    /// The smallest magnitude float writers render without an exponent.
+    const SMALLEST_PLAIN: f64 = 1e-5;
+
+    pub fn serialize<S, Cur>(
+        money: &MoneyInner<HighestUnit, Cur>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Cur: FromCurrency + fmt::Debug + Serialize,
+    {
+        let subunits = to_subunits::<LowestSubunit, _>(*money).map_err(ser::Error::custom)?;
+        let major = to_major_units(subunits).map_err(ser::Error::custom)?;
+        if major.amount == 0.0 || major.amount.abs() >= SMALLEST_PLAIN {
+            return major.serialize(serializer);
+        }
+        let exponent = get_exponent(&subunits).map_err(ser::Error::custom)?;
+        MoneyInner {
+            amount: decimal::format_subunits(subunits.amount.into(), exponent),
+            currency: money.currency,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, Cur>(
+        deserializer: D,
+    ) -> Result<MoneyInner<HighestUnit, Cur>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Cur: FromCurrency + Deserialize<'de>,
+    {
+        super::string_or_number::deserialize(deserializer)
+    }
+}
+
+/// (De)serializes `MoneyInner<HighestUnit, _>` rejecting, at parse time, amounts that would
+/// otherwise be normalized later: exponent notation such as `"1e5"`, and more decimals than
+/// the currency allows such as `1.00000000000001` USD.
+///
+/// Amounts may be numbers or numeric strings. Exponent notation in a JSON number is only
+/// visible with serde_json's `arbitrary_precision` feature, since serde_json otherwise hands
+/// over the parsed f64. Serializes like [`plain_number`].
+///
+/// ```
+/// # use amount_conversion::amount::{HighestUnit, MoneyInner};
+/// # use amount_conversion::factor::Currency;
+/// #[derive(Debug, serde::Deserialize)]
+/// struct Refund {
+///     #[serde(flatten, with = "amount_conversion::serde_helpers::strict_major")]
+///     amount: MoneyInner<HighestUnit, Currency>,
+/// }
+///
+/// let refund: Refund = serde_json::from_str(r#"{"amount": "10.5", "currency": "USD"}"#).unwrap();
+/// assert_eq!(refund.amount.amount(), 10.5);
+///
+/// let err = serde_json::from_str::<Refund>(r#"{"amount": 1.00000000000001, "currency": "USD"}"#)
+///     .unwrap_err();
+/// assert!(err.to_string().starts_with(
+///     r#"invalid value: string "1.00000000000001", expected an amount with at most 2 decimals"#
+/// ));
+/// assert!(serde_json::from_str::<Refund>(r#"{"amount": "1e5", "currency": "USD"}"#).is_err());
+/// ```
+pub mod strict_major {
+    use std::fmt;
+
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::amount::{HighestUnit, MoneyInner};
+    use crate::factor::{factors_of, get_exponent, to_major, FromCurrency};
+
+    pub fn serialize<S, Cur>(
+        money: &MoneyInner<HighestUnit, Cur>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Cur: FromCurrency + fmt::Debug + Serialize,
+    {
+        super::plain_number::serialize(money, serializer)
+    }
+
+    pub fn deserialize<'de, D, Cur>(
+        deserializer: D,
+    ) -> Result<MoneyInner<HighestUnit, Cur>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Cur: FromCurrency + fmt::Debug + Deserialize<'de>,
+    {
+        let raw = MoneyInner::<super::Digits, Cur>::deserialize(deserializer)?;
+        let exponent = get_exponent(&raw).map_err(de::Error::custom)?;
+        let subunits = super::parse_digits::<D::Error>(&raw.amount.0, exponent)?;
+        Ok(MoneyInner::<HighestUnit, Cur>::new(
+            to_major(subunits as f64, factors_of(exponent)),
+            &raw.currency,
+        ))
+    }
+}
+
+/// Deserializes a flattened `MoneyInner` from `application/x-www-form-urlencoded` bodies and
+/// query strings, where every value arrives as a string, e.g. `amount=1050&currency=USD`.
+///
+/// A plain `#[serde(flatten)]` field fails there because the amount is expected as a number.
+/// This accepts string amounts the same way as [`string_or_number`], which it is an alias of,
+/// and serializes unchanged.
+///
+/// ```
+/// # use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// # use amount_conversion::factor::Currency;
+/// #[derive(serde::Deserialize)]
+/// struct Checkout {
+///     #[serde(flatten, with = "amount_conversion::serde_helpers::form")]
+///     amount: MoneyInner<LowestSubunit, Currency>,
+///     order_id: String,
+/// }
+///
+/// let checkout: Checkout =
+///     serde_urlencoded::from_str("amount=1050&currency=USD&order_id=A1").unwrap();
+/// assert_eq!(checkout.amount, MoneyInner::<LowestSubunit, _>::new(1050, &Currency::USD));
+/// ```
+pub mod form {
+    pub use super::string_or_number::{deserialize, serialize};
+}
+
+/// Serializes `MoneyInner<LowestSubunit, _>` with the subunit amount as a string, e.g.
+/// `{"amount": "1050", "currency": "USD"}`, for gateway APIs that mandate string amounts.
+///
+/// Deserialization accepts the amount as a string or a number, like [`string_or_number`].
+///
+/// ```
+/// # use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// # use amount_conversion::factor::Currency;
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Capture {
+///     #[serde(flatten, with = "amount_conversion::serde_helpers::minor_string")]
+///     amount: MoneyInner<LowestSubunit, Currency>,
+/// }
+///
+/// let capture = Capture {
+///     amount: MoneyInner::<LowestSubunit, _>::new(1050, &Currency::USD),
+/// };
+/// assert_eq!(
+///     serde_json::to_string(&capture).unwrap(),
+///     r#"{"amount":"1050","currency":"USD"}"#
+/// );
+/// ```
+pub mod minor_string {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::amount::{LowestSubunit, MoneyInner};
+    use crate::factor::FromCurrency;
+
+    pub fn serialize<S, Cur>(
+        money: &MoneyInner<LowestSubunit, Cur>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Cur: FromCurrency + Serialize,
+    {
+        MoneyInner {
+            amount: money.amount.to_string(),
+            currency: money.currency,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, Cur>(
+        deserializer: D,
+    ) -> Result<MoneyInner<LowestSubunit, Cur>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Cur: FromCurrency + Deserialize<'de>,
+    {
+        super::string_or_number::deserialize(deserializer)
+    }
+}
+
+/// (De)serializes `MoneyInner` with the currency as its ISO 4217 code, whatever the custom
+/// currency type's own serde representation, e.g. `{"amount": 1050, "currency": "USD"}`.
+///
+/// Deserialization maps the code back with [`TryFromCurrency`], failing for ISO currencies
+/// the custom type does not have.
+///
+/// ```
+/// # use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// # use amount_conversion::factor::{Currency, FromCurrency, TryFromCurrency};
+/// #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+/// enum UserCurrency {
+///     Usd,
+/// }
+///
+/// impl FromCurrency for UserCurrency {
+///     fn currency(&self) -> Currency {
+///         Currency::USD
+///     }
+/// }
+///
+/// impl TryFromCurrency for UserCurrency {
+///     fn try_from_currency(currency: Currency) -> Option<Self> {
+///         (currency == Currency::USD).then_some(UserCurrency::Usd)
+///     }
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// struct GatewayResponse {
+///     #[serde(flatten, with = "amount_conversion::serde_helpers::iso_currency")]
+///     amount: MoneyInner<LowestSubunit, UserCurrency>,
+/// }
+///
+/// let response: GatewayResponse =
+///     serde_json::from_str(r#"{"amount": 1050, "currency": "USD"}"#).unwrap();
+/// assert_eq!(response.amount, MoneyInner::<LowestSubunit, _>::new(1050, &UserCurrency::Usd));
+/// assert!(serde_json::from_str::<GatewayResponse>(r#"{"amount": 1, "currency": "EUR"}"#).is_err());
+/// ```
+///
+/// [`TryFromCurrency`]: crate::factor::TryFromCurrency
+pub mod iso_currency {
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::amount::MoneyInner;
+    use crate::factor::{Currency, FromCurrency, TryFromCurrency};
+
+    pub fn serialize<S, Amt, Cur>(
+        money: &MoneyInner<Amt, Cur>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Amt: Serialize,
+        Cur: FromCurrency,
+    {
+        MoneyInner {
+            amount: &money.amount,
+            currency: money.currency.currency(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, Amt, Cur>(deserializer: D) -> Result<MoneyInner<Amt, Cur>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Amt: Deserialize<'de>,
+        Cur: TryFromCurrency,
+    {
+        let raw = MoneyInner::<Amt, Currency>::deserialize(deserializer)?;
+        let currency = Cur::try_from_currency(raw.currency).ok_or_else(|| {
+            de::Error::custom(format_args!("currency {} is not supported", raw.currency))
+        })?;
+        Ok(MoneyInner {
+            amount: raw.amount,
+            currency,
+        })
+    }
+}
+
+/// (De)serializes `MoneyInner` with the currency as its ISO 4217 numeric code, e.g.
+/// `{"amount": 1050, "currency": 840}`, as emitted by ISO 8583-derived systems.
+///
+/// Deserialization accepts the code as a number or a string such as `"840"` or `"008"`, and
+/// maps it to the custom currency type with [`TryFromCurrency`]. Serialization emits a number.
+///
+/// ```
+/// # use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// # use amount_conversion::factor::Currency;
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Authorization {
+///     #[serde(flatten, with = "amount_conversion::serde_helpers::numeric_currency")]
+///     amount: MoneyInner<LowestSubunit, Currency>,
+/// }
+///
+/// let auth: Authorization =
+///     serde_json::from_str(r#"{"amount": 1050, "currency": "840"}"#).unwrap();
+/// assert_eq!(auth.amount, MoneyInner::<LowestSubunit, _>::new(1050, &Currency::USD));
+/// assert_eq!(
+///     serde_json::to_string(&auth).unwrap(),
+///     r#"{"amount":1050,"currency":840}"#
+/// );
+/// ```
+///
+/// [`TryFromCurrency`]: crate::factor::TryFromCurrency
+pub mod numeric_currency {
+    use std::fmt;
+
+    use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::amount::MoneyInner;
+    use crate::factor::{Currency, FromCurrency, TryFromCurrency};
+
+    pub fn serialize<S, Amt, Cur>(
+        money: &MoneyInner<Amt, Cur>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Amt: Serialize,
+        Cur: FromCurrency,
+    {
+        MoneyInner {
+            amount: &money.amount,
+            currency: NumericCode(money.currency.currency()),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D, Amt, Cur>(deserializer: D) -> Result<MoneyInner<Amt, Cur>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Amt: Deserialize<'de>,
+        Cur: TryFromCurrency,
+    {
+        let raw = MoneyInner::<Amt, NumericCode>::deserialize(deserializer)?;
+        let NumericCode(iso) = raw.currency;
+        let currency = Cur::try_from_currency(iso)
+            .ok_or_else(|| de::Error::custom(format_args!("currency {iso} is not supported")))?;
+        Ok(MoneyInner {
+            amount: raw.amount,
+            currency,
+        })
+    }
+
+    #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+    struct NumericCode(Currency);
+
+    impl FromCurrency for NumericCode {
+        fn currency(&self) -> Currency {
+            self.0
+        }
+    }
+
+    impl Serialize for NumericCode {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_u16(self.0.numeric_code())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for NumericCode {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            deserializer.deserialize_any(NumericCodeVisitor)
+        }
+    }
+
+    struct NumericCodeVisitor;
+
+    impl NumericCodeVisitor {
+        fn lookup<E: de::Error>(
+            &self,
+            code: Option<u16>,
+            unexpected: de::Unexpected<'_>,
+        ) -> Result<NumericCode, E> {
+            code.and_then(Currency::from_numeric_code)
+                .map(NumericCode)
+                .ok_or_else(|| E::invalid_value(unexpected, self))
+        }
+    }
+
+    impl<'de> de::Visitor<'de> for NumericCodeVisitor {
+        type Value = NumericCode;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an ISO 4217 numeric currency code")
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            self.lookup(u16::try_from(value).ok(), de::Unexpected::Signed(value))
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            self.lookup(u16::try_from(value).ok(), de::Unexpected::Unsigned(value))
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+            // ISO lists numeric codes as three digits, e.g. "008"
+            let code = Some(value)
+                .filter(|value| value.len() == 3 && value.bytes().all(|b| b.is_ascii_digit()))
+                .and_then(|value| value.parse().ok());
+            self.lookup(code, de::Unexpected::Str(value))
+        }
+
+        fn visit_map<A: de::MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+            let digits = super::number_digits(map, &self)?;
+            self.lookup(digits.parse().ok(), de::Unexpected::Str(&digits))
+        }
+    }
+}
+
+/// Shared implementation behind [`serde_field_names!`](crate::serde_field_names), serializing
+/// `MoneyInner` with caller-chosen names for the amount and currency fields.
+pub mod field_names {
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    use serde::de::{self, IgnoredAny, MapAccess};
+    use serde::ser::SerializeStruct;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::amount::MoneyInner;
+    use crate::factor::FromCurrency;
+
+    #[doc(hidden)]
+    pub use serde as __serde;
+
+    /// Names of the amount and currency fields, in that order.
+    pub type FieldNames = [&'static str; 2];
+
+    pub fn serialize<S, Amt, Cur>(
+        money: &MoneyInner<Amt, Cur>,
+        serializer: S,
+        names: &'static FieldNames,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        Amt: Serialize,
+        Cur: FromCurrency + Serialize,
+    {
+        let mut state = serializer.serialize_struct("MoneyInner", 2)?;
+        state.serialize_field(names[0], &money.amount)?;
+        state.serialize_field(names[1], &money.currency)?;
+        state.end()
+    }
+
+    pub fn deserialize<'de, D, Amt, Cur>(
+        deserializer: D,
+        names: &'static FieldNames,
+    ) -> Result<MoneyInner<Amt, Cur>, D::Error>
+    where
+        D: Deserializer<'de>,
+        Amt: Deserialize<'de>,
+        Cur: FromCurrency + Deserialize<'de>,
+    {
+        deserializer.deserialize_struct(
+            "MoneyInner",
+            names,
+            FieldNamesVisitor {
+                names,
+                marker: PhantomData,
+            },
+        )
+    }
+
+    struct FieldNamesVisitor<Amt, Cur> {
+        names: &'static FieldNames,
+        marker: PhantomData<(Amt, Cur)>,
+    }
+
+    impl<'de, Amt, Cur> de::Visitor<'de> for FieldNamesVisitor<Amt, Cur>
+    where
+        Amt: Deserialize<'de>,
+        Cur: FromCurrency + Deserialize<'de>,
+    {
+        type Value = MoneyInner<Amt, Cur>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "a map with `{}` and `{}`", self.names[0], self.names[1])
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+            let (mut amount, mut currency) = (None, None);
+            while let Some(key) = map.next_key::<String>()? {
+                if key == self.names[0] {
+                    if amount.is_some() {
+                        return Err(de::Error::duplicate_field(self.names[0]));
+                    }
+                    amount = Some(map.next_value()?);
+                } else if key == self.names[1] {
+                    if currency.is_some() {
+                        return Err(de::Error::duplicate_field(self.names[1]));
+                    }
+                    currency = Some(map.next_value()?);
+                } else {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+            Ok(MoneyInner {
+                amount: amount.ok_or_else(|| de::Error::missing_field(self.names[0]))?,
+                currency: currency.ok_or_else(|| de::Error::missing_field(self.names[1]))?,
+            })
+        }
+    }
+}
+
+/// Generates a module for `#[serde(with = "...")]` that (de)serializes `MoneyInner` with custom
+/// names for the amount and currency fields.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::factor::Currency;
+///
+/// amount_conversion::serde_field_names!(mod gateway_amount {
+///     amount: "amt",
+///     currency: "ccy",
+/// });
+///
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Charge {
+///     #[serde(flatten, with = "gateway_amount")]
+///     amount: MoneyInner<LowestSubunit, Currency>,
+/// }
+///
+/// let charge: Charge = serde_json::from_str(r#"{"amt": 1050, "ccy": "USD"}"#).unwrap();
+/// assert_eq!(serde_json::to_string(&charge).unwrap(), r#"{"amt":1050,"ccy":"USD"}"#);
+/// ```
+#[macro_export]
+macro_rules! serde_field_names {
+    (
+        $(#[$meta:meta])*
+        $vis:vis mod $name:ident {
+            amount: $amount:literal,
+            currency: $currency:literal $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis mod $name {
+            use $crate::serde_helpers::field_names::{self, FieldNames, __serde};
+
+            static NAMES: FieldNames = [$amount, $currency];
+
+            pub fn serialize<S, Amt, Cur>(
+                money: &$crate::amount::MoneyInner<Amt, Cur>,
+                serializer: S,
+            ) -> ::std::result::Result<S::Ok, S::Error>
+            where
+                S: __serde::Serializer,
+                Amt: __serde::Serialize,
+                Cur: $crate::factor::FromCurrency + __serde::Serialize,
+            {
+                field_names::serialize(money, serializer, &NAMES)
+            }
+
+            pub fn deserialize<'de, D, Amt, Cur>(
+                deserializer: D,
+            ) -> ::std::result::Result<$crate::amount::MoneyInner<Amt, Cur>, D::Error>
+            where
+                D: __serde::Deserializer<'de>,
+                Amt: __serde::Deserialize<'de>,
+                Cur: $crate::factor::FromCurrency + __serde::Deserialize<'de>,
+            {
+                field_names::deserialize(deserializer, &NAMES)
+            }
+        }
+    };
+}
+
+crate::serde_field_names!(
+    /// (De)serializes `MoneyInner` as `{"value": 1050, "currency_code": "USD"}`.
+    pub mod value_currency_code {
+        amount: "value",
+        currency: "currency_code",
+    }
+);
+
+crate::serde_field_names!(
+    /// (De)serializes `MoneyInner` as `{"minor_amount": 1050, "currency": "USD"}`.
+    pub mod minor_amount {
+        amount: "minor_amount",
+        currency: "currency",
+    }
+);
+
+crate::serde_field_names!(
+    /// (De)serializes `MoneyInner` as a nested object `{"value": 1050, "currency": "USD"}`.
+    ///
+    /// Use it on a field without `flatten` to get the nested form partner APIs expect:
+    ///
+    /// ```
+    /// # use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// # use amount_conversion::factor::Currency;
+    /// #[derive(serde::Serialize)]
+    /// struct Order {
+    ///     #[serde(with = "amount_conversion::serde_helpers::nested")]
+    ///     amount: MoneyInner<LowestSubunit, Currency>,
+    /// }
+    ///
+    /// let order = Order {
+    ///     amount: MoneyInner::<LowestSubunit, _>::new(1050, &Currency::USD),
+    /// };
+    /// assert_eq!(
+    ///     serde_json::to_string(&order).unwrap(),
+    ///     r#"{"amount":{"value":1050,"currency":"USD"}}"#
+    /// );
+    /// ```
+    pub mod nested {
+        amount: "value",
+        currency: "currency",
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::amount::{HighestUnit, LowestSubunit, MoneyInner};
+    use crate::factor::Currency::{self, *};
+
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct DecimalString {
+        #[serde(flatten, with = "decimal_string")]
+        amount: MoneyInner<HighestUnit, Currency>,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize)]
+    #[serde(bound(deserialize = "Amt: string_or_number::Amount"))]
+    struct StringOrNumber<Amt> {
+        #[serde(flatten, with = "string_or_number")]
+        amount: MoneyInner<Amt, Currency>,
+    }
+
+    fn decimal_string(amount: f64, currency: &Currency) -> DecimalString {
+        DecimalString {
+            amount: MoneyInner::<HighestUnit, _>::new(amount, currency),
+        }
+    }
+
+    #[test]
+    fn decimal_string_round_trip() -> Result<(), serde_json::Error> {
+        for (money, json) in [
+            (
+                decimal_string(10.1, &USD),
+                r#"{"amount":"10.10","currency":"USD"}"#,
+            ),
+            (
+                decimal_string(-0.07, &USD),
+                r#"{"amount":"-0.07","currency":"USD"}"#,
+            ),
+            (
+                decimal_string(10.0, &JPY),
+                r#"{"amount":"10","currency":"JPY"}"#,
+            ),
+            (
+                decimal_string(1.5, &BHD),
+                r#"{"amount":"1.500","currency":"BHD"}"#,
+            ),
+        ] {
+            assert_eq!(serde_json::to_string(&money)?, json);
+            assert_eq!(serde_json::from_str::<DecimalString>(json)?, money);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn decimal_string_rejects_excess_precision() {
+        let json = r#"{"amount":"10.101","currency":"USD"}"#;
+        let err = serde_json::from_str::<DecimalString>(json).unwrap_err();
+        assert!(err.to_string().contains("more than 2 decimal places"));
+        assert!(
+            serde_json::from_str::<DecimalString>(r#"{"amount":10.1,"currency":"USD"}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn string_or_number_subunits() -> Result<(), serde_json::Error> {
+        let expected = MoneyInner::<LowestSubunit, _>::new(-1050, &USD);
+        for json in [
+            r#"{"amount":-1050,"currency":"USD"}"#,
+            r#"{"amount":"-1050","currency":"USD"}"#,
+            r#"{"amount":-1050.0,"currency":"USD"}"#,
+        ] {
+            let value = serde_json::from_str::<StringOrNumber<LowestSubunit>>(json)?;
+            assert_eq!(value.amount, expected);
+        }
+        for json in [
+            r#"{"amount":"10.5","currency":"USD"}"#,
+            r#"{"amount":10.5,"currency":"USD"}"#,
+            r#"{"amount":"","currency":"USD"}"#,
+            r#"{"amount":2147483648,"currency":"USD"}"#,
+            r#"{"amount":true,"currency":"USD"}"#,
+        ] {
+            assert!(serde_json::from_str::<StringOrNumber<LowestSubunit>>(json).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn string_or_number_highest_unit() -> Result<(), serde_json::Error> {
+        for json in [
+            r#"{"amount":"10.5","currency":"USD"}"#,
+            r#"{"amount":10.5,"currency":"USD"}"#,
+        ] {
+            let value = serde_json::from_str::<StringOrNumber<HighestUnit>>(json)?;
+            assert_eq!(value.amount.amount(), 10.5);
+        }
+        assert!(serde_json::from_str::<StringOrNumber<HighestUnit>>(
+            r#"{"amount":"NaN","currency":"USD"}"#
+        )
+        .is_err());
+        Ok(())
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    #[serde(bound(
+        deserialize = "Amt: string_or_number::Amount",
+        serialize = "Amt: serde::Serialize"
+    ))]
+    struct Form<Amt> {
+        #[serde(flatten, with = "form")]
+        amount: MoneyInner<Amt, Currency>,
+        note: String,
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct ExactMajor {
+        #[serde(flatten, with = "exact_major")]
+        amount: MoneyInner<LowestSubunit, Currency>,
+    }
+
+    /// With `arbitrary_precision` numbers keep the payload's digits; without it they are the
+    /// shortest digits of the parsed f64.
+    #[test]
+    fn exact_major_numbers() -> Result<(), serde_json::Error> {
+        let charge: ExactMajor =
+            serde_json::from_str(r#"{"amount": 21474836.47, "currency": "USD"}"#)?;
+        assert_eq!(
+            charge.amount,
+            MoneyInner::<LowestSubunit, _>::new(i32::MAX, &USD)
+        );
+        let charge: ExactMajor = serde_json::from_str(r#"{"amount": "-7", "currency": "KWD"}"#)?;
+        assert_eq!(
+            charge.amount,
+            MoneyInner::<LowestSubunit, _>::new(-7_000, &KWD)
+        );
+
+        let noisy = r#"{"amount": 0.1000000000000000055511151231257827, "currency": "USD"}"#;
+        let exponent = r#"{"amount": 1e3, "currency": "USD"}"#;
+        #[cfg(feature = "arbitrary-precision")]
+        {
+            let err = serde_json::from_str::<ExactMajor>(noisy).unwrap_err();
+            assert!(
+                err.to_string()
+                    .contains("expected an amount with at most 2 decimals"),
+                "{err}"
+            );
+            assert!(serde_json::from_str::<ExactMajor>(exponent).is_err());
+        }
+        #[cfg(not(feature = "arbitrary-precision"))]
+        for (json, subunits) in [(noisy, 10), (exponent, 100_000)] {
+            let charge: ExactMajor = serde_json::from_str(json)?;
+            assert_eq!(
+                charge.amount,
+                MoneyInner::<LowestSubunit, _>::new(subunits, &USD)
+            );
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct PlainNumber {
+        #[serde(flatten, with = "plain_number")]
+        amount: MoneyInner<HighestUnit, Currency>,
+    }
+
+    #[test]
+    fn plain_number_never_uses_exponents() -> Result<(), serde_json::Error> {
+        let plain = |amount, currency: &Currency| PlainNumber {
+            amount: MoneyInner::<HighestUnit, _>::new(amount, currency),
+        };
+        for (amount, currency, json) in [
+            (
+                2_147_483_647.0,
+                JPY,
+                r#"{"amount":2147483647.0,"currency":"JPY"}"#,
+            ),
+            (-5.551e-17, USD, r#"{"amount":0.0,"currency":"USD"}"#),
+            (0.0014, KWD, r#"{"amount":0.001,"currency":"KWD"}"#),
+        ] {
+            let serialized = serde_json::to_string(&plain(amount, &currency))?;
+            assert_eq!(serialized, json);
+            assert!(!serialized.contains("e-") && !serialized.contains("e+"));
+            let back: PlainNumber = serde_json::from_str(&serialized)?;
+            assert!(back
+                .amount
+                .eq_exact(&plain(amount, &currency).amount)
+                .unwrap());
+        }
+        Ok(())
+    }
+
+    #[derive(Debug, serde::Deserialize)]
+    struct StrictMajor {
+        #[serde(flatten, with = "strict_major")]
+        amount: MoneyInner<HighestUnit, Currency>,
+    }
+
+    #[test]
+    fn strict_major_rejects_at_parse_time() {
+        let parse = |json: &str| {
+            serde_json::from_str::<StrictMajor>(json)
+                .map(|strict| strict.amount.amount())
+                .map_err(|err| err.to_string())
+        };
+        assert_eq!(
+            parse(r#"{"amount": 12.345, "currency": "KWD"}"#),
+            Ok(12.345)
+        );
+        assert_eq!(parse(r#"{"amount": -3, "currency": "JPY"}"#), Ok(-3.0));
+        let exponent = parse(r#"{"amount": 1e5, "currency": "USD"}"#);
+        #[cfg(feature = "arbitrary-precision")]
+        {
+            let err = exponent.unwrap_err();
+            assert!(
+                err.contains("expected a plain decimal amount without exponent notation"),
+                "{err}"
+            );
+        }
+        #[cfg(not(feature = "arbitrary-precision"))]
+        assert_eq!(exponent, Ok(100_000.0));
+        let err = parse(r#"{"amount": "1.5", "currency": "JPY"}"#).unwrap_err();
+        assert!(
+            err.contains("expected an amount with at most 0 decimals"),
+            "{err}"
+        );
+        assert!(parse(r#"{"amount": "1,000", "currency": "USD"}"#).is_err());
+    }
+
+    #[test]
+    fn form_urlencoded() -> Result<(), serde_urlencoded::de::Error> {
+        let value = serde_urlencoded::from_str::<Form<LowestSubunit>>(
+            "note=gift&amount=-1050&currency=INR",
+        )?;
+        assert_eq!(
+            value.amount,
+            MoneyInner::<LowestSubunit, _>::new(-1050, &INR)
+        );
+
+        let value =
+            serde_urlencoded::from_str::<Form<HighestUnit>>("amount=10.5&currency=USD&note=")?;
+        assert_eq!(value.amount.amount(), 10.5);
+
+        for query in [
+            "amount=10.5&currency=USD&note=",
+            "amount=&currency=USD&note=",
+            "amount=1050&currency=XYZ&note=",
+        ] {
+            assert!(serde_urlencoded::from_str::<Form<LowestSubunit>>(query).is_err());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn form_round_trip() {
+        let value = Form {
+            amount: MoneyInner::<LowestSubunit, _>::new(1050, &USD),
+            note: "a b".to_string(),
+        };
+        let query = serde_urlencoded::to_string(&value).unwrap();
+        assert_eq!(query, "amount=1050&currency=USD&note=a+b");
+        assert_eq!(
+            serde_urlencoded::from_str::<Form<LowestSubunit>>(&query),
+            Ok(value)
+        );
+    }
+
+    #[test]
+    fn minor_string() -> Result<(), serde_json::Error> {
+        #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        struct Capture {
+            #[serde(flatten, with = "minor_string")]
+            amount: MoneyInner<LowestSubunit, Currency>,
+        }
+
+        let json = r#"{"amount":"-2147483648","currency":"JPY"}"#;
+        let value = Capture {
+            amount: MoneyInner::<LowestSubunit, _>::new(i32::MIN, &JPY),
+        };
+        assert_eq!(serde_json::to_string(&value)?, json);
+        assert_eq!(serde_json::from_str::<Capture>(json)?, value);
+        assert_eq!(
+            serde_json::from_str::<Capture>(r#"{"amount":-2147483648,"currency":"JPY"}"#)?,
+            value
+        );
+        assert!(serde_json::from_str::<Capture>(r#"{"amount":"10.5","currency":"USD"}"#).is_err());
+        Ok(())
+    }
+
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct FieldNames {
+        #[serde(flatten, with = "value_currency_code")]
+        amount: MoneyInner<LowestSubunit, Currency>,
+        id: u8,
+    }
+
+    #[test]
+    fn field_names() -> Result<(), serde_json::Error> {
+        let json = r#"{"value":1050,"currency_code":"USD","id":1}"#;
+        let value = FieldNames {
+            amount: MoneyInner::<LowestSubunit, _>::new(1050, &USD),
+            id: 1,
+        };
+        assert_eq!(serde_json::to_string(&value)?, json);
+        assert_eq!(serde_json::from_str::<FieldNames>(json)?, value);
+
+        let err = serde_json::from_str::<FieldNames>(r#"{"value":1050,"id":1}"#).unwrap_err();
+        assert!(err.to_string().contains("missing field `currency_code`"));
+        Ok(())
+    }
+
+    #[test]
+    fn minor_amount_nested() -> Result<(), serde_json::Error> {
+        #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        struct Nested {
+            #[serde(with = "minor_amount")]
+            price: MoneyInner<LowestSubunit, Currency>,
+        }
+
+        let json = r#"{"price":{"minor_amount":5,"currency":"JPY"}}"#;
+        let value = Nested {
+            price: MoneyInner::<LowestSubunit, _>::new(5, &JPY),
+        };
+        assert_eq!(serde_json::to_string(&value)?, json);
+        assert_eq!(serde_json::from_str::<Nested>(json)?, value);
+        Ok(())
+    }
+
+    #[test]
+    fn nested() -> Result<(), serde_json::Error> {
+        #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        struct Order {
+            #[serde(with = "nested")]
+            amount: MoneyInner<LowestSubunit, Currency>,
+            id: u8,
+        }
+
+        let json = r#"{"amount":{"value":1050,"currency":"USD"},"id":7}"#;
+        let value = Order {
+            amount: MoneyInner::<LowestSubunit, _>::new(1050, &USD),
+            id: 7,
+        };
+        assert_eq!(serde_json::to_string(&value)?, json);
+        assert_eq!(serde_json::from_str::<Order>(json)?, value);
+        assert!(
+            serde_json::from_str::<Order>(r#"{"amount":1050,"currency":"USD","id":7}"#).is_err()
+        );
+        Ok(())
+    }
+
+    #[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+    enum Local {
+        Rupee,
+    }
+
+    impl crate::factor::FromCurrency for Local {
+        fn currency(&self) -> Currency {
+            INR
+        }
+    }
+
+    impl crate::factor::TryFromCurrency for Local {
+        fn try_from_currency(currency: Currency) -> Option<Self> {
+            (currency == INR).then_some(Local::Rupee)
+        }
+    }
+
+    #[test]
+    fn iso_currency() -> Result<(), serde_json::Error> {
+        #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        struct Payout {
+            #[serde(flatten, with = "iso_currency")]
+            amount: MoneyInner<LowestSubunit, Local>,
+        }
+
+        let json = r#"{"amount":-500,"currency":"INR"}"#;
+        let value = Payout {
+            amount: MoneyInner::<LowestSubunit, _>::new(-500, &Local::Rupee),
+        };
+        assert_eq!(serde_json::to_string(&value)?, json);
+        assert_eq!(serde_json::from_str::<Payout>(json)?, value);
+
+        let err = serde_json::from_str::<Payout>(r#"{"amount":1,"currency":"USD"}"#).unwrap_err();
+        assert!(err.to_string().contains("currency USD is not supported"));
+        Ok(())
+    }
+
+    #[test]
+    fn numeric_currency() -> Result<(), serde_json::Error> {
+        #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+        struct Authorization {
+            #[serde(flatten, with = "numeric_currency")]
+            amount: MoneyInner<LowestSubunit, Local>,
+        }
+
+        let value = Authorization {
+            amount: MoneyInner::<LowestSubunit, _>::new(500, &Local::Rupee),
+        };
+        for json in [
+            r#"{"amount":500,"currency":356}"#,
+            r#"{"amount":500,"currency":"356"}"#,
+        ] {
+            assert_eq!(serde_json::from_str::<Authorization>(json)?, value);
+        }
+        assert_eq!(
+            serde_json::to_string(&value)?,
+            r#"{"amount":500,"currency":356}"#
+        );
+        for json in [
+            r#"{"amount":1,"currency":840}"#,
+            r#"{"amount":1,"currency":999}"#,
+            r#"{"amount":1,"currency":"35"}"#,
+            r#"{"amount":1,"currency":"INR"}"#,
+            r#"{"amount":1,"currency":-356}"#,
+        ] {
+            assert!(serde_json::from_str::<Authorization>(json).is_err());
+        }
+        // `arbitrary_precision` hands non-integers to the flattened visitor as a map
+        let err =
+            serde_json::from_str::<Authorization>(r#"{"amount":1,"currency":356.5}"#).unwrap_err();
+        assert!(err.to_string().contains("356.5"), "{err}");
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+//! Amounts by date in a single currency, enabled with the `chrono` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{LowestSubunit, MoneyInner};
+//! use amount_conversion::factor::Currency::{self, USD};
+//! use amount_conversion::series::{MoneySeries, Period};
+//! use chrono::NaiveDate;
+//!
+//! type Money = MoneyInner<LowestSubunit, Currency>;
+//!
+//! let day = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+//! let mut daily = MoneySeries::new(USD);
+//! daily.add(day(1, 3), Money::new(1_000, &USD))?;
+//! daily.add(day(1, 20), Money::new(500, &USD))?;
+//! daily.add(day(2, 1), Money::new(250, &USD))?;
+//!
+//! let monthly = daily.resample(Period::Month)?;
+//! let points: Vec<_> = monthly.iter().map(|(date, money)| (date, money.amount())).collect();
+//! assert_eq!(points, [(day(1, 1), 1_500), (day(2, 1), 250)]);
+//!
+//! let running: Vec<_> = daily.cumulative()?.iter().map(|(_, money)| money.amount()).collect();
+//! assert_eq!(running, [1_000, 1_500, 1_750]);
+//! # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+//! ```
+use std::collections::BTreeMap;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::FromCurrency;
+
+/// The buckets used by [`MoneySeries::resample`], each keyed by its first day.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Period {
+    Month,
+    Quarter,
+    Year,
+}
+
+impl Period {
+    fn start(self, date: NaiveDate) -> NaiveDate {
+        let month = match self {
+            Self::Month => date.month(),
+            Self::Quarter => date.month0() / 3 * 3 + 1,
+            Self::Year => 1,
+        };
+        NaiveDate::from_ymd_opt(date.year(), month, 1).expect("first day of a month is valid")
+    }
+}
+
+/// Amounts keyed by date, all in one currency, in date order.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MoneySeries<Cur: FromCurrency> {
+    currency: Cur,
+    points: BTreeMap<NaiveDate, LowestSubunit>,
+}
+
+impl<Cur: FromCurrency> MoneySeries<Cur> {
+    /// An empty series in `currency`.
+    pub fn new(currency: Cur) -> Self {
+        Self {
+            currency,
+            points: BTreeMap::new(),
+        }
+    }
+
+    pub fn currency(&self) -> Cur {
+        self.currency
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    /// Adds `money` to the amount at `date`.
+    ///
+    /// Fails with `CurrencyMismatch` when `money` is not in the series' currency, or with
+    /// `AmountOverflow`, leaving the series unchanged.
+    pub fn add(
+        &mut self,
+        date: NaiveDate,
+        money: MoneyInner<LowestSubunit, Cur>,
+    ) -> Result<(), MoneyConversionError<Cur>> {
+        let sum = self.get(date).checked_add(money)?;
+        self.points.insert(date, sum.amount);
+        Ok(())
+    }
+
+    /// The amount at `date`, zero when nothing was added.
+    pub fn get(&self, date: NaiveDate) -> MoneyInner<LowestSubunit, Cur> {
+        let amount = self.points.get(&date).copied().unwrap_or(0);
+        MoneyInner::<LowestSubunit, _>::new(amount, &self.currency)
+    }
+
+    /// The dates and amounts, in date order.
+    pub fn iter(&self) -> impl Iterator<Item = (NaiveDate, MoneyInner<LowestSubunit, Cur>)> + '_ {
+        self.points.iter().map(move |(&date, &amount)| {
+            (
+                date,
+                MoneyInner::<LowestSubunit, _>::new(amount, &self.currency),
+            )
+        })
+    }
+
+    /// The sum of all amounts, failing with `AmountOverflow`.
+    pub fn total(&self) -> Result<MoneyInner<LowestSubunit, Cur>, MoneyConversionError<Cur>> {
+        self.iter().try_fold(
+            MoneyInner::<LowestSubunit, _>::zero(&self.currency),
+            |total, (_, money)| total.checked_add(money),
+        )
+    }
+
+    /// Sums the amounts per `period`, keyed by the first day of each period.
+    pub fn resample(&self, period: Period) -> Result<Self, MoneyConversionError<Cur>> {
+        let mut resampled = Self::new(self.currency);
+        for (date, money) in self.iter() {
+            resampled.add(period.start(date), money)?;
+        }
+        Ok(resampled)
+    }
+
+    /// The running total at each date.
+    pub fn cumulative(&self) -> Result<Self, MoneyConversionError<Cur>> {
+        let mut running = MoneyInner::<LowestSubunit, _>::zero(&self.currency);
+        let mut cumulative = Self::new(self.currency);
+        for (date, money) in self.iter() {
+            running = running.checked_add(money)?;
+            cumulative.points.insert(date, running.amount);
+        }
+        Ok(cumulative)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn currency_consistency() {
+        let mut series = MoneySeries::new(EUR);
+        series.add(date(2024, 1, 1), Money::new(5, &EUR)).unwrap();
+        assert_eq!(
+            series.add(date(2024, 1, 1), Money::new(5, &USD)),
+            Err(MoneyConversionError::CurrencyMismatch(EUR, USD))
+        );
+        assert_eq!(
+            series.add(date(2024, 1, 1), Money::new(i32::MAX, &EUR)),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+        assert_eq!(series.get(date(2024, 1, 1)), Money::new(5, &EUR));
+        assert_eq!(series.len(), 1);
+    }
+
+    #[test]
+    fn resample_periods() -> Result<(), MoneyConversionError<Currency>> {
+        let mut series = MoneySeries::new(INR);
+        for (day, amount) in [
+            (date(2023, 12, 31), 1),
+            (date(2024, 2, 29), 10),
+            (date(2024, 3, 1), 100),
+            (date(2024, 4, 1), 1_000),
+        ] {
+            series.add(day, Money::new(amount, &INR))?;
+        }
+        let amounts = |series: MoneySeries<Currency>| {
+            series
+                .iter()
+                .map(|(day, money)| (day, money.amount()))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(
+            amounts(series.resample(Period::Quarter)?),
+            [
+                (date(2023, 10, 1), 1),
+                (date(2024, 1, 1), 110),
+                (date(2024, 4, 1), 1_000)
+            ]
+        );
+        assert_eq!(
+            amounts(series.resample(Period::Year)?),
+            [(date(2023, 1, 1), 1), (date(2024, 1, 1), 1_110)]
+        );
+        assert_eq!(series.total()?, Money::new(1_111, &INR));
+        Ok(())
+    }
+
+    #[test]
+    fn cumulative_overflow() {
+        let mut series = MoneySeries::new(USD);
+        series
+            .add(date(2024, 1, 1), Money::new(i32::MAX, &USD))
+            .unwrap();
+        series.add(date(2024, 1, 2), Money::new(1, &USD)).unwrap();
+        assert_eq!(
+            series.cumulative(),
+            Err(MoneyConversionError::AmountOverflow)
+        );
+        assert!(MoneySeries::new(USD).cumulative().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,357 @@
+//! Pinned [`RateTable`] snapshots in JSON or TOML files, enabled with the `json` and `toml`
+//! features, for running conversions fully offline.
+//!
+//! A snapshot holds a `timestamp`, the whole seconds since the Unix epoch (UTC) at which the
+//! rates were taken, and a list of `rates`. Each rate has a `from` and `to` currency code and
+//! either a `rate` or a `bid` and `ask`, in major units of `to` per major unit of `from`:
+//!
+//! ```json
+//! {
+//!   "timestamp": 1718000000,
+//!   "rates": [
+//!     {"from": "USD", "to": "INR", "rate": 83.25},
+//!     {"from": "EUR", "to": "USD", "bid": 1.08, "ask": 1.09}
+//!   ]
+//! }
+//! ```
+//!
+//! or in TOML:
+//!
+//! ```toml
+//! timestamp = 1718000000
+//!
+//! [[rates]]
+//! from = "USD"
+//! to = "INR"
+//! rate = 83.25
+//!
+//! [[rates]]
+//! from = "EUR"
+//! to = "USD"
+//! bid = 1.08
+//! ask = 1.09
+//! ```
+//!
+//! Rates are validated like [`ExchangeRate::new`] and [`ExchangeRate::with_spread`]; a rate with
+//! both a `rate` and a `bid` or `ask`, or with only one side of a spread, is invalid too.
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::amount::MoneyConversionError;
+use crate::exchange::ExchangeRate;
+use crate::factor::FromCurrency;
+use crate::rate_table::RateTable;
+
+/// Why a snapshot could not be read or written.
+#[derive(Debug)]
+pub enum SnapshotError<Cur> {
+    /// The file could not be read or written.
+    Io(io::Error),
+    /// The file is not valid JSON or does not follow the schema.
+    #[cfg(feature = "json")]
+    Json(serde_json::Error),
+    /// The file is not valid TOML or does not follow the schema.
+    #[cfg(feature = "toml")]
+    TomlDe(toml::de::Error),
+    /// The table could not be written as TOML.
+    #[cfg(feature = "toml")]
+    TomlSer(toml::ser::Error),
+    /// The table to write has no timestamp, or one before the Unix epoch, or the file holds a
+    /// timestamp too far in the future for `SystemTime`.
+    InvalidTimestamp,
+    /// A rate in the file is invalid.
+    Money(MoneyConversionError<Cur>),
+}
+
+impl<Cur> SnapshotError<Cur> {
+    /// A stable snake_case identifier of the error kind: `"snapshot_io"`,
+    /// `"invalid_snapshot_timestamp"` or, for a file that cannot be parsed or holds an invalid
+    /// rate, `"invalid_snapshot"`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Io(_) => "snapshot_io",
+            Self::InvalidTimestamp => "invalid_snapshot_timestamp",
+            _ => "invalid_snapshot",
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct Snapshot<Cur> {
+    timestamp: u64,
+    rates: Vec<SnapshotRate<Cur>>,
+}
+
+#[derive(Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct SnapshotRate<Cur> {
+    from: Cur,
+    to: Cur,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rate: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    bid: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    ask: Option<f64>,
+}
+
+impl<Cur: FromCurrency> RateTable<Cur> {
+    /// Reads a JSON snapshot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, UNIX_EPOCH};
+    ///
+    /// use amount_conversion::exchange::ExchangeRate;
+    /// use amount_conversion::factor::Currency::{self, *};
+    /// use amount_conversion::rate_table::RateTable;
+    ///
+    /// let path = std::env::temp_dir().join("amount_conversion-doc-rates.json");
+    /// let taken = UNIX_EPOCH + Duration::from_secs(1_718_000_000);
+    /// let mut table = RateTable::new().with_timestamp(taken);
+    /// table.insert(ExchangeRate::with_spread(EUR, USD, 1.08, 1.09).unwrap());
+    /// table.to_json_file(&path).unwrap();
+    ///
+    /// let pinned = RateTable::<Currency>::from_json_file(&path).unwrap();
+    /// assert_eq!(pinned.timestamp(), table.timestamp());
+    /// assert_eq!(pinned.get(USD, EUR), table.get(USD, EUR));
+    /// # std::fs::remove_file(&path).unwrap();
+    /// ```
+    #[cfg(feature = "json")]
+    pub fn from_json_file(path: impl AsRef<Path>) -> Result<Self, SnapshotError<Cur>>
+    where
+        Cur: DeserializeOwned,
+    {
+        let file = std::fs::File::open(path)?;
+        let snapshot = serde_json::from_reader(io::BufReader::new(file))?;
+        Self::from_snapshot(snapshot)
+    }
+
+    /// Writes the table as a pretty-printed JSON snapshot, replacing the file.
+    #[cfg(feature = "json")]
+    pub fn to_json_file(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError<Cur>>
+    where
+        Cur: Serialize,
+    {
+        let mut json = serde_json::to_string_pretty(&self.to_snapshot()?)?;
+        json.push('\n');
+        Ok(std::fs::write(path, json)?)
+    }
+
+    /// Reads a TOML snapshot.
+    #[cfg(feature = "toml")]
+    pub fn from_toml_file(path: impl AsRef<Path>) -> Result<Self, SnapshotError<Cur>>
+    where
+        Cur: DeserializeOwned,
+    {
+        let snapshot = toml::from_str(&std::fs::read_to_string(path)?)?;
+        Self::from_snapshot(snapshot)
+    }
+
+    /// Writes the table as a TOML snapshot, replacing the file.
+    #[cfg(feature = "toml")]
+    pub fn to_toml_file(&self, path: impl AsRef<Path>) -> Result<(), SnapshotError<Cur>>
+    where
+        Cur: Serialize,
+    {
+        let toml = toml::to_string(&self.to_snapshot()?)?;
+        Ok(std::fs::write(path, toml)?)
+    }
+
+    fn from_snapshot(snapshot: Snapshot<Cur>) -> Result<Self, SnapshotError<Cur>> {
+        let timestamp = UNIX_EPOCH
+            .checked_add(Duration::from_secs(snapshot.timestamp))
+            .ok_or(SnapshotError::InvalidTimestamp)?;
+        let mut table = Self::new().with_timestamp(timestamp);
+        for rate in snapshot.rates {
+            let rate = match (rate.rate, rate.bid, rate.ask) {
+                (Some(mid), None, None) => ExchangeRate::new(rate.from, rate.to, mid)?,
+                (None, Some(bid), Some(ask)) => {
+                    ExchangeRate::with_spread(rate.from, rate.to, bid, ask)?
+                }
+                _ => return Err(MoneyConversionError::InvalidExchangeRate.into()),
+            };
+            table.insert(rate);
+        }
+        Ok(table)
+    }
+
+    fn to_snapshot(&self) -> Result<Snapshot<Cur>, SnapshotError<Cur>> {
+        let timestamp = self
+            .timestamp()
+            .and_then(|timestamp| timestamp.duration_since(UNIX_EPOCH).ok())
+            .ok_or(SnapshotError::InvalidTimestamp)?;
+        let rates = self
+            .rates()
+            .iter()
+            .map(|rate| {
+                let spread = rate.bid() != rate.ask();
+                SnapshotRate {
+                    from: rate.from(),
+                    to: rate.to(),
+                    rate: (!spread).then(|| rate.rate()),
+                    bid: spread.then(|| rate.bid()),
+                    ask: spread.then(|| rate.ask()),
+                }
+            })
+            .collect();
+        Ok(Snapshot {
+            timestamp: timestamp.as_secs(),
+            rates,
+        })
+    }
+}
+
+impl<Cur> From<io::Error> for SnapshotError<Cur> {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<Cur> From<serde_json::Error> for SnapshotError<Cur> {
+    fn from(error: serde_json::Error) -> Self {
+        Self::Json(error)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl<Cur> From<toml::de::Error> for SnapshotError<Cur> {
+    fn from(error: toml::de::Error) -> Self {
+        Self::TomlDe(error)
+    }
+}
+
+#[cfg(feature = "toml")]
+impl<Cur> From<toml::ser::Error> for SnapshotError<Cur> {
+    fn from(error: toml::ser::Error) -> Self {
+        Self::TomlSer(error)
+    }
+}
+
+impl<Cur> From<MoneyConversionError<Cur>> for SnapshotError<Cur> {
+    fn from(error: MoneyConversionError<Cur>) -> Self {
+        Self::Money(error)
+    }
+}
+
+impl<Cur: fmt::Debug> fmt::Display for SnapshotError<Cur> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "{error}"),
+            #[cfg(feature = "json")]
+            Self::Json(error) => write!(f, "{error}"),
+            #[cfg(feature = "toml")]
+            Self::TomlDe(error) => write!(f, "{error}"),
+            #[cfg(feature = "toml")]
+            Self::TomlSer(error) => write!(f, "{error}"),
+            Self::InvalidTimestamp => f.write_str(
+                "the rate table has no timestamp between the Unix epoch and the latest `SystemTime`",
+            ),
+            Self::Money(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl<Cur: fmt::Debug> std::error::Error for SnapshotError<Cur> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    fn pinned() -> RateTable<Currency> {
+        let mut table =
+            RateTable::new().with_timestamp(UNIX_EPOCH + Duration::from_secs(1_718_000_000));
+        table.insert(ExchangeRate::new(USD, INR, 83.25).unwrap());
+        table.insert(ExchangeRate::with_spread(EUR, USD, 1.08, 1.09).unwrap());
+        table
+    }
+
+    fn path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("amount_conversion-{}-{name}", std::process::id()))
+    }
+
+    fn assert_same(read: &RateTable<Currency>, written: &RateTable<Currency>) {
+        assert_eq!(read.timestamp(), written.timestamp());
+        assert_eq!(read.rates(), written.rates());
+    }
+
+    #[test]
+    fn rejects_invalid_snapshots() {
+        let snapshot = |rate: &str| -> Snapshot<Currency> {
+            serde_json::from_str(&format!(
+                r#"{{"timestamp": 0, "rates": [{{"from": "USD", "to": "INR", {rate}}}]}}"#
+            ))
+            .unwrap()
+        };
+        for rate in [
+            r#""rate": 83.0, "bid": 83.0"#,
+            r#""bid": 83.0"#,
+            r#""bid": 84.0, "ask": 83.0"#,
+            r#""rate": -1.0"#,
+        ] {
+            assert!(matches!(
+                RateTable::from_snapshot(snapshot(rate)),
+                Err(SnapshotError::Money(
+                    MoneyConversionError::InvalidExchangeRate
+                ))
+            ));
+        }
+        assert!(matches!(
+            RateTable::<Currency>::new().to_snapshot(),
+            Err(SnapshotError::InvalidTimestamp)
+        ));
+        let far_future = Snapshot::<Currency> {
+            timestamp: u64::MAX,
+            rates: Vec::new(),
+        };
+        assert!(matches!(
+            RateTable::from_snapshot(far_future),
+            Err(SnapshotError::InvalidTimestamp)
+        ));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn json_round_trip() {
+        let path = path("rates.json");
+        let table = pinned();
+        table.to_json_file(&path).unwrap();
+        let json = std::fs::read_to_string(&path).unwrap();
+        assert!(json.contains(r#""timestamp": 1718000000"#));
+        assert!(!json.contains(r#""bid": 83.25"#));
+        assert_same(&RateTable::from_json_file(&path).unwrap(), &table);
+        std::fs::remove_file(&path).unwrap();
+        assert!(matches!(
+            RateTable::<Currency>::from_json_file(&path),
+            Err(SnapshotError::Io(_))
+        ));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_round_trip() {
+        let path = path("rates.toml");
+        let table = pinned();
+        table.to_toml_file(&path).unwrap();
+        assert!(std::fs::read_to_string(&path)
+            .unwrap()
+            .starts_with("timestamp = 1718000000\n\n[[rates]]\nfrom = \"USD\"\n"));
+        assert_same(&RateTable::from_toml_file(&path).unwrap(), &table);
+
+        std::fs::write(&path, "timestamp = 0\nrates = []\nsource = \"ecb\"\n").unwrap();
+        assert!(matches!(
+            RateTable::<Currency>::from_toml_file(&path),
+            Err(SnapshotError::TomlDe(_))
+        ));
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,128 @@
+use crate::amount::{i128_to_i32, LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::factor::FromCurrency;
+use crate::percent::{BasisPoints, BPS_PER_UNIT};
+use crate::rounding::{div_round, RoundingMode};
+
+impl<Cur: FromCurrency> MoneyInner<LowestSubunit, Cur> {
+    /// Tax-exclusive pricing: treats the amount as net and adds tax at `rate` on top.
+    ///
+    /// Returns `(net, tax, gross)` where `net + tax == gross` holds exactly; only the tax is
+    /// rounded, with `rounding`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, INR};
+    /// use amount_conversion::percent::BasisPoints;
+    /// use amount_conversion::rounding::RoundingMode;
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// let (net, tax, gross) = Money::new(9999, &INR)
+    ///     .add_tax(BasisPoints::from_percent(18), RoundingMode::HalfUp)
+    ///     .unwrap();
+    /// assert_eq!((net.amount(), tax.amount(), gross.amount()), (9999, 1800, 11799));
+    /// ```
+    pub fn add_tax(
+        self,
+        rate: impl Into<BasisPoints>,
+        rounding: RoundingMode,
+    ) -> Result<(Self, Self, Self), MoneyConversionError<Cur>> {
+        let rate = tax_rate(rate)?;
+        let tax = Self::new(
+            i128_to_i32(rate.of(i128::from(self.amount), rounding), self.currency)?,
+            &self.currency,
+        );
+        Ok((self, tax, self.checked_add(tax)?))
+    }
+
+    /// Tax-inclusive pricing: treats the amount as gross and extracts the tax at `rate` from it.
+    ///
+    /// Returns `(net, tax, gross)` where `net + tax == gross` holds exactly; the net is rounded
+    /// with `rounding` and the tax is whatever remains.
+    pub fn extract_tax(
+        self,
+        rate: impl Into<BasisPoints>,
+        rounding: RoundingMode,
+    ) -> Result<(Self, Self, Self), MoneyConversionError<Cur>> {
+        let rate = tax_rate(rate)?;
+        let net = div_round(
+            i128::from(self.amount) * BPS_PER_UNIT,
+            BPS_PER_UNIT + i128::from(rate.get()),
+            rounding,
+        );
+        let net = Self::new(i128_to_i32(net, self.currency)?, &self.currency);
+        Ok((net, self.checked_sub(net)?, self))
+    }
+}
+
+fn tax_rate<Cur>(rate: impl Into<BasisPoints>) -> Result<BasisPoints, MoneyConversionError<Cur>> {
+    let rate = rate.into();
+    if rate.get() < 0 {
+        return Err(MoneyConversionError::InvalidRate(rate.get()));
+    }
+    Ok(rate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    fn parts(triple: (Money, Money, Money)) -> (i32, i32, i32) {
+        (triple.0.amount(), triple.1.amount(), triple.2.amount())
+    }
+
+    #[test]
+    fn add_tax() -> Result<(), MoneyConversionError<Currency>> {
+        let amount = Money::new(1000, &EUR);
+        assert_eq!(
+            parts(amount.add_tax(1900, RoundingMode::HalfEven)?),
+            (1000, 190, 1190)
+        );
+        assert_eq!(
+            parts(Money::new(5, &EUR).add_tax(1000, RoundingMode::HalfEven)?),
+            (5, 0, 5)
+        );
+        assert_eq!(
+            parts(Money::new(5, &EUR).add_tax(1000, RoundingMode::Ceil)?),
+            (5, 1, 6)
+        );
+        assert_eq!(
+            parts(amount.add_tax(0, RoundingMode::HalfEven)?),
+            (1000, 0, 1000)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn extract_tax_sums_exactly() -> Result<(), MoneyConversionError<Currency>> {
+        assert_eq!(
+            parts(Money::new(1190, &EUR).extract_tax(1900, RoundingMode::HalfEven)?),
+            (1000, 190, 1190)
+        );
+        for gross in [1, 99, 1001, 123_457, -999] {
+            for mode in [
+                RoundingMode::HalfEven,
+                RoundingMode::Floor,
+                RoundingMode::Ceil,
+            ] {
+                let (net, tax, total) = Money::new(gross, &INR).extract_tax(1800, mode)?;
+                assert_eq!(net.checked_add(tax)?, total);
+                assert_eq!(total.amount(), gross);
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn negative_rate() {
+        assert_eq!(
+            Money::new(100, &USD).extract_tax(-10_000, RoundingMode::HalfEven),
+            Err(MoneyConversionError::InvalidRate(-10_000))
+        );
+    }
+}
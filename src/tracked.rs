@@ -0,0 +1,303 @@
+use crate::allocation::{Allocation, RemainderStrategy};
+use crate::amount::{LowestSubunit, MoneyConversionError, MoneyInner};
+use crate::exchange::ExchangeRate;
+use crate::factor::FromCurrency;
+use crate::percent::{BasisPoints, BPS_PER_UNIT};
+use crate::rounding::{snap_to_whole, RoundingMode};
+
+/// Money that remembers how far rounding has moved it from the exact result.
+///
+/// Every operation rounds to whole subunits like its `MoneyInner` counterpart and adds the
+/// rounding delta to a running error, carried through later operations, so a chain of
+/// conversions can be checked against a tolerance at the end.
+///
+/// # Examples
+///
+/// ```
+/// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+/// use amount_conversion::exchange::ExchangeRate;
+/// use amount_conversion::factor::Currency::{self, EUR, USD};
+/// use amount_conversion::rounding::RoundingMode;
+/// use amount_conversion::tracked::TrackedMoney;
+///
+/// type Money = MoneyInner<LowestSubunit, Currency>;
+///
+/// let tracked = TrackedMoney::new(Money::new(3, &USD))
+///     .convert(&ExchangeRate::new(USD, EUR, 0.5)?, RoundingMode::Ceil)?;
+/// assert_eq!(tracked.money(), Money::new(2, &EUR));
+/// assert_eq!(tracked.accumulated_error(), 0.5);
+/// assert!(tracked.is_within(0.5));
+/// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct TrackedMoney<Cur: FromCurrency> {
+    money: MoneyInner<LowestSubunit, Cur>,
+    error: f64,
+}
+
+impl<Cur: FromCurrency> TrackedMoney<Cur> {
+    /// Starts tracking from an exact amount.
+    pub fn new(money: MoneyInner<LowestSubunit, Cur>) -> Self {
+        Self { money, error: 0.0 }
+    }
+
+    pub fn money(&self) -> MoneyInner<LowestSubunit, Cur> {
+        self.money
+    }
+
+    pub fn into_inner(self) -> MoneyInner<LowestSubunit, Cur> {
+        self.money
+    }
+
+    /// The rounded amount minus the exact amount, in subunits of the current currency.
+    ///
+    /// Positive when rounding has left the amount above the exact result.
+    pub fn accumulated_error(&self) -> f64 {
+        self.error
+    }
+
+    /// Whether the accumulated error is at most `tolerance` subunits either way.
+    pub fn is_within(&self, tolerance: f64) -> bool {
+        self.error.abs() <= tolerance
+    }
+
+    /// The `bps` share of the amount, see [`MoneyInner::apply_bps_with`].
+    pub fn apply_bps(
+        self,
+        bps: impl Into<BasisPoints>,
+        rounding: RoundingMode,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        let bps = bps.into();
+        let money = self.money.apply_bps_with(bps, rounding)?;
+        let scale = f64::from(bps.get()) / BPS_PER_UNIT as f64;
+        // the product is exact in i128, so the error is only off by the final division
+        let exact =
+            (i128::from(self.money.amount) * i128::from(bps.get())) as f64 / BPS_PER_UNIT as f64;
+        Ok(self.track(money, scale, exact))
+    }
+
+    /// Converts into the target currency of `rate`, see [`ExchangeRate::convert_with`].
+    pub fn convert(
+        self,
+        rate: &ExchangeRate<Cur>,
+        rounding: RoundingMode,
+    ) -> Result<Self, MoneyConversionError<Cur>> {
+        let scale = rate.subunit_rate(&self.money)?;
+        let money = rate.convert_with(self.money, rounding)?;
+        // float noise such as `100.0 * 0.29` is no rounding, as for `convert_with`
+        let exact = snap_to_whole(f64::from(self.money.amount) * scale);
+        Ok(self.track(money, scale, exact))
+    }
+
+    /// Adds two tracked amounts of the same currency along with their errors.
+    pub fn checked_add(self, other: Self) -> Result<Self, MoneyConversionError<Cur>> {
+        Ok(Self {
+            money: self.money.checked_add(other.money)?,
+            error: self.error + other.error,
+        })
+    }
+
+    /// Subtracts two tracked amounts of the same currency along with their errors.
+    pub fn checked_sub(self, other: Self) -> Result<Self, MoneyConversionError<Cur>> {
+        Ok(Self {
+            money: self.money.checked_sub(other.money)?,
+            error: self.error - other.error,
+        })
+    }
+
+    /// Splits into `n` parts, see [`MoneyInner::split`].
+    ///
+    /// Each part carries its share of the error so far plus how far the split moved it from an
+    /// exact `1/n` share, so the part errors always sum to the error of the whole.
+    pub fn split(
+        self,
+        n: u32,
+        strategy: RemainderStrategy,
+    ) -> Result<Vec<Self>, MoneyConversionError<Cur>> {
+        let allocation = self.money.split(n, strategy)?;
+        let share = 1.0 / f64::from(n);
+        Ok(self.track_parts(allocation, std::iter::repeat(share)))
+    }
+
+    /// Allocates in proportion to `ratios`, see [`MoneyInner::allocate`], tracking each part's
+    /// error like [`split`](Self::split).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use amount_conversion::allocation::RemainderStrategy;
+    /// use amount_conversion::amount::{LowestSubunit, MoneyInner};
+    /// use amount_conversion::factor::Currency::{self, USD};
+    /// use amount_conversion::tracked::TrackedMoney;
+    ///
+    /// type Money = MoneyInner<LowestSubunit, Currency>;
+    ///
+    /// let parts = TrackedMoney::new(Money::new(1_001, &USD))
+    ///     .allocate(&[1, 1, 2], RemainderStrategy::FrontLoad)?;
+    /// let errors: Vec<_> = parts.iter().map(TrackedMoney::accumulated_error).collect();
+    /// assert_eq!(errors, [0.75, -0.25, -0.5]);
+    /// # Ok::<(), amount_conversion::amount::MoneyConversionError<Currency>>(())
+    /// ```
+    pub fn allocate(
+        self,
+        ratios: &[u32],
+        strategy: RemainderStrategy,
+    ) -> Result<Vec<Self>, MoneyConversionError<Cur>> {
+        let allocation = self.money.allocate(ratios, strategy)?;
+        let total: f64 = ratios.iter().copied().map(f64::from).sum();
+        let shares = ratios.iter().map(|&ratio| f64::from(ratio) / total);
+        Ok(self.track_parts(allocation, shares))
+    }
+
+    fn track_parts(
+        self,
+        allocation: Allocation<Cur>,
+        shares: impl Iterator<Item = f64>,
+    ) -> Vec<Self> {
+        let amount = f64::from(self.money.amount);
+        allocation
+            .into_parts()
+            .into_iter()
+            .zip(shares)
+            .map(|(money, share)| Self {
+                money,
+                error: self.error * share + (f64::from(money.amount) - amount * share),
+            })
+            .collect()
+    }
+
+    /// `money`, rounded from the `exact` result of scaling the amount by `scale`.
+    fn track(self, money: MoneyInner<LowestSubunit, Cur>, scale: f64, exact: f64) -> Self {
+        Self {
+            money,
+            error: self.error * scale + (f64::from(money.amount) - exact),
+        }
+    }
+}
+
+impl<Cur: FromCurrency> From<MoneyInner<LowestSubunit, Cur>> for TrackedMoney<Cur> {
+    fn from(money: MoneyInner<LowestSubunit, Cur>) -> Self {
+        Self::new(money)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn apply_bps_tracks_delta() -> Result<(), MoneyConversionError<Currency>> {
+        let tracked =
+            TrackedMoney::new(Money::new(5, &USD)).apply_bps(1000, RoundingMode::Floor)?;
+        assert_eq!(tracked.money(), Money::new(0, &USD));
+        assert_eq!(tracked.accumulated_error(), -0.5);
+
+        let exact =
+            TrackedMoney::new(Money::new(1000, &USD)).apply_bps(2500, RoundingMode::Floor)?;
+        assert_eq!(exact.money(), Money::new(250, &USD));
+        assert_eq!(exact.accumulated_error(), 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn matches_untracked_rounding() -> Result<(), MoneyConversionError<Currency>> {
+        for (amount, bps, rounding) in [
+            (100, 700, RoundingMode::Ceil),
+            (100, 2_900, RoundingMode::Floor),
+            (100, 5_700, RoundingMode::Floor),
+            (-100, 5_700, RoundingMode::Ceil),
+            (i32::MAX, 3_333, RoundingMode::HalfEven),
+        ] {
+            let money = Money::new(amount, &USD);
+            let tracked = TrackedMoney::new(money).apply_bps(bps, rounding)?;
+            assert_eq!(tracked.money(), money.apply_bps_with(bps, rounding)?);
+            let exact = f64::from(amount) * f64::from(bps) / 10_000.0;
+            assert!(
+                (tracked.accumulated_error() - (f64::from(tracked.money().amount()) - exact)).abs()
+                    < 1e-6
+            );
+        }
+        assert_eq!(
+            TrackedMoney::new(Money::new(100, &USD))
+                .apply_bps(700, RoundingMode::Ceil)?
+                .accumulated_error(),
+            0.0
+        );
+
+        let rate = ExchangeRate::new(USD, EUR, 0.29)?;
+        for (amount, rounding) in [
+            (100, RoundingMode::Floor),
+            (100, RoundingMode::Ceil),
+            (57, RoundingMode::Floor),
+            (-100, RoundingMode::Ceil),
+        ] {
+            let money = Money::new(amount, &USD);
+            let tracked = TrackedMoney::new(money).convert(&rate, rounding)?;
+            assert_eq!(tracked.money(), rate.convert_with(money, rounding)?);
+        }
+        let tracked =
+            TrackedMoney::new(Money::new(100, &USD)).convert(&rate, RoundingMode::Floor)?;
+        assert_eq!(tracked.money(), Money::new(29, &EUR));
+        assert_eq!(tracked.accumulated_error(), 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn error_carries_through_conversions() -> Result<(), MoneyConversionError<Currency>> {
+        let to_eur = ExchangeRate::new(USD, EUR, 0.5)?;
+        let tracked =
+            TrackedMoney::new(Money::new(3, &USD)).convert(&to_eur, RoundingMode::Ceil)?;
+        assert_eq!(tracked.accumulated_error(), 0.5);
+
+        // converting back doubles both the amount and the error carried so far
+        let back = tracked.convert(&to_eur.inverse(), RoundingMode::HalfEven)?;
+        assert_eq!(back.money(), Money::new(4, &USD));
+        assert_eq!(back.accumulated_error(), 1.0);
+        assert!(!back.is_within(0.5));
+        Ok(())
+    }
+
+    #[test]
+    fn parts_share_errors() -> Result<(), MoneyConversionError<Currency>> {
+        let errors = |parts: Vec<TrackedMoney<Currency>>| -> Vec<f64> {
+            parts.iter().map(TrackedMoney::accumulated_error).collect()
+        };
+        let whole = TrackedMoney::new(Money::new(1_000, &USD));
+        let parts = whole.split(3, RemainderStrategy::BackLoad)?;
+        assert_eq!(
+            parts.iter().map(TrackedMoney::money).collect::<Vec<_>>(),
+            [333, 333, 334].map(|amount| Money::new(amount, &USD))
+        );
+        assert!(errors(parts).iter().sum::<f64>().abs() < 1e-9);
+
+        let parts = whole.allocate(&[70, 0, 30], RemainderStrategy::RoundRobin)?;
+        assert_eq!(errors(parts), [0.0, 0.0, 0.0]);
+
+        // a part inherits its share of the error carried so far
+        let half = TrackedMoney::new(Money::new(5, &INR)).apply_bps(1000, RoundingMode::Ceil)?;
+        let parts = half.allocate(&[1, 3], RemainderStrategy::FrontLoad)?;
+        assert_eq!(errors(parts), [0.125 + 0.75, 0.375 - 0.75]);
+        assert_eq!(
+            whole.split(0, RemainderStrategy::FrontLoad),
+            Err(MoneyConversionError::DivisionByZero)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn sums_errors() -> Result<(), MoneyConversionError<Currency>> {
+        let half = TrackedMoney::new(Money::new(5, &INR)).apply_bps(1000, RoundingMode::Ceil)?;
+        let total = half.checked_add(half)?;
+        assert_eq!(total.money(), Money::new(2, &INR));
+        assert_eq!(total.accumulated_error(), 1.0);
+        assert_eq!(total.checked_sub(half)?.accumulated_error(), 0.5);
+        assert_eq!(
+            half.checked_add(TrackedMoney::new(Money::new(1, &USD))),
+            Err(MoneyConversionError::CurrencyMismatch(INR, USD))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,112 @@
+//! Helpers for the `validator` crate, enabled with the `validator` feature.
+//!
+//! Each helper returns a `ValidationError` with a stable code and the offending values as
+//! params, so they can be used directly or combined in a `#[validate(custom(function = ..))]`
+//! function on request DTOs.
+//!
+//! # Examples
+//!
+//! ```
+//! use amount_conversion::amount::{LowestSubunit, MoneyInner};
+//! use amount_conversion::factor::Currency::{self, EUR, INR, USD};
+//! use amount_conversion::validation::{validate_currency_allowed, validate_money_range};
+//! use validator::{Validate, ValidationError};
+//!
+//! type Money = MoneyInner<LowestSubunit, Currency>;
+//!
+//! #[derive(Validate)]
+//! struct Payment {
+//!     #[validate(custom(function = "valid_amount"))]
+//!     amount: Money,
+//! }
+//!
+//! fn valid_amount(amount: &Money) -> Result<(), ValidationError> {
+//!     validate_currency_allowed(amount, &[USD, EUR])?;
+//!     validate_money_range(amount, 100, 1_000_000)
+//! }
+//!
+//! assert!(Payment { amount: Money::new(5_000, &USD) }.validate().is_ok());
+//! assert!(Payment { amount: Money::new(50, &USD) }.validate().is_err());
+//! assert!(Payment { amount: Money::new(5_000, &INR) }.validate().is_err());
+//! ```
+use std::borrow::Cow;
+
+use validator::ValidationError;
+
+use crate::amount::{LowestSubunit, MoneyInner};
+use crate::factor::FromCurrency;
+
+/// Checks that the amount is within `min..=max` subunits, with code `money_range`.
+pub fn validate_money_range<Cur: FromCurrency>(
+    money: &MoneyInner<LowestSubunit, Cur>,
+    min: LowestSubunit,
+    max: LowestSubunit,
+) -> Result<(), ValidationError> {
+    if (min..=max).contains(&money.amount) {
+        return Ok(());
+    }
+    let mut error = ValidationError::new("money_range");
+    error.add_param(Cow::from("min"), &min);
+    error.add_param(Cow::from("max"), &max);
+    error.add_param(Cow::from("value"), &money.amount);
+    Err(error)
+}
+
+/// Checks that the amount is greater than zero, with code `money_positive`.
+pub fn validate_money_positive<Cur: FromCurrency>(
+    money: &MoneyInner<LowestSubunit, Cur>,
+) -> Result<(), ValidationError> {
+    if money.amount > 0 {
+        return Ok(());
+    }
+    let mut error = ValidationError::new("money_positive");
+    error.add_param(Cow::from("value"), &money.amount);
+    Err(error)
+}
+
+/// Checks that the currency is one of `allowed`, with code `currency_allowed`.
+pub fn validate_currency_allowed<Cur: FromCurrency>(
+    money: &MoneyInner<LowestSubunit, Cur>,
+    allowed: &[Cur],
+) -> Result<(), ValidationError> {
+    if allowed.contains(&money.currency) {
+        return Ok(());
+    }
+    let mut error = ValidationError::new("currency_allowed");
+    error.add_param(Cow::from("currency"), &money.currency.currency_code());
+    Err(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::factor::Currency::{self, *};
+
+    type Money = MoneyInner<LowestSubunit, Currency>;
+
+    #[test]
+    fn money_range() {
+        assert!(validate_money_range(&Money::new(100, &USD), 100, 200).is_ok());
+        assert!(validate_money_range(&Money::new(200, &USD), 100, 200).is_ok());
+
+        let error = validate_money_range(&Money::new(201, &USD), 100, 200).unwrap_err();
+        assert_eq!(error.code, "money_range");
+        assert_eq!(error.params["value"], 201);
+        assert_eq!(error.params["max"], 200);
+    }
+
+    #[test]
+    fn money_positive() {
+        assert!(validate_money_positive(&Money::new(1, &USD)).is_ok());
+        let error = validate_money_positive(&Money::new(0, &USD)).unwrap_err();
+        assert_eq!(error.code, "money_positive");
+    }
+
+    #[test]
+    fn currency_allowed() {
+        assert!(validate_currency_allowed(&Money::new(1, &INR), &[USD, INR]).is_ok());
+        let error = validate_currency_allowed(&Money::new(1, &EUR), &[USD, INR]).unwrap_err();
+        assert_eq!(error.code, "currency_allowed");
+        assert_eq!(error.params["currency"], "EUR");
+    }
+}
@@ -0,0 +1,112 @@
+//! `wasm-bindgen` bindings, enabled with the `wasm` feature.
+//!
+//! Currencies cross the JS boundary as ISO 4217 codes (`"USD"`), amounts as subunits.
+//! All conversions go through the same subunit tables and rounding as the Rust API.
+use wasm_bindgen::prelude::*;
+
+use crate::amount::{HighestUnit, LowestSubunit, MoneyInner};
+use crate::factor::{Currency, ParseCurrencyError};
+
+/// Parses an ISO 4217 code; `?` turns the error into a JS `Error` with the same message.
+fn parse_currency(code: &str) -> Result<Currency, ParseCurrencyError> {
+    code.parse()
+}
+
+/// Money held in the currency's lowest subunit, exported to JS as `Money`.
+#[wasm_bindgen(js_name = Money)]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WasmMoney {
+    inner: MoneyInner<LowestSubunit, Currency>,
+}
+
+#[wasm_bindgen(js_class = Money)]
+impl WasmMoney {
+    /// `new Money(1050, "USD")` - an amount in subunits.
+    #[wasm_bindgen(constructor)]
+    pub fn new(amount: i32, currency: &str) -> Result<WasmMoney, JsError> {
+        let currency = parse_currency(currency)?;
+        Ok(Self {
+            inner: MoneyInner::<LowestSubunit, _>::new(amount, &currency),
+        })
+    }
+
+    /// `Money.fromMajor(10.5, "USD")` - converts a major unit amount into subunits.
+    #[wasm_bindgen(js_name = fromMajor)]
+    pub fn from_major(amount: f64, currency: &str) -> Result<WasmMoney, JsError> {
+        let currency = parse_currency(currency)?;
+        let inner = MoneyInner::<HighestUnit, _>::new(amount, &currency).convert()?;
+        Ok(Self { inner })
+    }
+
+    /// `Money.parse("10.50", "USD")` - parses a decimal string exactly, without f64.
+    pub fn parse(amount: &str, currency: &str) -> Result<WasmMoney, JsError> {
+        let currency = parse_currency(currency)?;
+        let inner = MoneyInner::from_str_exact(amount, &currency)?;
+        Ok(Self { inner })
+    }
+
+    /// The amount in subunits.
+    #[wasm_bindgen(getter)]
+    pub fn amount(&self) -> i32 {
+        self.inner.amount
+    }
+
+    /// The ISO 4217 code of the currency.
+    #[wasm_bindgen(getter)]
+    pub fn currency(&self) -> String {
+        self.inner.currency.code().to_string()
+    }
+
+    /// The amount in major units.
+    #[wasm_bindgen(js_name = toMajor)]
+    pub fn to_major(&self) -> Result<f64, JsError> {
+        Ok(self.inner.convert()?.amount())
+    }
+
+    /// The amount in major units with exactly the currency's decimals, e.g. `"10.50"`.
+    pub fn format(&self) -> Result<String, JsError> {
+        Ok(self.inner.format_decimal()?)
+    }
+}
+
+// `JsError` can only be built on wasm32, so these cover the success paths and the messages
+// errors carry to JS.
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructors_and_accessors() {
+        let money = WasmMoney::new(1050, "USD").unwrap();
+        assert_eq!(
+            (money.amount(), money.currency()),
+            (1050, "USD".to_string())
+        );
+        assert_eq!(money.to_major().ok(), Some(10.5));
+        assert_eq!(money.format().ok().as_deref(), Some("10.50"));
+
+        assert_eq!(WasmMoney::from_major(10.5, "USD").ok(), Some(money));
+        let dinars = WasmMoney::parse("12.345", "BHD").unwrap();
+        assert_eq!(dinars.amount(), 12_345);
+        assert_eq!(dinars.format().ok().as_deref(), Some("12.345"));
+        assert_eq!(
+            WasmMoney::from_major(12.0, "JPY")
+                .map(|yen| yen.amount())
+                .ok(),
+            Some(12)
+        );
+    }
+
+    #[test]
+    fn error_messages() {
+        assert_eq!(parse_currency("EUR"), Ok(Currency::EUR));
+        assert_eq!(
+            parse_currency("UDS").unwrap_err().to_string(),
+            "unknown currency code `UDS`, did you mean USD or UZS?"
+        );
+        assert_eq!(
+            parse_currency("XXXXXX").unwrap_err().to_string(),
+            "unknown currency code `XXXXXX`"
+        );
+    }
+}